@@ -241,6 +241,7 @@ fn create_dummy_proposal<C: Deref<Target = impl Signer> + Clone>(
         .request()
         .accounts(govern::accounts::CreateProposal {
             governor,
+            instruction_buffer: None,
             proposal,
             smart_wallet: governor_state.smart_wallet,
             proposer: program.payer(),
@@ -248,6 +249,10 @@ fn create_dummy_proposal<C: Deref<Target = impl Signer> + Clone>(
             event_authority,
             program: govern::ID,
             system_program: solana_program::system_program::ID,
+            proposal_bond: None,
+            bond_vault: None,
+            proposer_bond_account: None,
+            token_program: None,
         })
         .args(govern::instruction::CreateProposal {
             proposal_type: 0,
@@ -305,6 +310,7 @@ fn queue_proposal<C: Deref<Target = impl Signer> + Clone>(
             proposal,
             transaction,
             smart_wallet: governor_state.smart_wallet,
+            option_proposal_instructions: None,
             smart_wallet_program: smart_wallet::id(),
             payer: program.payer(),
             event_authority,
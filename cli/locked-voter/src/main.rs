@@ -439,6 +439,7 @@ fn cast_vote<C: Deref<Target = impl Signer> + Clone>(
             vote_delegate: program.payer(),
             governor: locker_state.governor,
             govern_program: govern::ID,
+            delegation_table: None,
         }
         .to_account_metas(None),
         data: locked_voter::instruction::CastVote { side }.data(),
@@ -471,12 +472,17 @@ fn set_vote_delegate<C: Deref<Target = impl Signer> + Clone>(
         ],
         &locked_voter::id(),
     );
+    let locker_state: locked_voter::Locker = program.account(locker)?;
 
     let builder = program
         .request()
         .accounts(locked_voter::accounts::SetVoteDelegate {
+            locker,
+            governor: locker_state.governor,
             escrow,
             escrow_owner: program.payer(),
+            old_delegate_record: None,
+            new_delegate_record: None,
         })
         .args(locked_voter::instruction::SetVoteDelegate { new_delegate });
     let signature = builder.send()?;
@@ -110,6 +110,34 @@ pub enum CliCommand {
         #[clap(long)]
         timelock_delay_seconds: i64,
     },
+    CreateSetVotingParamsTx {
+        #[clap(long)]
+        base: Pubkey,
+        /// Quorum mode: 0 for absolute quorum votes, 1 for a fraction of the locked supply
+        #[clap(long, default_value_t = 0)]
+        quorum_mode: u8,
+        /// Quorum in basis points of the locked supply's voting power, used when quorum mode is 1
+        #[clap(long, default_value_t = 0)]
+        quorum_bps: u16,
+        /// Share of For votes, in basis points of For and Against votes, required to pass. 0 for a simple majority
+        #[clap(long, default_value_t = 0)]
+        approval_threshold_bps: u16,
+        /// Duration of the reveal window after voting ends, in seconds. 0 disables commit-reveal voting
+        #[clap(long, default_value_t = 0)]
+        reveal_period: u64,
+        /// Seconds voting is extended by when quorum is reached late. 0 disables the extension
+        #[clap(long, default_value_t = 0)]
+        late_quorum_extension: u64,
+        /// How the veto threshold of optimistic proposals is computed. 0 for absolute votes, 1 for a fraction of the locked supply
+        #[clap(long, default_value_t = 0)]
+        optimistic_veto_mode: u8,
+        /// Against votes an optimistic proposal must exceed to be defeated, must be greater than 0 when optimistic veto mode is 0
+        #[clap(long, default_value_t = 0)]
+        optimistic_veto_votes: u64,
+        /// Veto threshold in basis points of the locked supply's voting power, used when optimistic veto mode is 1
+        #[clap(long, default_value_t = 0)]
+        optimistic_veto_bps: u16,
+    },
     ApproveTransaction {
         #[clap(long)]
         base: Pubkey,
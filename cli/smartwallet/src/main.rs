@@ -101,6 +101,32 @@ fn main() -> Result<()> {
                 timelock_delay_seconds,
             )?;
         }
+        CliCommand::CreateSetVotingParamsTx {
+            base,
+            quorum_mode,
+            quorum_bps,
+            approval_threshold_bps,
+            reveal_period,
+            late_quorum_extension,
+            optimistic_veto_mode,
+            optimistic_veto_votes,
+            optimistic_veto_bps,
+        } => {
+            create_set_voting_params_tx(
+                &program,
+                base,
+                govern::VotingParameters {
+                    quorum_mode,
+                    quorum_bps,
+                    approval_threshold_bps,
+                    reveal_period,
+                    late_quorum_extension,
+                    optimistic_veto_mode,
+                    optimistic_veto_votes,
+                    optimistic_veto_bps,
+                },
+            )?;
+        }
         CliCommand::Verify {
             base,
             max_owners,
@@ -352,6 +378,40 @@ fn create_set_governance_params_tx<C: Deref<Target = impl Signer> + Clone>(
     create_transaction(program, base, vec![instruction])
 }
 
+fn create_set_voting_params_tx<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    base: Pubkey,
+    params: govern::VotingParameters,
+) -> Result<()> {
+    let (smart_wallet, _bump) = Pubkey::find_program_address(
+        &[b"SmartWallet".as_ref(), base.as_ref()],
+        &smart_wallet::id(),
+    );
+    let (governor, _bump) =
+        Pubkey::find_program_address(&[b"Governor".as_ref(), base.as_ref()], &govern::id());
+
+    println!("set voting parameters");
+    let data = govern::instruction::SetVotingParams { params }.data();
+    let instruction = smart_wallet::TXInstruction {
+        program_id: govern::ID,
+        keys: vec![
+            smart_wallet::TXAccountMeta {
+                pubkey: governor,
+                is_signer: false,
+                is_writable: true,
+            },
+            smart_wallet::TXAccountMeta {
+                pubkey: smart_wallet,
+                is_signer: true,
+                is_writable: false,
+            },
+        ],
+        data,
+    };
+
+    create_transaction(program, base, vec![instruction])
+}
+
 fn create_activate_proposal_tx<C: Deref<Target = impl Signer> + Clone>(
     program: &Program<C>,
     base: Pubkey,
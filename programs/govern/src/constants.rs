@@ -11,3 +11,36 @@ pub const AGAINST_VOTE_INDEX: usize = 1;
 
 #[constant]
 pub const FOR_VOTE_INDEX: usize = 2;
+
+#[constant]
+pub const MAX_BPS: u16 = 10_000;
+
+#[constant]
+pub const SIMPLE_MAJORITY_BPS: u16 = 5_000;
+
+#[constant]
+pub const MAX_REWARD_STREAMS: usize = 8;
+
+#[constant]
+pub const PAUSE_CREATE_PROPOSAL: u8 = 1 << 0;
+
+#[constant]
+pub const PAUSE_ACTIVATE_PROPOSAL: u8 = 1 << 1;
+
+#[constant]
+pub const PAUSE_SET_VOTE: u8 = 1 << 2;
+
+#[constant]
+pub const PAUSE_QUEUE_PROPOSAL: u8 = 1 << 3;
+
+#[constant]
+pub const PAUSE_CLAIM_REWARD: u8 = 1 << 4;
+
+#[constant]
+pub const PAUSE_ALL: u8 = (1 << 5) - 1;
+
+#[constant]
+pub const MAX_PAUSE_DURATION: i64 = 7 * 24 * 60 * 60;
+
+#[constant]
+pub const INCENTIVE_CLAIM_PERIOD: i64 = 30 * 24 * 60 * 60;
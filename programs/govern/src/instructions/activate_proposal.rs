@@ -13,10 +13,17 @@ pub struct ActivateProposal<'info> {
 }
 
 impl<'info> ActivateProposal<'info> {
-    pub fn activate_proposal(&mut self) -> Result<()> {
+    pub fn activate_proposal(&mut self, locked_voting_power: u64) -> Result<()> {
         let proposal = &mut self.proposal;
         let now = Clock::get()?.unix_timestamp;
         proposal.activated_at = now;
+        // only pauses after activation extend voting.
+        proposal.voting_paused_duration = unwrap_int!(self.governor.voting_paused_duration_at(now));
+        proposal.quorum_votes = unwrap_int!(proposal.compute_quorum_votes(locked_voting_power));
+        if proposal.is_optimistic() {
+            proposal.optimistic_veto_votes =
+                unwrap_int!(proposal.compute_optimistic_veto_votes(locked_voting_power));
+        }
         proposal.voting_ends_at = unwrap_int!(self
             .governor
             .params
@@ -28,6 +35,7 @@ impl<'info> ActivateProposal<'info> {
             governor: proposal.governor,
             proposal: proposal.key(),
             voting_ends_at: proposal.voting_ends_at,
+            quorum_votes: proposal.quorum_votes,
         });
 
         Ok(())
@@ -38,10 +46,15 @@ impl<'info> Validate<'info> for ActivateProposal<'info> {
     fn validate(&self) -> Result<()> {
         assert_keys_eq!(self.governor, self.proposal.governor);
         assert_keys_eq!(self.locker, self.governor.locker);
+        self.governor.assert_not_paused(PAUSE_ACTIVATE_PROPOSAL)?;
         invariant!(
             self.proposal.get_state()? == ProposalState::Draft,
             ProposalNotDraft
         );
+        invariant!(
+            self.proposal.has_all_option_instructions(),
+            MissingOptionProposalInstructions
+        );
 
         let earliest_activation_time = unwrap_int!(self
             .governor
@@ -73,4 +86,6 @@ pub struct ProposalActivateEvent {
     pub proposal: Pubkey,
     /// When voting ends for the [Proposal].
     pub voting_ends_at: i64,
+    /// The quorum of the [Proposal], snapshotted at activation.
+    pub quorum_votes: u64,
 }
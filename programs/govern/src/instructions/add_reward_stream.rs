@@ -0,0 +1,114 @@
+use crate::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+/// Accounts for [govern::add_reward_stream].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddRewardStream<'info> {
+    /// The [Proposal].
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [ProposalRewards] of the [Proposal].
+    #[account(mut, has_one = proposal)]
+    pub proposal_rewards: Box<Account<'info, ProposalRewards>>,
+    /// The reward mint.
+    pub reward_mint: Account<'info, Mint>,
+    /// The reward vault, owned by the [ProposalRewards].
+    #[account(
+        mut,
+        constraint = reward_vault.owner == proposal_rewards.key(),
+        constraint = reward_vault.mint == reward_mint.key()
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+    /// The funder of the reward.
+    pub funder: Signer<'info>,
+    /// The token account the reward is transferred from.
+    #[account(mut)]
+    pub funder_token_account: Account<'info, TokenAccount>,
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> AddRewardStream<'info> {
+    /// Funds a reward stream, adding to the existing stream of the funder with the same mint if any.
+    pub fn add_reward_stream(&mut self, amount: u64) -> Result<RewardStreamAddEvent> {
+        invariant!(amount > 0, "amount must be greater than 0");
+
+        let reward_mint = self.reward_mint.key();
+        let reward_vault = self.reward_vault.key();
+        let funder = self.funder.key();
+        let streams = &mut self.proposal_rewards.streams;
+        match streams
+            .iter_mut()
+            .find(|stream| stream.mint == reward_mint && stream.funder == funder)
+        {
+            Some(stream) => {
+                assert_keys_eq!(stream.vault, reward_vault);
+                stream.amount = unwrap_int!(stream.amount.checked_add(amount));
+            }
+            None => {
+                invariant!(streams.len() < MAX_REWARD_STREAMS, TooManyRewardStreams);
+                streams.push(RewardStream {
+                    mint: reward_mint,
+                    vault: reward_vault,
+                    funder,
+                    amount,
+                    total_claimed: 0,
+                    refunded_at: 0,
+                });
+            }
+        }
+
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.funder_token_account.to_account_info(),
+                    to: self.reward_vault.to_account_info(),
+                    authority: self.funder.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        Ok(RewardStreamAddEvent {
+            governor: self.proposal.governor,
+            proposal: self.proposal.key(),
+            funder: self.funder.key(),
+            reward_mint,
+            amount,
+        })
+    }
+}
+
+impl<'info> Validate<'info> for AddRewardStream<'info> {
+    fn validate(&self) -> Result<()> {
+        let proposal_state = self.proposal.get_state()?;
+        invariant!(
+            proposal_state == ProposalState::Draft || proposal_state == ProposalState::Active,
+            "proposal must be draft or active"
+        );
+        // without a claim deadline, the funder could never get the unclaimed reward back.
+        invariant!(
+            self.proposal.reward_claim_period > 0,
+            RewardClaimPeriodRequired
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [govern::add_reward_stream].
+#[event]
+pub struct RewardStreamAddEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal.
+    #[index]
+    pub proposal: Pubkey,
+    /// The funder.
+    pub funder: Pubkey,
+    /// The reward mint.
+    pub reward_mint: Pubkey,
+    /// Amount added to the stream.
+    pub amount: u64,
+}
@@ -0,0 +1,60 @@
+use crate::*;
+
+/// Accounts for [govern::append_instruction_buffer].
+#[derive(Accounts)]
+#[instruction(instructions: Vec<ProposalInstruction>)]
+pub struct AppendInstructionBuffer<'info> {
+    /// The [InstructionBuffer].
+    #[account(
+        mut,
+        has_one = proposer,
+        realloc = 8 + InstructionBuffer::space(&instruction_buffer.instructions)
+            + instructions.iter().map(|ix| ix.space()).sum::<usize>(),
+        realloc::payer = payer,
+        realloc::zero = false
+    )]
+    pub instruction_buffer: Box<Account<'info, InstructionBuffer>>,
+    /// The [InstructionBuffer::proposer].
+    pub proposer: Signer<'info>,
+    /// Payer of the reallocation.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AppendInstructionBuffer<'info> {
+    pub fn append_instruction_buffer(
+        &mut self,
+        instructions: Vec<ProposalInstruction>,
+    ) -> Result<()> {
+        self.instruction_buffer.instructions.extend(instructions);
+
+        emit!(InstructionBufferAppendEvent {
+            governor: self.instruction_buffer.governor,
+            instruction_buffer: self.instruction_buffer.key(),
+            instructions_len: self.instruction_buffer.instructions.len() as u64,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for AppendInstructionBuffer<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Event called in [govern::append_instruction_buffer].
+#[event]
+pub struct InstructionBufferAppendEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The instruction buffer.
+    #[index]
+    pub instruction_buffer: Pubkey,
+    /// Number of instructions in the buffer after the append.
+    pub instructions_len: u64,
+}
@@ -0,0 +1,44 @@
+use crate::*;
+
+/// Accounts for [govern::apply_voting_pause].
+#[derive(Accounts)]
+pub struct ApplyVotingPause<'info> {
+    /// The [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// The [Proposal] whose voting was paused.
+    #[account(mut, has_one = governor)]
+    pub proposal: Box<Account<'info, Proposal>>,
+}
+
+impl<'info> ApplyVotingPause<'info> {
+    /// Extends the voting of the [Proposal] by the time voting was paused since it was last extended.
+    pub fn apply_voting_pause(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let proposal = &mut self.proposal;
+        let extension = unwrap_int!(proposal.voting_pause_extension(&self.governor, now));
+
+        let previous_voting_ends_at = proposal.voting_ends_at;
+        proposal.voting_ends_at = unwrap_int!(proposal.voting_ends_at.checked_add(extension));
+        proposal.voting_paused_duration = unwrap_int!(self.governor.voting_paused_duration_at(now));
+
+        emit!(ProposalVotingExtendEvent {
+            governor: proposal.governor,
+            proposal: proposal.key(),
+            previous_voting_ends_at,
+            voting_ends_at: proposal.voting_ends_at,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for ApplyVotingPause<'info> {
+    fn validate(&self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        invariant!(
+            unwrap_int!(self.proposal.voting_pause_extension(&self.governor, now)) > 0,
+            "voting of the proposal was not paused"
+        );
+        Ok(())
+    }
+}
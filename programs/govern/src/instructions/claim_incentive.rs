@@ -0,0 +1,124 @@
+use crate::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Accounts for [govern::claim_incentive].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimIncentive<'info> {
+    /// The [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// The [Proposal].
+    #[account(has_one = governor)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [Vote] of the voter.
+    #[account(has_one = proposal, has_one = voter)]
+    pub vote: Box<Account<'info, Vote>>,
+    /// The [OptionIncentive].
+    #[account(mut, has_one = proposal, has_one = vault)]
+    pub option_incentive: Box<Account<'info, OptionIncentive>>,
+    /// The [OptionIncentive::vault].
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+    /// The [IncentiveClaim], which prevents claiming twice.
+    #[account(
+        init,
+        seeds = [
+            b"IncentiveClaim".as_ref(),
+            option_incentive.key().as_ref(),
+            vote.key().as_ref()
+        ],
+        bump,
+        payer = voter,
+        space = 8 + IncentiveClaim::LEN
+    )]
+    pub incentive_claim: Box<Account<'info, IncentiveClaim>>,
+    /// The [Vote::voter].
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    /// Voter token account
+    #[account(mut, constraint = voter_token_account.owner == voter.key())]
+    pub voter_token_account: Account<'info, TokenAccount>,
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimIncentive<'info> {
+    pub fn claim_incentive(&mut self) -> Result<IncentiveClaimEvent> {
+        let option_incentive = &self.option_incentive;
+        let amount = unwrap_opt!(
+            self.proposal.get_option_incentive_share(
+                option_incentive.total_deposited,
+                &self.vote,
+                option_incentive.option
+            ),
+            "Math is overflow"
+        );
+        invariant!(amount > 0, "vote has no incentive to claim");
+
+        let seeds = option_incentive_seeds!(option_incentive);
+        let signer_seeds = &[&seeds[..]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.vault.to_account_info(),
+                    to: self.voter_token_account.to_account_info(),
+                    authority: option_incentive.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        let incentive_claim = &mut self.incentive_claim;
+        incentive_claim.option_incentive = self.option_incentive.key();
+        incentive_claim.vote = self.vote.key();
+        incentive_claim.amount = amount;
+        let option_incentive = &mut self.option_incentive;
+        option_incentive.total_claimed =
+            unwrap_int!(option_incentive.total_claimed.checked_add(amount));
+
+        Ok(IncentiveClaimEvent {
+            proposal: self.proposal.key(),
+            option_incentive: option_incentive.key(),
+            voter: self.voter.key(),
+            amount,
+        })
+    }
+}
+
+impl<'info> Validate<'info> for ClaimIncentive<'info> {
+    fn validate(&self) -> Result<()> {
+        self.governor.assert_not_paused(PAUSE_CLAIM_REWARD)?;
+        self.proposal.assert_voting_pause_applied(&self.governor)?;
+        let now = Clock::get()?.unix_timestamp;
+        invariant!(
+            unwrap_opt!(self.proposal.is_voting_over(now), "invalid state"),
+            "voting must be over"
+        );
+        invariant!(
+            now <= unwrap_opt!(self.proposal.incentive_claim_deadline()),
+            "incentive claim deadline has passed"
+        );
+        invariant!(!self.vote.unrewarded, VoteUnrewarded);
+        assert_keys_eq!(self.voter_token_account.mint, self.option_incentive.mint);
+        Ok(())
+    }
+}
+
+/// Event called in [govern::claim_incentive].
+#[event]
+pub struct IncentiveClaimEvent {
+    /// The proposal.
+    #[index]
+    pub proposal: Pubkey,
+    /// The option incentive.
+    #[index]
+    pub option_incentive: Pubkey,
+    /// The voter.
+    pub voter: Pubkey,
+    /// Amount claimed.
+    pub amount: u64,
+}
@@ -1,5 +1,8 @@
 use crate::*;
-use anchor_spl::token::{self, Token, TokenAccount};
+use anchor_spl::{
+    associated_token::get_associated_token_address,
+    token::{self, Token, TokenAccount},
+};
 /// Accounts for [govern::claim_reward].
 #[event_cpi]
 #[derive(Accounts)]
@@ -16,25 +19,40 @@ pub struct ClaimReward<'info> {
     /// The [Vote].
     #[account(mut, has_one = proposal, has_one = voter)]
     pub vote: Box<Account<'info, Vote>>,
-    /// Owner of the vault
-    /// TODO: check whether vote delegrate can claim on behalf of owner?
-    pub voter: Signer<'info>,
-    /// Voter token account
+    /// Owner of the vault. Must sign, unless the [Governor::locker] claims on behalf of the voter.
+    /// CHECK: checked by the has_one constraint of the [Vote]
+    pub voter: UncheckedAccount<'info>,
+    /// The [Governor::locker] claiming on behalf of the voter, if any. Defaults to the voter.
+    pub authority: Option<Signer<'info>>,
+    /// Voter token account. Must be the associated token account of the voter
+    /// if the [Governor::locker] is claiming.
     #[account(mut)]
     pub voter_token_account: Account<'info, TokenAccount>,
+    /// The [ProposalRewards] of the [Proposal], if any.
+    /// The vault and the voter token account of each [RewardStream] are passed as remaining accounts, in order.
+    #[account(mut, has_one = proposal)]
+    pub proposal_rewards: Option<Box<Account<'info, ProposalRewards>>>,
     /// Token program.
     pub token_program: Program<'info, Token>,
 }
 
 impl<'info> ClaimReward<'info> {
-    pub fn claim_reward(&mut self) -> Result<ClaimRewardEvent> {
+    pub fn claim_reward(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<ClaimRewardEvent> {
+        // the voting reward may have been claimed already with [govern::claim_rewards_batch],
+        // or swept back to the treasury
+        let voting_reward = if self.vote.claimed || self.proposal.reward_swept_at > 0 {
+            0
+        } else {
+            unwrap_opt!(
+                self.proposal.get_voting_reward(&self.vote),
+                "Math is overflow"
+            )
+        };
         self.vote.claimed = true;
 
-        let voting_reward = unwrap_opt!(
-            self.proposal.get_voting_reward(&self.vote),
-            "Math is overflow"
-        );
-
         if voting_reward != 0 {
             // transfer tokens to the escrow
             let seeds = governor_seeds!(self.governor);
@@ -60,42 +78,163 @@ impl<'info> ClaimReward<'info> {
             );
         }
 
+        let stream_rewards = self.claim_reward_streams(remaining_accounts)?;
+
         Ok(ClaimRewardEvent {
             governor: self.governor.key(),
             voter: self.voter.key(),
             proposal: self.proposal.key(),
             voting_reward,
+            stream_rewards,
         })
     }
+
+    /// Pays the voter's share of every [RewardStream] of the [ProposalRewards].
+    fn claim_reward_streams(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<Vec<u64>> {
+        let is_locker_claim = self.is_locker_claim();
+        let proposal_rewards = match &mut self.proposal_rewards {
+            Some(proposal_rewards) => proposal_rewards,
+            None => return Ok(vec![]),
+        };
+        invariant!(
+            remaining_accounts.len() == proposal_rewards.streams.len() * 2,
+            "missing reward stream accounts"
+        );
+
+        let seeds = proposal_rewards_seeds!(proposal_rewards);
+        let signer_seeds = &[&seeds[..]];
+        let mut stream_rewards = Vec::with_capacity(proposal_rewards.streams.len());
+        for (index, accounts) in remaining_accounts.chunks(2).enumerate() {
+            let stream = proposal_rewards.streams[index];
+            let (reward_vault, voter_token_account) = (&accounts[0], &accounts[1]);
+            assert_keys_eq!(stream.vault, reward_vault.key());
+            let voter_token = Account::<TokenAccount>::try_from(voter_token_account)?;
+            assert_keys_eq!(
+                voter_token.owner,
+                self.voter,
+                "Must be withdraw to owner wallet"
+            );
+            assert_keys_eq!(voter_token.mint, stream.mint);
+            if is_locker_claim {
+                assert_keys_eq!(
+                    voter_token_account.key(),
+                    get_associated_token_address(&self.voter.key(), &stream.mint),
+                    "Must be withdraw to owner associated token account"
+                );
+            }
+
+            let reward = if self.vote.claimed_streams & (1 << index) != 0 || stream.refunded_at > 0
+            {
+                0
+            } else {
+                unwrap_opt!(
+                    self.proposal.get_reward_share(stream.amount, &self.vote),
+                    "Math is overflow"
+                )
+            };
+            if reward != 0 {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        self.token_program.to_account_info(),
+                        token::Transfer {
+                            from: reward_vault.clone(),
+                            to: voter_token_account.clone(),
+                            authority: proposal_rewards.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    reward,
+                )?;
+            }
+            self.vote.claimed_streams |= 1 << index;
+            stream_rewards.push(reward);
+        }
+
+        for (stream, reward) in proposal_rewards.streams.iter_mut().zip(&stream_rewards) {
+            stream.total_claimed = unwrap_int!(stream.total_claimed.checked_add(*reward));
+        }
+        Ok(stream_rewards)
+    }
+
+    /// Whether the [Governor::locker] is claiming on behalf of the voter.
+    fn is_locker_claim(&self) -> bool {
+        self.authority
+            .as_ref()
+            .is_some_and(|authority| authority.key() != self.voter.key())
+    }
 }
 
 impl<'info> Validate<'info> for ClaimReward<'info> {
     fn validate(&self) -> Result<()> {
+        self.governor.assert_not_paused(PAUSE_CLAIM_REWARD)?;
         let voting_reward = self.proposal.voting_reward;
         assert_keys_eq!(voting_reward.reward_vault, self.reward_vault);
 
+        let now = Clock::get()?.unix_timestamp;
+        validate_reward_claim(&self.governor, &self.proposal, now)?;
+        invariant!(!self.vote.unrewarded, VoteUnrewarded);
+        // reward streams can still be claimed after the voting reward, or once it was swept
         invariant!(
-            unwrap_opt!(self.proposal.meets_quorum(), "Math is overflow"),
-            "Proposal doesn't meet quorum"
+            !self.vote.claimed || self.proposal_rewards.is_some(),
+            "Voter has claimed reward"
         );
-        let now = Clock::get()?.unix_timestamp;
-        let proposal_state = unwrap_opt!(self.proposal.state(now), "invalid state");
         invariant!(
-            proposal_state == ProposalState::Defeated
-                || proposal_state == ProposalState::Queued
-                || proposal_state == ProposalState::Succeeded,
-            "Proposal must be defeated, queued or succeeded"
+            self.proposal.reward_swept_at == 0 || self.proposal_rewards.is_some(),
+            RewardSwept
         );
-        invariant!(!self.vote.claimed, "Voter has claimed reward");
 
         invariant!(
             self.voter_token_account.owner == self.voter.key(),
             "Must be withdraw to owner wallet"
         );
+        if self.is_locker_claim() {
+            // the locker vouches for the claimer, e.g. the vote delegate of the escrow
+            assert_keys_eq!(
+                unwrap_opt!(self.authority.as_ref()).key(),
+                self.governor.locker
+            );
+            assert_keys_eq!(
+                self.voter_token_account,
+                get_associated_token_address(&self.voter.key(), &self.voter_token_account.mint),
+                "Must be withdraw to owner associated token account"
+            );
+        } else {
+            invariant!(self.voter.is_signer, "voter must sign");
+        }
         Ok(())
     }
 }
 
+/// Checks that the rewards of a [Proposal] can be claimed.
+pub(crate) fn validate_reward_claim(
+    governor: &Governor,
+    proposal: &Proposal,
+    now: i64,
+) -> Result<()> {
+    proposal.assert_voting_pause_applied(governor)?;
+    invariant!(
+        unwrap_opt!(proposal.meets_quorum(), "Math is overflow"),
+        "Proposal doesn't meet quorum"
+    );
+    let proposal_state = unwrap_opt!(proposal.state(now), "invalid state");
+    invariant!(
+        proposal_state == ProposalState::Defeated
+            || proposal_state == ProposalState::Queued
+            || proposal_state == ProposalState::Succeeded
+            || proposal_state == ProposalState::Executed
+            || proposal_state == ProposalState::Expired
+            || proposal_state == ProposalState::Vetoed,
+        "Proposal must be defeated, succeeded, queued, executed or expired"
+    );
+    if let Some(claim_deadline) = unwrap_opt!(proposal.reward_claim_deadline()) {
+        invariant!(now <= claim_deadline, RewardClaimDeadlinePassed);
+    }
+    Ok(())
+}
+
 /// Event called in [govern::claim_reward].
 #[event]
 pub struct ClaimRewardEvent {
@@ -108,4 +247,7 @@ pub struct ClaimRewardEvent {
     pub proposal: Pubkey,
     /// Voting reward
     pub voting_reward: u64,
+    /// Reward of each [RewardStream].
+    /// Always empty with [govern::claim_rewards_batch], which only pays the voting reward.
+    pub stream_rewards: Vec<u64>,
 }
@@ -0,0 +1,145 @@
+use crate::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Accounts for [govern::claim_rewards_batch].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimRewardsBatch<'info> {
+    /// The [Governor]
+    pub governor: Box<Account<'info, Governor>>,
+    /// Owner of the [Vote]s
+    pub voter: Signer<'info>,
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ClaimRewardsBatch<'info> {
+    /// Claims the voting rewards of many [Vote]s at once.
+    /// Only the [Proposal::voting_reward] is paid: the [RewardStream]s of a [ProposalRewards]
+    /// are still claimed per proposal with [govern::claim_reward].
+    ///
+    /// The remaining accounts are `vote_count` pairs of ([Proposal], [Vote]), followed by a pair of
+    /// (reward vault, voter token account) for each distinct reward vault of the proposals.
+    pub fn claim_rewards_batch(
+        &mut self,
+        vote_count: u8,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<(Vec<ClaimRewardEvent>, ClaimRewardsBatchEvent)> {
+        let vote_accounts_len = unwrap_int!((vote_count as usize).checked_mul(2));
+        invariant!(vote_count > 0, "no votes to claim");
+        invariant!(
+            remaining_accounts.len() > vote_accounts_len,
+            "invalid remaining accounts"
+        );
+        let (vote_accounts, vault_accounts) = remaining_accounts.split_at(vote_accounts_len);
+        invariant!(
+            vault_accounts.chunks_exact(2).remainder().is_empty(),
+            "invalid remaining accounts"
+        );
+
+        let reward_vaults: Vec<Pubkey> = vault_accounts
+            .chunks(2)
+            .map(|accounts| accounts[0].key())
+            .collect();
+        let mut total_rewards = vec![0u64; reward_vaults.len()];
+        let mut events = Vec::with_capacity(vote_count as usize);
+
+        let now = Clock::get()?.unix_timestamp;
+        for accounts in vote_accounts.chunks(2) {
+            let mut proposal = Account::<Proposal>::try_from(&accounts[0])?;
+            let mut vote = Account::<Vote>::try_from(&accounts[1])?;
+            assert_keys_eq!(proposal.governor, self.governor);
+            assert_keys_eq!(vote.proposal, proposal);
+            assert_keys_eq!(vote.voter, self.voter);
+            validate_reward_claim(&self.governor, &proposal, now)?;
+            invariant!(proposal.reward_swept_at == 0, RewardSwept);
+            invariant!(!vote.claimed, "Voter has claimed reward");
+            invariant!(!vote.unrewarded, VoteUnrewarded);
+
+            let vault_index = unwrap_opt!(
+                reward_vaults
+                    .iter()
+                    .position(|reward_vault| *reward_vault == proposal.voting_reward.reward_vault),
+                "missing reward vault"
+            );
+            let voting_reward = unwrap_opt!(proposal.get_voting_reward(&vote), "Math is overflow");
+            total_rewards[vault_index] =
+                unwrap_int!(total_rewards[vault_index].checked_add(voting_reward));
+
+            vote.claimed = true;
+            proposal.total_claimed_reward =
+                unwrap_int!(proposal.total_claimed_reward.checked_add(voting_reward));
+            vote.exit(&crate::ID)?;
+            proposal.exit(&crate::ID)?;
+
+            events.push(ClaimRewardEvent {
+                governor: self.governor.key(),
+                voter: self.voter.key(),
+                proposal: proposal.key(),
+                voting_reward,
+                stream_rewards: vec![],
+            });
+        }
+
+        let seeds = governor_seeds!(self.governor);
+        let signer_seeds = &[&seeds[..]];
+        for (accounts, total_reward) in vault_accounts.chunks(2).zip(&total_rewards) {
+            let (reward_vault, voter_token_account) = (&accounts[0], &accounts[1]);
+            let voter_token = Account::<TokenAccount>::try_from(voter_token_account)?;
+            assert_keys_eq!(
+                voter_token.owner,
+                self.voter,
+                "Must be withdraw to owner wallet"
+            );
+            if *total_reward == 0 {
+                continue;
+            }
+            token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    token::Transfer {
+                        from: reward_vault.clone(),
+                        to: voter_token_account.clone(),
+                        authority: self.governor.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                *total_reward,
+            )?;
+        }
+
+        Ok((
+            events,
+            ClaimRewardsBatchEvent {
+                governor: self.governor.key(),
+                voter: self.voter.key(),
+                vote_count,
+                reward_vaults,
+                total_rewards,
+            },
+        ))
+    }
+}
+
+impl<'info> Validate<'info> for ClaimRewardsBatch<'info> {
+    fn validate(&self) -> Result<()> {
+        self.governor.assert_not_paused(PAUSE_CLAIM_REWARD)
+    }
+}
+
+/// Event called in [govern::claim_rewards_batch].
+/// The totals only cover the [Proposal::voting_reward], not the [RewardStream]s.
+#[event]
+pub struct ClaimRewardsBatchEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// Voter
+    pub voter: Pubkey,
+    /// Number of votes claimed
+    pub vote_count: u8,
+    /// Reward vaults the rewards were paid from
+    pub reward_vaults: Vec<Pubkey>,
+    /// Total reward paid from each reward vault
+    pub total_rewards: Vec<u64>,
+}
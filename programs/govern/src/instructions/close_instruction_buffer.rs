@@ -0,0 +1,40 @@
+use crate::*;
+
+/// Accounts for [govern::close_instruction_buffer].
+#[derive(Accounts)]
+pub struct CloseInstructionBuffer<'info> {
+    /// The [InstructionBuffer] to close.
+    #[account(mut, has_one = proposer, close = proposer)]
+    pub instruction_buffer: Box<Account<'info, InstructionBuffer>>,
+    /// The [InstructionBuffer::proposer]. Receives the rent of the buffer.
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+}
+
+impl<'info> CloseInstructionBuffer<'info> {
+    pub fn close_instruction_buffer(&mut self) -> Result<()> {
+        emit!(InstructionBufferCloseEvent {
+            governor: self.instruction_buffer.governor,
+            instruction_buffer: self.instruction_buffer.key(),
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for CloseInstructionBuffer<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Event called in [govern::close_instruction_buffer].
+#[event]
+pub struct InstructionBufferCloseEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The closed instruction buffer.
+    #[index]
+    pub instruction_buffer: Pubkey,
+}
@@ -0,0 +1,109 @@
+use crate::*;
+
+/// Accounts for [govern::close_proposal].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseProposal<'info> {
+    /// The [Proposal] to close.
+    #[account(mut, has_one = proposer, close = proposer)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [ProposalMeta] of the [Proposal], if any.
+    #[account(mut, has_one = proposal, close = proposer)]
+    pub proposal_meta: Option<Box<Account<'info, ProposalMeta>>>,
+    /// The [OptionProposalMeta] of the [Proposal], if any.
+    #[account(mut, has_one = proposal, close = proposer)]
+    pub option_proposal_meta: Option<Box<Account<'info, OptionProposalMeta>>>,
+    /// The [ProposalBond] of the [Proposal], which must be settled first.
+    /// CHECK: must be empty
+    #[account(seeds = [b"ProposalBond".as_ref(), proposal.key().as_ref()], bump)]
+    pub proposal_bond: UncheckedAccount<'info>,
+    /// The [ProposalRewards] of the [Proposal], whose streams must all be refunded first.
+    /// CHECK: must be empty, or a [ProposalRewards] checked in validate
+    #[account(seeds = [b"ProposalRewards".as_ref(), proposal.key().as_ref()], bump)]
+    pub proposal_rewards: UncheckedAccount<'info>,
+    /// The [Proposal::proposer]. Receives the rent of the closed accounts.
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+}
+
+impl<'info> CloseProposal<'info> {
+    /// Closes the [Proposal] and its metadata.
+    ///
+    /// If the proposal has [OptionProposalInstructions], the remaining accounts must be the
+    /// [OptionProposalInstructions] addresses of every option, from 1 to [Proposal::max_option].
+    /// The ones that were created are closed as well.
+    pub fn close_proposal(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<ProposalCloseEvent> {
+        if self.proposal.has_option_instructions {
+            invariant!(
+                remaining_accounts.len() == self.proposal.max_option as usize,
+                "missing option proposal instructions"
+            );
+            for (option, account) in (1..=self.proposal.max_option).zip(remaining_accounts) {
+                let (option_proposal_instructions, _bump) = Pubkey::find_program_address(
+                    &[
+                        b"OptionProposalInstructions".as_ref(),
+                        self.proposal.key().as_ref(),
+                        &[option],
+                    ],
+                    &crate::ID,
+                );
+                assert_keys_eq!(account.key(), option_proposal_instructions);
+                if account.owner == &crate::ID {
+                    Account::<OptionProposalInstructions>::try_from(account)?
+                        .close(self.proposer.to_account_info())?;
+                }
+            }
+        } else {
+            invariant!(remaining_accounts.is_empty(), "invalid remaining accounts");
+        }
+
+        Ok(ProposalCloseEvent {
+            governor: self.proposal.governor,
+            proposal: self.proposal.key(),
+        })
+    }
+}
+
+impl<'info> Validate<'info> for CloseProposal<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.proposal.get_state()? == ProposalState::Canceled,
+            "proposal must be canceled"
+        );
+        invariant!(
+            self.proposal_bond.data_is_empty(),
+            "proposal bond must be settled"
+        );
+        // funders could no longer get their tokens back once the proposal is closed.
+        if !self.proposal_rewards.data_is_empty() {
+            let proposal_rewards =
+                Account::<ProposalRewards>::try_from(&self.proposal_rewards.to_account_info())?;
+            invariant!(
+                proposal_rewards
+                    .streams
+                    .iter()
+                    .all(|stream| stream.refunded_at != 0),
+                "reward streams must be refunded"
+            );
+        }
+        invariant!(
+            self.proposal.funded_option_incentives == 0,
+            "option incentive deposits must be refunded"
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [govern::close_proposal].
+#[event]
+pub struct ProposalCloseEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The closed proposal.
+    #[index]
+    pub proposal: Pubkey,
+}
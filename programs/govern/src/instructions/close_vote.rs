@@ -0,0 +1,95 @@
+use crate::*;
+use smart_wallet::Transaction;
+
+/// Accounts for [govern::close_vote].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseVote<'info> {
+    /// The [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// The [Proposal].
+    #[account(has_one = governor)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [Vote] to close.
+    #[account(mut, has_one = proposal, has_one = voter, close = voter)]
+    pub vote: Box<Account<'info, Vote>>,
+    /// The [VoteCommitment] of the [Vote], if any.
+    #[account(mut, has_one = vote, close = voter)]
+    pub vote_commitment: Option<Box<Account<'info, VoteCommitment>>>,
+    /// The [ProposalRewards] of the [Proposal], whose reward streams must be claimed first.
+    /// CHECK: deserialized if it exists
+    #[account(seeds = [b"ProposalRewards".as_ref(), proposal.key().as_ref()], bump)]
+    pub proposal_rewards: UncheckedAccount<'info>,
+    /// The [Proposal::queued_transaction], required if the [Proposal] is [ProposalState::Queued].
+    pub queued_transaction: Option<Box<Account<'info, Transaction>>>,
+    /// The [Vote::voter]. Receives the rent of the closed accounts.
+    #[account(mut)]
+    pub voter: Signer<'info>,
+}
+
+impl<'info> CloseVote<'info> {
+    pub fn close_vote(&mut self) -> Result<VoteCloseEvent> {
+        Ok(VoteCloseEvent {
+            governor: self.proposal.governor,
+            proposal: self.proposal.key(),
+            voter: self.voter.key(),
+            vote: self.vote.key(),
+        })
+    }
+}
+
+impl<'info> Validate<'info> for CloseVote<'info> {
+    fn validate(&self) -> Result<()> {
+        self.proposal.assert_voting_pause_applied(&self.governor)?;
+        let proposal_state = self.proposal.get_state()?;
+        if proposal_state == ProposalState::Queued {
+            let queued_transaction =
+                unwrap_opt!(self.queued_transaction.as_ref(), ProposalNotFinal);
+            assert_keys_eq!(queued_transaction.key(), self.proposal.queued_transaction);
+            invariant!(
+                queued_transaction.eta == smart_wallet::NO_ETA,
+                ProposalNotFinal
+            );
+        } else {
+            invariant!(proposal_state.is_final(), ProposalNotFinal);
+        }
+
+        let proposal_rewards = if self.proposal_rewards.data_is_empty() {
+            None
+        } else {
+            Some(Account::<ProposalRewards>::try_from(
+                &self.proposal_rewards.to_account_info(),
+            )?)
+        };
+        invariant!(
+            !unwrap_opt!(self
+                .proposal
+                .has_unclaimed_reward(&self.vote, proposal_rewards.as_deref())),
+            "voter has an unclaimed reward"
+        );
+        if self.proposal.has_option_incentives {
+            invariant!(
+                Clock::get()?.unix_timestamp
+                    > unwrap_opt!(self.proposal.incentive_claim_deadline()),
+                "option incentives can still be claimed"
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Event called in [govern::close_vote].
+#[event]
+pub struct VoteCloseEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal.
+    #[index]
+    pub proposal: Pubkey,
+    /// The voter.
+    #[index]
+    pub voter: Pubkey,
+    /// The closed vote.
+    pub vote: Pubkey,
+}
@@ -0,0 +1,82 @@
+use crate::*;
+
+/// Accounts for [govern::commit_vote].
+#[derive(Accounts)]
+pub struct CommitVote<'info> {
+    /// The [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// The [Proposal].
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [Vote].
+    pub vote: Box<Account<'info, Vote>>,
+    /// The [VoteCommitment].
+    #[account(mut, has_one = vote)]
+    pub vote_commitment: Box<Account<'info, VoteCommitment>>,
+    /// The [Governor::locker].
+    pub locker: Signer<'info>,
+}
+
+impl<'info> CommitVote<'info> {
+    /// Commits a hidden vote. A later commit replaces the previous one.
+    pub fn commit_vote(&mut self, commitment: [u8; 32], voting_power: u64) -> Result<()> {
+        let vote_commitment = &mut self.vote_commitment;
+        vote_commitment.commitment = commitment;
+        vote_commitment.voting_power = voting_power;
+
+        emit!(VoteCommitEvent {
+            governor: self.governor.key(),
+            proposal: self.proposal.key(),
+            voter: self.vote.voter,
+            vote: self.vote.key(),
+            commitment,
+            voting_power,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for CommitVote<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.governor.locker, self.locker);
+        self.governor.assert_not_paused(PAUSE_SET_VOTE)?;
+        self.proposal.assert_voting_pause_applied(&self.governor)?;
+        assert_keys_eq!(
+            self.governor,
+            self.proposal.governor,
+            "proposal should be under the governor"
+        );
+        assert_keys_eq!(
+            self.vote.proposal,
+            self.proposal,
+            "vote proposal should match"
+        );
+        invariant!(self.proposal.is_commit_reveal(), NotCommitRevealProposal);
+        invariant!(
+            self.proposal.get_state()? == ProposalState::Active,
+            ProposalNotActive
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [govern::commit_vote].
+#[event]
+pub struct VoteCommitEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal being voted on.
+    #[index]
+    pub proposal: Pubkey,
+    /// The voter.
+    #[index]
+    pub voter: Pubkey,
+    /// The vote.
+    #[index]
+    pub vote: Pubkey,
+    /// Hash of the side, voting power and salt.
+    pub commitment: [u8; 32],
+    /// The vote's voting_power.
+    pub voting_power: u64,
+}
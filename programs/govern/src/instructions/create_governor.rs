@@ -33,10 +33,7 @@ impl<'info> CreateGovernor<'info> {
         locker: Pubkey,
         params: GovernanceParameters,
     ) -> Result<()> {
-        invariant!(
-            params.timelock_delay_seconds >= 0,
-            "timelock delay must be at least 0 seconds"
-        );
+        params.validate()?;
 
         let governor = &mut self.governor;
         governor.base = self.base.key();
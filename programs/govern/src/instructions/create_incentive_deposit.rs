@@ -0,0 +1,44 @@
+use crate::*;
+
+/// Accounts for [govern::create_incentive_deposit].
+#[derive(Accounts)]
+#[instruction(depositor: Pubkey)]
+pub struct CreateIncentiveDeposit<'info> {
+    /// The [OptionIncentive].
+    pub option_incentive: Box<Account<'info, OptionIncentive>>,
+    /// The [IncentiveDeposit].
+    #[account(
+        init,
+        seeds = [
+            b"IncentiveDeposit".as_ref(),
+            option_incentive.key().as_ref(),
+            depositor.as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + IncentiveDeposit::LEN
+    )]
+    pub incentive_deposit: Box<Account<'info, IncentiveDeposit>>,
+    /// Payer of the [IncentiveDeposit].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateIncentiveDeposit<'info> {
+    pub fn create_incentive_deposit(&mut self, bump: u8, depositor: Pubkey) -> Result<()> {
+        let incentive_deposit = &mut self.incentive_deposit;
+        incentive_deposit.option_incentive = self.option_incentive.key();
+        incentive_deposit.depositor = depositor;
+        incentive_deposit.bump = bump;
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for CreateIncentiveDeposit<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use crate::*;
+
+/// Accounts for [govern::create_instruction_buffer].
+#[derive(Accounts)]
+pub struct CreateInstructionBuffer<'info> {
+    /// The [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// The [InstructionBuffer].
+    #[account(
+        init,
+        seeds = [
+            b"InstructionBuffer".as_ref(),
+            governor.key().as_ref(),
+            proposer.key().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + InstructionBuffer::space(&[])
+    )]
+    pub instruction_buffer: Box<Account<'info, InstructionBuffer>>,
+    /// The proposer who owns the buffer.
+    pub proposer: Signer<'info>,
+    /// Payer of the [InstructionBuffer].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateInstructionBuffer<'info> {
+    pub fn create_instruction_buffer(&mut self, bump: u8) -> Result<()> {
+        let instruction_buffer = &mut self.instruction_buffer;
+        instruction_buffer.governor = self.governor.key();
+        instruction_buffer.proposer = self.proposer.key();
+        instruction_buffer.bump = bump;
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for CreateInstructionBuffer<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use crate::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+/// Accounts for [govern::create_option_incentive].
+#[derive(Accounts)]
+#[instruction(option: u8)]
+pub struct CreateOptionIncentive<'info> {
+    /// The [Proposal].
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [OptionIncentive].
+    #[account(
+        init,
+        seeds = [
+            b"OptionIncentive".as_ref(),
+            proposal.key().as_ref(),
+            &[option],
+            mint.key().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + OptionIncentive::LEN
+    )]
+    pub option_incentive: Box<Account<'info, OptionIncentive>>,
+    /// Incentive mint.
+    pub mint: Account<'info, Mint>,
+    /// Incentive vault, owned by the [OptionIncentive].
+    #[account(
+        constraint = vault.owner == option_incentive.key(),
+        constraint = vault.mint == mint.key()
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    /// Payer of the [OptionIncentive].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateOptionIncentive<'info> {
+    pub fn create_option_incentive(&mut self, bump: u8, option: u8) -> Result<()> {
+        invariant!(option <= self.proposal.max_option, InvalidVoteSide);
+
+        let option_incentive = &mut self.option_incentive;
+        option_incentive.proposal = self.proposal.key();
+        option_incentive.option = option;
+        option_incentive.mint = self.mint.key();
+        option_incentive.vault = self.vault.key();
+        option_incentive.bump = bump;
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for CreateOptionIncentive<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
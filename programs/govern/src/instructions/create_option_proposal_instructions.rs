@@ -0,0 +1,97 @@
+use crate::*;
+
+/// Accounts for [govern::create_option_proposal_instructions].
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(option: u8, instructions: Vec<ProposalInstruction>)]
+pub struct CreateOptionProposalInstructions<'info> {
+    /// The [Proposal].
+    #[account(mut)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// Proposer of the proposal.
+    pub proposer: Signer<'info>,
+    /// The [OptionProposalInstructions].
+    #[account(
+        init,
+        seeds = [
+            b"OptionProposalInstructions".as_ref(),
+            proposal.key().as_ref(),
+            &[option]
+        ],
+        bump,
+        payer = payer,
+        space = 8 + OptionProposalInstructions::space(&instructions)
+    )]
+    pub option_proposal_instructions: Box<Account<'info, OptionProposalInstructions>>,
+    /// Payer of the [OptionProposalInstructions].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateOptionProposalInstructions<'info> {
+    pub fn create_option_proposal_instructions(
+        &mut self,
+        bump: u8,
+        option: u8,
+        instructions: Vec<ProposalInstruction>,
+    ) -> Result<OptionProposalInstructionsCreateEvent> {
+        invariant!(
+            option >= 1 && option <= self.proposal.max_option,
+            InvalidVoteSide
+        );
+
+        let option_proposal_instructions = &mut self.option_proposal_instructions;
+        option_proposal_instructions.proposal = self.proposal.key();
+        option_proposal_instructions.option = option;
+        option_proposal_instructions.bump = bump;
+        option_proposal_instructions.instructions = instructions.clone();
+
+        let proposal = &mut self.proposal;
+        proposal.has_option_instructions = true;
+        proposal.option_instructions_count =
+            unwrap_int!(proposal.option_instructions_count.checked_add(1));
+
+        Ok(OptionProposalInstructionsCreateEvent {
+            governor: self.proposal.governor,
+            proposal: self.proposal.key(),
+            option,
+            instructions,
+        })
+    }
+}
+
+impl<'info> Validate<'info> for CreateOptionProposalInstructions<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.proposer, self.proposal.proposer);
+        invariant!(
+            self.proposal.proposal_type == u8::from(ProposalType::Option),
+            NotOptionProposal
+        );
+        invariant!(
+            self.proposal.get_state()? == ProposalState::Draft,
+            ProposalNotDraft
+        );
+        invariant!(
+            self.proposal.instructions.is_empty(),
+            "proposal already has shared instructions"
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [govern::create_option_proposal_instructions].
+#[event]
+pub struct OptionProposalInstructionsCreateEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal.
+    #[index]
+    pub proposal: Pubkey,
+    /// The option the instructions belong to.
+    pub option: u8,
+    /// Instructions executed if the option wins.
+    pub instructions: Vec<ProposalInstruction>,
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use anchor_spl::token::{self, Token, TokenAccount};
 
 /// Accounts for [govern::create_proposal].
 #[event_cpi]
@@ -8,6 +9,10 @@ pub struct CreateProposal<'info> {
     /// The [Governor].
     #[account(mut, has_one = smart_wallet)]
     pub governor: Box<Account<'info, Governor>>,
+    /// The [InstructionBuffer] of the proposer, if any. Its instructions are appended to the
+    /// `instructions` of the [Proposal], and it is closed to the payer.
+    #[account(mut, has_one = governor, has_one = proposer, close = payer)]
+    pub instruction_buffer: Option<Box<Account<'info, InstructionBuffer>>>,
     /// The [Proposal].
     #[account(
         init,
@@ -18,7 +23,8 @@ pub struct CreateProposal<'info> {
         ],
         bump,
         payer = payer,
-        space = 8 + Proposal::space(max_option + 1, instructions), // yes/no proposal only has 2 options, plus 1 for abstain vote
+        space = 8 + Proposal::space(max_option + 1, instructions) // yes/no proposal only has 2 options, plus 1 for abstain vote
+            + instruction_buffer.as_ref().map(|buffer| buffer.instructions_space()).unwrap_or(0),
     )]
     pub proposal: Box<Account<'info, Proposal>>,
     /// smart wallet of governor
@@ -31,28 +37,79 @@ pub struct CreateProposal<'info> {
     pub payer: Signer<'info>,
     /// System program.
     pub system_program: Program<'info, System>,
+
+    /// The [ProposalBond], required if the [Governor] requires a proposal bond.
+    #[account(
+        init,
+        seeds = [
+            b"ProposalBond".as_ref(),
+            proposal.key().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + ProposalBond::LEN
+    )]
+    pub proposal_bond: Option<Box<Account<'info, ProposalBond>>>,
+    /// Bond vault, owned by the [ProposalBond].
+    #[account(mut)]
+    pub bond_vault: Option<Account<'info, TokenAccount>>,
+    /// The token account the bond is transferred from, owned by the proposer.
+    #[account(mut)]
+    pub proposer_bond_account: Option<Account<'info, TokenAccount>>,
+    /// Token program.
+    pub token_program: Option<Program<'info, Token>>,
 }
 
 impl<'info> CreateProposal<'info> {
     pub fn create_proposal(
         &mut self,
         bump: u8,
+        bond_bump: u8,
         proposal_type: u8,
         max_option: u8,
         instructions: Vec<ProposalInstruction>,
     ) -> Result<ProposalCreateEvent> {
+        let mut proposal_instructions = instructions.clone();
+        let mut buffered_instructions_len = 0;
+        if let Some(instruction_buffer) = &self.instruction_buffer {
+            proposal_instructions.extend(instruction_buffer.instructions.iter().cloned());
+            buffered_instructions_len = instruction_buffer.instructions.len() as u64;
+        }
+
         // validate proposal type
         let proposal_type_state = ProposalType::try_from(proposal_type)?;
         match proposal_type_state {
             ProposalType::YesNo => {
                 invariant!(max_option == 2, InvalidMaxOption);
             }
+            ProposalType::Optimistic => {
+                invariant!(max_option == 2, InvalidMaxOption);
+                // governors that never set their voting parameters have no veto threshold
+                let voting_params = &self.governor.voting_params;
+                invariant!(
+                    voting_params.optimistic_veto_mode
+                        == u8::from(QuorumMode::LockedSupplyFraction)
+                        || voting_params.optimistic_veto_votes > 0,
+                    InvalidOptimisticVetoVotes
+                );
+            }
             ProposalType::Option => {
                 invariant!(
                     max_option >= 2 && max_option <= MAX_OPTION,
                     InvalidMaxOption
                 );
             }
+            ProposalType::Approval => {
+                invariant!(
+                    max_option >= 2 && max_option <= MAX_OPTION,
+                    InvalidMaxOption
+                );
+                // a commitment only hides a single side
+                invariant!(
+                    self.governor.voting_params.reveal_period == 0,
+                    CommitRevealProposal
+                );
+            }
         }
 
         let governor = &mut self.governor;
@@ -65,6 +122,17 @@ impl<'info> CreateProposal<'info> {
         proposal.proposer = self.proposer.key();
 
         proposal.quorum_votes = governor.params.quorum_votes;
+        proposal.quorum_mode = governor.voting_params.quorum_mode;
+        proposal.quorum_bps = governor.voting_params.quorum_bps;
+        if proposal_type_state == ProposalType::Optimistic {
+            // the veto threshold is computed like a quorum when the proposal is activated
+            proposal.optimistic_veto_votes = governor.voting_params.optimistic_veto_votes;
+            proposal.optimistic_veto_mode = governor.voting_params.optimistic_veto_mode;
+            proposal.optimistic_veto_bps = governor.voting_params.optimistic_veto_bps;
+        }
+        proposal.approval_threshold_bps = governor.voting_params.approval_threshold_bps;
+        proposal.reveal_period = governor.voting_params.reveal_period;
+        proposal.late_quorum_extension = governor.voting_params.late_quorum_extension;
         proposal.created_at = Clock::get()?.unix_timestamp;
         proposal.canceled_at = 0;
         proposal.activated_at = 0;
@@ -74,15 +142,49 @@ impl<'info> CreateProposal<'info> {
         proposal.queued_transaction = Pubkey::default();
 
         proposal.voting_reward = governor.voting_reward;
+        proposal.reward_claim_period = governor.reward_claim_period;
 
-        proposal.instructions = instructions.clone();
+        proposal.instructions = proposal_instructions;
 
         proposal.proposal_type = proposal_type;
         proposal.max_option = max_option;
+        // raised for approval proposals with [govern::set_proposal_max_selections]
+        proposal.max_selections = 1;
         proposal.option_votes = vec![0; (max_option + 1) as usize]; // plus 1 for abstain vote
 
         governor.proposal_count += 1;
 
+        if let (
+            Some(proposal_bond),
+            Some(bond_vault),
+            Some(proposer_bond_account),
+            Some(token_program),
+        ) = (
+            &mut self.proposal_bond,
+            &self.bond_vault,
+            &self.proposer_bond_account,
+            &self.token_program,
+        ) {
+            let amount = governor.proposal_bond.amount;
+            token::transfer(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    token::Transfer {
+                        from: proposer_bond_account.to_account_info(),
+                        to: bond_vault.to_account_info(),
+                        authority: self.proposer.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+
+            proposal_bond.proposal = proposal.key();
+            proposal_bond.proposer = self.proposer.key();
+            proposal_bond.vault = bond_vault.key();
+            proposal_bond.bump = bond_bump;
+            proposal_bond.amount = amount;
+        }
+
         Ok(ProposalCreateEvent {
             governor: governor.key(),
             proposal: proposal.key(),
@@ -91,14 +193,33 @@ impl<'info> CreateProposal<'info> {
             max_option,
             index: proposal.index,
             instructions,
+            buffered_instructions_len,
         })
     }
 }
 
 impl<'info> Validate<'info> for CreateProposal<'info> {
     fn validate(&self) -> Result<()> {
+        self.governor.assert_not_paused(PAUSE_CREATE_PROPOSAL)?;
         // validate proposer is one of owners of smart-wallet
         self.smart_wallet.owner_index(self.proposer.key())?;
+
+        let bond_params = self.governor.proposal_bond;
+        if bond_params.amount > 0 {
+            let proposal_bond = unwrap_opt!(self.proposal_bond.as_ref(), ProposalBondRequired);
+            let bond_vault = unwrap_opt!(self.bond_vault.as_ref(), ProposalBondRequired);
+            invariant!(
+                self.proposer_bond_account.is_some() && self.token_program.is_some(),
+                ProposalBondRequired
+            );
+            assert_keys_eq!(bond_vault.owner, proposal_bond.key());
+            assert_keys_eq!(bond_vault.mint, bond_params.mint);
+        } else {
+            invariant!(
+                self.proposal_bond.is_none(),
+                "proposal bond is not required"
+            );
+        }
         Ok(())
     }
 }
@@ -120,6 +241,8 @@ pub struct ProposalCreateEvent {
     pub max_option: u8,
     /// The index of the [Proposal].
     pub index: u64,
-    /// Instructions in the proposal.
+    /// Instructions in the proposal, excluding the ones of the [InstructionBuffer].
     pub instructions: Vec<ProposalInstruction>,
+    /// Number of instructions appended from the [InstructionBuffer], which may be too many to fit in the event.
+    pub buffered_instructions_len: u64,
 }
@@ -18,9 +18,7 @@ pub struct CreateProposalMeta<'info> {
         ],
         bump,
         payer = payer,
-        space = 8 + std::mem::size_of::<Pubkey>()
-            + 4 + title.as_bytes().len()
-            + 4 + description_link.as_bytes().len()
+        space = 8 + ProposalMeta::space(&title, &description_link)
     )]
     pub proposal_meta: Box<Account<'info, ProposalMeta>>,
     /// Payer of the [ProposalMeta].
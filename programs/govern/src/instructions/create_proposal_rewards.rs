@@ -0,0 +1,41 @@
+use crate::*;
+
+/// Accounts for [govern::create_proposal_rewards].
+#[derive(Accounts)]
+pub struct CreateProposalRewards<'info> {
+    /// The [Proposal].
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [ProposalRewards].
+    #[account(
+        init,
+        seeds = [
+            b"ProposalRewards".as_ref(),
+            proposal.key().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + ProposalRewards::LEN
+    )]
+    pub proposal_rewards: Box<Account<'info, ProposalRewards>>,
+    /// Payer of the [ProposalRewards].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateProposalRewards<'info> {
+    pub fn create_proposal_rewards(&mut self, bump: u8) -> Result<()> {
+        let proposal_rewards = &mut self.proposal_rewards;
+        proposal_rewards.proposal = self.proposal.key();
+        proposal_rewards.bump = bump;
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for CreateProposalRewards<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use crate::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Accounts for [govern::deposit_incentive].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DepositIncentive<'info> {
+    /// The [Proposal].
+    #[account(mut)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [OptionIncentive].
+    #[account(mut, has_one = proposal, has_one = vault)]
+    pub option_incentive: Box<Account<'info, OptionIncentive>>,
+    /// The [OptionIncentive::vault].
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+    /// The [IncentiveDeposit] of the depositor.
+    #[account(mut, has_one = option_incentive, has_one = depositor)]
+    pub incentive_deposit: Box<Account<'info, IncentiveDeposit>>,
+    /// The depositor.
+    pub depositor: Signer<'info>,
+    /// The token account the deposit is transferred from.
+    #[account(mut)]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> DepositIncentive<'info> {
+    pub fn deposit_incentive(&mut self, amount: u64) -> Result<IncentiveDepositEvent> {
+        invariant!(amount > 0, "amount must be greater than 0");
+
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.depositor_token_account.to_account_info(),
+                    to: self.vault.to_account_info(),
+                    authority: self.depositor.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let incentive_deposit = &mut self.incentive_deposit;
+        incentive_deposit.amount = unwrap_int!(incentive_deposit.amount.checked_add(amount));
+        let option_incentive = &mut self.option_incentive;
+        let proposal = &mut self.proposal;
+        if option_incentive.total_deposited == 0 {
+            proposal.funded_option_incentives =
+                unwrap_int!(proposal.funded_option_incentives.checked_add(1));
+        }
+        option_incentive.total_deposited =
+            unwrap_int!(option_incentive.total_deposited.checked_add(amount));
+        proposal.has_option_incentives = true;
+
+        Ok(IncentiveDepositEvent {
+            proposal: self.proposal.key(),
+            option_incentive: option_incentive.key(),
+            depositor: self.depositor.key(),
+            option: option_incentive.option,
+            mint: option_incentive.mint,
+            amount,
+        })
+    }
+}
+
+impl<'info> Validate<'info> for DepositIncentive<'info> {
+    fn validate(&self) -> Result<()> {
+        let proposal_state = self.proposal.get_state()?;
+        invariant!(
+            proposal_state == ProposalState::Draft || proposal_state == ProposalState::Active,
+            "proposal must be draft or active"
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [govern::deposit_incentive].
+#[event]
+pub struct IncentiveDepositEvent {
+    /// The proposal.
+    #[index]
+    pub proposal: Pubkey,
+    /// The option incentive.
+    #[index]
+    pub option_incentive: Pubkey,
+    /// The depositor.
+    pub depositor: Pubkey,
+    /// The option the deposit is tied to.
+    pub option: u8,
+    /// The incentive mint.
+    pub mint: Pubkey,
+    /// Amount deposited.
+    pub amount: u64,
+}
@@ -0,0 +1,80 @@
+use crate::*;
+use smart_wallet::Transaction;
+
+/// Accounts for [govern::finalize_proposal].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FinalizeProposal<'info> {
+    /// The [Governor].
+    #[account(has_one = smart_wallet)]
+    pub governor: Box<Account<'info, Governor>>,
+    /// The [Proposal] to finalize.
+    #[account(mut, has_one = governor)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [Transaction] queued by the [Proposal].
+    #[account(has_one = smart_wallet)]
+    pub transaction: Box<Account<'info, Transaction>>,
+    /// The Smart Wallet.
+    pub smart_wallet: Box<Account<'info, SmartWallet>>,
+}
+
+impl<'info> FinalizeProposal<'info> {
+    /// Records whether the queued [Transaction] was executed or has expired.
+    pub fn finalize_proposal(&mut self) -> Result<ProposalFinalizeEvent> {
+        let now = Clock::get()?.unix_timestamp;
+        let transaction = &self.transaction;
+
+        let proposal = &mut self.proposal;
+        if transaction.executed_at != -1 {
+            proposal.executed_at = transaction.executed_at;
+        } else {
+            invariant!(
+                transaction.eta != smart_wallet::NO_ETA
+                    && now
+                        > unwrap_int!(transaction.eta.checked_add(self.smart_wallet.grace_period)),
+                QueuedTransactionPending
+            );
+            proposal.expired_at = now;
+        }
+
+        Ok(ProposalFinalizeEvent {
+            governor: proposal.governor,
+            proposal: proposal.key(),
+            transaction: transaction.key(),
+            executed_at: proposal.executed_at,
+            expired_at: proposal.expired_at,
+        })
+    }
+}
+
+impl<'info> Validate<'info> for FinalizeProposal<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(
+            self.transaction,
+            self.proposal.queued_transaction,
+            "transaction should be queued by the proposal"
+        );
+        invariant!(
+            self.proposal.get_state()? == ProposalState::Queued,
+            ProposalNotQueued
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [govern::finalize_proposal].
+#[event]
+pub struct ProposalFinalizeEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal being finalized.
+    #[index]
+    pub proposal: Pubkey,
+    /// The queued transaction.
+    pub transaction: Pubkey,
+    /// When the transaction was executed, or 0 if it has expired.
+    pub executed_at: i64,
+    /// When the proposal was marked as expired, or 0 if it was executed.
+    pub expired_at: i64,
+}
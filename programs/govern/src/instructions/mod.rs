@@ -1,27 +1,85 @@
 //! Instruction processors.
 
 pub mod activate_proposal;
+pub mod add_reward_stream;
+pub mod append_instruction_buffer;
+pub mod apply_voting_pause;
 pub mod cancel_proposal;
+pub mod claim_incentive;
 pub mod claim_reward;
+pub mod claim_rewards_batch;
+pub mod close_instruction_buffer;
+pub mod close_proposal;
+pub mod close_vote;
+pub mod commit_vote;
 pub mod create_governor;
+pub mod create_incentive_deposit;
+pub mod create_instruction_buffer;
+pub mod create_option_incentive;
+pub mod create_option_proposal_instructions;
 pub mod create_option_proposal_meta;
 pub mod create_proposal;
 pub mod create_proposal_meta;
+pub mod create_proposal_rewards;
+pub mod deposit_incentive;
+pub mod finalize_proposal;
 pub mod new_vote;
+pub mod new_vote_commitment;
+pub mod pause_governor;
 pub mod queue_proposal;
+pub mod refund_incentive;
+pub mod refund_reward_stream;
+pub mod reveal_vote;
 pub mod set_governance_params;
+pub mod set_proposal_bond;
+pub mod set_proposal_max_selections;
 pub mod set_vote;
 pub mod set_voting_reward;
+pub mod settle_proposal_bond;
+pub mod sweep_reward;
+pub mod update_option_proposal_meta;
+pub mod update_proposal_instructions;
+pub mod update_proposal_meta;
+pub mod veto_proposal;
 
 pub use activate_proposal::*;
+pub use add_reward_stream::*;
+pub use append_instruction_buffer::*;
+pub use apply_voting_pause::*;
 pub use cancel_proposal::*;
+pub use claim_incentive::*;
 pub use claim_reward::*;
+pub use claim_rewards_batch::*;
+pub use close_instruction_buffer::*;
+pub use close_proposal::*;
+pub use close_vote::*;
+pub use commit_vote::*;
 pub use create_governor::*;
+pub use create_incentive_deposit::*;
+pub use create_instruction_buffer::*;
+pub use create_option_incentive::*;
+pub use create_option_proposal_instructions::*;
 pub use create_option_proposal_meta::*;
 pub use create_proposal::*;
 pub use create_proposal_meta::*;
+pub use create_proposal_rewards::*;
+pub use deposit_incentive::*;
+pub use finalize_proposal::*;
 pub use new_vote::*;
+pub use new_vote_commitment::*;
+pub use pause_governor::*;
 pub use queue_proposal::*;
+pub use refund_incentive::*;
+pub use refund_reward_stream::*;
+pub use reveal_vote::*;
 pub use set_governance_params::*;
+pub use set_proposal_bond::*;
+pub use set_proposal_max_selections::*;
 pub use set_vote::*;
 pub use set_voting_reward::*;
+pub use settle_proposal_bond::*;
+pub use sweep_reward::*;
+pub use update_option_proposal_meta::*;
+pub use update_proposal_instructions::*;
+pub use update_proposal_meta::*;
+pub use veto_proposal::*;
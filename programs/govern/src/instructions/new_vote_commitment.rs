@@ -0,0 +1,44 @@
+use crate::*;
+
+/// Accounts for [govern::new_vote_commitment].
+#[derive(Accounts)]
+pub struct NewVoteCommitment<'info> {
+    /// The [Vote] being committed.
+    pub vote: Box<Account<'info, Vote>>,
+
+    /// The [VoteCommitment].
+    #[account(
+        init,
+        seeds = [
+            b"VoteCommitment".as_ref(),
+            vote.key().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + VoteCommitment::LEN
+    )]
+    pub vote_commitment: Account<'info, VoteCommitment>,
+
+    /// Payer of the [VoteCommitment].
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> NewVoteCommitment<'info> {
+    pub fn new_vote_commitment(&mut self, bump: u8) -> Result<()> {
+        let vote_commitment = &mut self.vote_commitment;
+        vote_commitment.vote = self.vote.key();
+        vote_commitment.bump = bump;
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for NewVoteCommitment<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
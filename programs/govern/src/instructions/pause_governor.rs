@@ -0,0 +1,105 @@
+use crate::*;
+
+/// Accounts for [govern::pause_governor] and [govern::unpause_governor].
+#[derive(Accounts)]
+pub struct PauseGovernor<'info> {
+    /// The [Governor].
+    #[account(mut)]
+    pub governor: Box<Account<'info, Governor>>,
+    /// The [Governor::smart_wallet] or the [Governor::pause_authority].
+    pub authority: Signer<'info>,
+}
+
+impl<'info> PauseGovernor<'info> {
+    pub fn pause_governor(&mut self, instructions: u8, duration: i64) -> Result<()> {
+        invariant!(
+            instructions != 0 && instructions & !PAUSE_ALL == 0,
+            InvalidPauseInstructions
+        );
+        invariant!(
+            duration > 0 && duration <= MAX_PAUSE_DURATION,
+            InvalidPauseDuration
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        if !self.is_smart_wallet() {
+            // the guardian can neither extend a pause nor pause again until a cooldown of
+            // [MAX_PAUSE_DURATION] has passed, so governance can always remove them.
+            let cooldown_ends_at =
+                unwrap_int!(self.governor.paused_until.checked_add(MAX_PAUSE_DURATION));
+            invariant!(now >= cooldown_ends_at, PauseCooldown);
+        }
+
+        let governor = &mut self.governor;
+        // the time voting was paused by the previous pause is kept, as it is replaced.
+        governor.voting_paused_duration = unwrap_int!(governor.voting_paused_duration_at(now));
+        governor.paused_instructions = instructions;
+        governor.paused_at = now;
+        governor.paused_until = unwrap_int!(now.checked_add(duration));
+
+        emit!(GovernorPauseEvent {
+            governor: governor.key(),
+            authority: self.authority.key(),
+            instructions,
+            paused_until: governor.paused_until,
+        });
+
+        Ok(())
+    }
+
+    pub fn unpause_governor(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let governor = &mut self.governor;
+        governor.voting_paused_duration = unwrap_int!(governor.voting_paused_duration_at(now));
+        governor.paused_instructions = 0;
+        governor.paused_until = governor.paused_until.min(now);
+
+        emit!(GovernorUnpauseEvent {
+            governor: governor.key(),
+            authority: self.authority.key(),
+        });
+
+        Ok(())
+    }
+
+    fn is_smart_wallet(&self) -> bool {
+        self.authority.key() == self.governor.smart_wallet
+    }
+}
+
+impl<'info> Validate<'info> for PauseGovernor<'info> {
+    fn validate(&self) -> Result<()> {
+        if !self.is_smart_wallet() {
+            invariant!(
+                self.governor.pause_authority != Pubkey::default(),
+                "pause authority not set"
+            );
+            assert_keys_eq!(self.authority, self.governor.pause_authority);
+        }
+        Ok(())
+    }
+}
+
+/// Event called in [govern::pause_governor].
+#[event]
+pub struct GovernorPauseEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The smart wallet or pause authority that paused the governor.
+    pub authority: Pubkey,
+    /// Bitmask of the paused instruction classes.
+    pub instructions: u8,
+    /// The timestamp when the pause expires.
+    pub paused_until: i64,
+}
+
+/// Event called in [govern::unpause_governor].
+#[event]
+pub struct GovernorUnpauseEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The smart wallet or pause authority that unpaused the governor.
+    pub authority: Pubkey,
+}
@@ -20,6 +20,9 @@ pub struct QueueProposal<'info> {
     /// Payer of the queued transaction.
     #[account(mut)]
     pub payer: Signer<'info>,
+    /// The [OptionProposalInstructions] of the [Proposal::winning_option].
+    /// Required if the [Proposal] has [Proposal::has_option_instructions].
+    pub option_proposal_instructions: Option<Box<Account<'info, OptionProposalInstructions>>>,
     /// The Smart Wallet program.
     pub smart_wallet_program: Program<'info, smart_wallet::program::SmartWallet>,
     /// The System program.
@@ -43,19 +46,22 @@ impl<'info> QueueProposal<'info> {
             signer_seeds,
         );
 
+        let instructions = match &self.option_proposal_instructions {
+            Some(option_proposal_instructions) => {
+                option_proposal_instructions.to_smart_wallet_instructions()
+            }
+            None => self.proposal.to_smart_wallet_instructions(),
+        };
+
         // no delay
         if self.governor.params.timelock_delay_seconds == 0 {
-            smart_wallet::cpi::create_transaction(
-                cpi_ctx,
-                0,
-                self.proposal.to_smart_wallet_instructions(),
-            )?;
+            smart_wallet::cpi::create_transaction(cpi_ctx, 0, instructions)?;
         } else {
             // delay; calculate ETA
             smart_wallet::cpi::create_transaction_with_timelock(
                 cpi_ctx,
                 0,
-                self.proposal.to_smart_wallet_instructions(),
+                instructions,
                 unwrap_int!(Clock::get()?
                     .unix_timestamp
                     .checked_add(self.governor.params.timelock_delay_seconds)),
@@ -78,6 +84,8 @@ impl<'info> Validate<'info> for QueueProposal<'info> {
     fn validate(&self) -> Result<()> {
         assert_keys_eq!(self.governor, self.proposal.governor);
         assert_keys_eq!(self.smart_wallet, self.governor.smart_wallet);
+        self.governor.assert_not_paused(PAUSE_QUEUE_PROPOSAL)?;
+        self.proposal.assert_voting_pause_applied(&self.governor)?;
         let now = Clock::get()?.unix_timestamp;
         let proposal_state = unwrap_opt!(self.proposal.state(now), "invalid state");
         if proposal_state != ProposalState::Succeeded {
@@ -88,14 +96,15 @@ impl<'info> Validate<'info> for QueueProposal<'info> {
             );
             if self.proposal.proposal_type == u8::from(ProposalType::YesNo) {
                 msg!(
-                    "for votes: {}, against votes: {}",
+                    "for votes: {}, against votes: {}, approval threshold bps: {}",
                     self.proposal.option_votes[FOR_VOTE_INDEX],
                     self.proposal.option_votes[AGAINST_VOTE_INDEX],
+                    self.proposal.approval_threshold_bps(),
                 );
             }
             msg!(
                 "quorum req: {}, abstain votes: {}",
-                self.governor.params.quorum_votes,
+                self.proposal.quorum_votes,
                 self.proposal.option_votes[ABSTAIN_VOTE_INDEX],
             );
             invariant!(
@@ -103,6 +112,23 @@ impl<'info> Validate<'info> for QueueProposal<'info> {
                 "proposal must be succeeded to be queued"
             );
         }
+
+        if self.proposal.has_option_instructions {
+            let option_proposal_instructions = unwrap_opt!(
+                self.option_proposal_instructions.as_ref(),
+                "missing option proposal instructions"
+            );
+            assert_keys_eq!(option_proposal_instructions.proposal, self.proposal);
+            invariant!(
+                Some(option_proposal_instructions.option) == self.proposal.winning_option(),
+                "option proposal instructions must be of the winning option"
+            );
+        } else {
+            invariant!(
+                self.option_proposal_instructions.is_none(),
+                "proposal has no option proposal instructions"
+            );
+        }
         Ok(())
     }
 }
@@ -0,0 +1,132 @@
+use crate::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Accounts for [govern::refund_incentive].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefundIncentive<'info> {
+    /// The [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// The [Proposal].
+    #[account(mut, has_one = governor)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [OptionIncentive].
+    #[account(mut, has_one = proposal, has_one = vault)]
+    pub option_incentive: Box<Account<'info, OptionIncentive>>,
+    /// The [OptionIncentive::vault].
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+    /// The [IncentiveDeposit] of the depositor.
+    #[account(mut, has_one = option_incentive, has_one = depositor, close = depositor)]
+    pub incentive_deposit: Box<Account<'info, IncentiveDeposit>>,
+    /// The depositor.
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    /// The token account the deposit is refunded to.
+    #[account(mut, constraint = depositor_token_account.owner == depositor.key())]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> RefundIncentive<'info> {
+    /// Refunds the deposit in full if no voter could claim it. Otherwise, after the
+    /// [Proposal::incentive_claim_deadline], refunds its share of the unclaimed incentive.
+    pub fn refund_incentive(&mut self) -> Result<IncentiveRefundEvent> {
+        let deposit = self.incentive_deposit.amount;
+        let is_unclaimable = self.is_unclaimable()?;
+        let amount = if is_unclaimable {
+            deposit
+        } else {
+            unwrap_opt!(
+                self.option_incentive.unclaimed_refund(deposit),
+                "Math is overflow"
+            )
+        };
+        let option_incentive = &self.option_incentive;
+        if amount != 0 {
+            let seeds = option_incentive_seeds!(option_incentive);
+            let signer_seeds = &[&seeds[..]];
+            token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    token::Transfer {
+                        from: self.vault.to_account_info(),
+                        to: self.depositor_token_account.to_account_info(),
+                        authority: option_incentive.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+
+        let option_incentive = &mut self.option_incentive;
+        if is_unclaimable {
+            option_incentive.total_deposited =
+                unwrap_int!(option_incentive.total_deposited.checked_sub(amount));
+            if option_incentive.total_deposited == 0 {
+                let proposal = &mut self.proposal;
+                proposal.funded_option_incentives =
+                    unwrap_int!(proposal.funded_option_incentives.checked_sub(1));
+            }
+        } else {
+            option_incentive.refunded_deposits =
+                unwrap_int!(option_incentive.refunded_deposits.checked_add(deposit));
+            option_incentive.total_refunded =
+                unwrap_int!(option_incentive.total_refunded.checked_add(amount));
+        }
+
+        Ok(IncentiveRefundEvent {
+            proposal: self.proposal.key(),
+            option_incentive: option_incentive.key(),
+            depositor: self.depositor.key(),
+            amount,
+        })
+    }
+
+    /// Whether no voter can claim the [OptionIncentive], because the proposal was canceled
+    /// or the option got no votes.
+    fn is_unclaimable(&self) -> Result<bool> {
+        let now = Clock::get()?.unix_timestamp;
+        let proposal_state = unwrap_opt!(self.proposal.state(now), "invalid state");
+        let no_option_votes = unwrap_opt!(self.proposal.is_voting_over(now), "invalid state")
+            && self.proposal.option_votes[self.option_incentive.option as usize] == 0;
+        Ok(proposal_state == ProposalState::Canceled || no_option_votes)
+    }
+}
+
+impl<'info> Validate<'info> for RefundIncentive<'info> {
+    fn validate(&self) -> Result<()> {
+        self.governor.assert_not_paused(PAUSE_CLAIM_REWARD)?;
+        self.proposal.assert_voting_pause_applied(&self.governor)?;
+        let now = Clock::get()?.unix_timestamp;
+        // deposits are refunded in full if no voter can claim them, and the unclaimed
+        // incentive is shared back once voters can no longer claim it
+        invariant!(
+            self.is_unclaimable()?
+                || now > unwrap_opt!(self.proposal.incentive_claim_deadline()),
+            "proposal must be canceled, the option must have no votes, or the claim deadline must have passed"
+        );
+        assert_keys_eq!(
+            self.depositor_token_account.mint,
+            self.option_incentive.mint
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [govern::refund_incentive].
+#[event]
+pub struct IncentiveRefundEvent {
+    /// The proposal.
+    #[index]
+    pub proposal: Pubkey,
+    /// The option incentive.
+    #[index]
+    pub option_incentive: Pubkey,
+    /// The depositor.
+    pub depositor: Pubkey,
+    /// Amount refunded.
+    pub amount: u64,
+}
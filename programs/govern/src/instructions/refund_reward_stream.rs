@@ -0,0 +1,113 @@
+use crate::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Accounts for [govern::refund_reward_stream].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefundRewardStream<'info> {
+    /// The [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// The [Proposal].
+    #[account(has_one = governor)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [ProposalRewards] of the [Proposal].
+    #[account(mut, has_one = proposal)]
+    pub proposal_rewards: Box<Account<'info, ProposalRewards>>,
+    /// The [RewardStream::vault].
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+    /// The [RewardStream::funder].
+    pub funder: Signer<'info>,
+    /// The token account the unclaimed reward is refunded to.
+    #[account(mut, constraint = funder_token_account.owner == funder.key())]
+    pub funder_token_account: Account<'info, TokenAccount>,
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> RefundRewardStream<'info> {
+    /// Refunds the unclaimed reward of the funder's [RewardStream]. Voters can no longer claim it.
+    pub fn refund_reward_stream(&mut self) -> Result<RewardStreamRefundEvent> {
+        let funder = self.funder.key();
+        let reward_vault = self.reward_vault.key();
+        let now = Clock::get()?.unix_timestamp;
+
+        let proposal_rewards = &mut self.proposal_rewards;
+        let stream = unwrap_opt!(
+            proposal_rewards
+                .streams
+                .iter_mut()
+                .find(|stream| stream.funder == funder && stream.vault == reward_vault),
+            "reward stream not found"
+        );
+        invariant!(stream.refunded_at == 0, "reward stream has been refunded");
+        let amount = unwrap_int!(stream.amount.checked_sub(stream.total_claimed));
+        stream.refunded_at = now;
+        let reward_mint = stream.mint;
+
+        if amount != 0 {
+            let seeds = proposal_rewards_seeds!(self.proposal_rewards);
+            let signer_seeds = &[&seeds[..]];
+            token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    token::Transfer {
+                        from: self.reward_vault.to_account_info(),
+                        to: self.funder_token_account.to_account_info(),
+                        authority: self.proposal_rewards.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+
+        Ok(RewardStreamRefundEvent {
+            governor: self.proposal.governor,
+            proposal: self.proposal.key(),
+            funder,
+            reward_mint,
+            amount,
+        })
+    }
+}
+
+impl<'info> Validate<'info> for RefundRewardStream<'info> {
+    fn validate(&self) -> Result<()> {
+        self.proposal.assert_voting_pause_applied(&self.governor)?;
+        let now = Clock::get()?.unix_timestamp;
+        let proposal_state = unwrap_opt!(self.proposal.state(now), "invalid state");
+        // streams of canceled proposals, or of proposals which did not meet quorum, cannot be claimed
+        if proposal_state != ProposalState::Canceled {
+            invariant!(
+                unwrap_opt!(self.proposal.is_voting_over(now), "invalid state"),
+                "voting must be over"
+            );
+            if unwrap_opt!(self.proposal.meets_quorum(), "Math is overflow") {
+                let claim_deadline = unwrap_opt!(
+                    unwrap_opt!(self.proposal.reward_claim_deadline()),
+                    "voting reward has no claim deadline"
+                );
+                invariant!(now > claim_deadline, "claim deadline has not passed");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Event called in [govern::refund_reward_stream].
+#[event]
+pub struct RewardStreamRefundEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal.
+    #[index]
+    pub proposal: Pubkey,
+    /// The funder.
+    pub funder: Pubkey,
+    /// The reward mint.
+    pub reward_mint: Pubkey,
+    /// Amount of unclaimed reward refunded.
+    pub amount: u64,
+}
@@ -0,0 +1,62 @@
+use crate::*;
+
+/// Accounts for [govern::reveal_vote].
+#[derive(Accounts)]
+pub struct RevealVote<'info> {
+    /// The [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// The [Proposal].
+    #[account(mut, has_one = governor)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [Vote].
+    #[account(mut, has_one = proposal)]
+    pub vote: Box<Account<'info, Vote>>,
+    /// The [VoteCommitment].
+    #[account(mut, has_one = vote)]
+    pub vote_commitment: Box<Account<'info, VoteCommitment>>,
+}
+
+impl<'info> RevealVote<'info> {
+    /// Reveals a committed vote, adding its weight to the [Proposal].
+    pub fn reveal_vote(&mut self, side: u8, salt: [u8; 32]) -> Result<()> {
+        let vote_commitment = &mut self.vote_commitment;
+        invariant!(
+            VoteCommitment::hash(side, vote_commitment.voting_power, &salt)
+                == vote_commitment.commitment,
+            InvalidVoteReveal
+        );
+        vote_commitment.revealed = true;
+        let voting_power = vote_commitment.voting_power;
+
+        let proposal = &mut self.proposal;
+        proposal.add_vote_weight(side, voting_power)?;
+
+        let vote = &mut self.vote;
+        vote.side = side;
+        vote.voting_power = voting_power;
+
+        emit!(VoteSetEvent {
+            governor: proposal.governor,
+            proposal: proposal.key(),
+            voter: vote.voter,
+            vote: vote.key(),
+            side,
+            voting_power,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for RevealVote<'info> {
+    fn validate(&self) -> Result<()> {
+        self.governor.assert_not_paused(PAUSE_SET_VOTE)?;
+        self.proposal.assert_voting_pause_applied(&self.governor)?;
+        invariant!(!self.vote_commitment.revealed, VoteAlreadyRevealed);
+        invariant!(
+            self.proposal.get_state()? == ProposalState::Revealing,
+            ProposalNotRevealing
+        );
+        Ok(())
+    }
+}
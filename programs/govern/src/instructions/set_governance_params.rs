@@ -1,5 +1,6 @@
 use crate::*;
-/// Accounts for [govern::set_governance_params] and [govern::set_voter].
+/// Accounts for [govern::set_governance_params], [govern::set_voting_params], [govern::set_locker],
+/// [govern::set_veto_authority], [govern::set_pause_authority] and [govern::set_reward_claim_period].
 #[derive(Accounts)]
 pub struct SetGovernanceParams<'info> {
     /// The [Governor]
@@ -11,6 +12,8 @@ pub struct SetGovernanceParams<'info> {
 
 impl<'info> SetGovernanceParams<'info> {
     pub fn set_governance_params(&mut self, params: GovernanceParameters) -> Result<()> {
+        params.validate()?;
+
         let prev_params = self.governor.params;
         self.governor.params = params;
 
@@ -23,6 +26,36 @@ impl<'info> SetGovernanceParams<'info> {
         Ok(())
     }
 
+    pub fn set_voting_params(&mut self, params: VotingParameters) -> Result<()> {
+        params.validate()?;
+
+        let prev_params = self.governor.voting_params;
+        self.governor.voting_params = params;
+
+        emit!(GovernorSetVotingParamsEvent {
+            governor: self.governor.key(),
+            prev_params,
+            params,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_reward_claim_period(&mut self, claim_period: u64) -> Result<()> {
+        invariant!(claim_period.to_i64().is_some(), "claim period is too long");
+
+        let prev_claim_period = self.governor.reward_claim_period;
+        self.governor.reward_claim_period = claim_period;
+
+        emit!(GovernorSetRewardClaimPeriodEvent {
+            governor: self.governor.key(),
+            prev_claim_period,
+            claim_period,
+        });
+
+        Ok(())
+    }
+
     pub fn set_locker(&mut self, locker: Pubkey) -> Result<()> {
         let prev_locker = self.governor.locker;
         self.governor.locker = locker;
@@ -35,6 +68,32 @@ impl<'info> SetGovernanceParams<'info> {
 
         Ok(())
     }
+
+    pub fn set_veto_authority(&mut self, veto_authority: Pubkey) -> Result<()> {
+        let prev_veto_authority = self.governor.veto_authority;
+        self.governor.veto_authority = veto_authority;
+
+        emit!(GovernorSetVetoAuthorityEvent {
+            governor: self.governor.key(),
+            prev_veto_authority,
+            veto_authority,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_pause_authority(&mut self, pause_authority: Pubkey) -> Result<()> {
+        let prev_pause_authority = self.governor.pause_authority;
+        self.governor.pause_authority = pause_authority;
+
+        emit!(GovernorSetPauseAuthorityEvent {
+            governor: self.governor.key(),
+            prev_pause_authority,
+            pause_authority,
+        });
+
+        Ok(())
+    }
 }
 
 impl<'info> Validate<'info> for SetGovernanceParams<'info> {
@@ -60,6 +119,30 @@ pub struct GovernorSetParamsEvent {
     pub params: GovernanceParameters,
 }
 
+/// Event called in [govern::set_voting_params].
+#[event]
+pub struct GovernorSetVotingParamsEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// Previous [VotingParameters].
+    pub prev_params: VotingParameters,
+    /// New [VotingParameters].
+    pub params: VotingParameters,
+}
+
+/// Event called in [govern::set_reward_claim_period].
+#[event]
+pub struct GovernorSetRewardClaimPeriodEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// Previous [Governor::reward_claim_period].
+    pub prev_claim_period: u64,
+    /// New [Governor::reward_claim_period].
+    pub claim_period: u64,
+}
+
 /// Event called in [govern::set_voter].
 #[event]
 pub struct GovernorSetVoterEvent {
@@ -71,3 +154,27 @@ pub struct GovernorSetVoterEvent {
     /// New [Governor::locker].
     pub new_locker: Pubkey,
 }
+
+/// Event called in [govern::set_veto_authority].
+#[event]
+pub struct GovernorSetVetoAuthorityEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// Previous [Governor::veto_authority].
+    pub prev_veto_authority: Pubkey,
+    /// New [Governor::veto_authority].
+    pub veto_authority: Pubkey,
+}
+
+/// Event called in [govern::set_pause_authority].
+#[event]
+pub struct GovernorSetPauseAuthorityEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// Previous [Governor::pause_authority].
+    pub prev_pause_authority: Pubkey,
+    /// New [Governor::pause_authority].
+    pub pause_authority: Pubkey,
+}
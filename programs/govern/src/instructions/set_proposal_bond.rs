@@ -0,0 +1,48 @@
+use anchor_spl::token::Mint;
+
+use crate::*;
+/// Accounts for [govern::set_proposal_bond].
+#[derive(Accounts)]
+pub struct SetProposalBond<'info> {
+    /// The [Governor]
+    #[account(mut, has_one = smart_wallet)]
+    pub governor: Box<Account<'info, Governor>>,
+    /// bond mint
+    pub bond_mint: Account<'info, Mint>,
+    /// The Smart Wallet.
+    pub smart_wallet: Signer<'info>,
+}
+
+impl<'info> SetProposalBond<'info> {
+    pub fn set_proposal_bond(&mut self, amount: u64) -> Result<()> {
+        let mint = self.bond_mint.key();
+
+        self.governor.proposal_bond = ProposalBondParams { mint, amount };
+
+        emit!(GovernorSetProposalBondEvent {
+            governor: self.governor.key(),
+            mint,
+            amount,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for SetProposalBond<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Event called in [govern::set_proposal_bond].
+#[event]
+pub struct GovernorSetProposalBondEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// Bond mint
+    pub mint: Pubkey,
+    /// Bond amount
+    pub amount: u64,
+}
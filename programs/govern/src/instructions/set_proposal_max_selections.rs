@@ -0,0 +1,63 @@
+use crate::*;
+
+/// Accounts for [govern::set_proposal_max_selections].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetProposalMaxSelections<'info> {
+    /// The [Proposal].
+    #[account(mut, has_one = proposer)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [Proposal::proposer].
+    pub proposer: Signer<'info>,
+}
+
+impl<'info> SetProposalMaxSelections<'info> {
+    pub fn set_proposal_max_selections(
+        &mut self,
+        max_selections: u8,
+    ) -> Result<ProposalSetMaxSelectionsEvent> {
+        let proposal = &mut self.proposal;
+        invariant!(
+            max_selections >= 1 && max_selections <= proposal.max_option,
+            InvalidMaxSelections
+        );
+        proposal.max_selections = max_selections;
+        proposal.revision = unwrap_int!(proposal.revision.checked_add(1));
+
+        Ok(ProposalSetMaxSelectionsEvent {
+            governor: proposal.governor,
+            proposal: proposal.key(),
+            revision: proposal.revision,
+            max_selections,
+        })
+    }
+}
+
+impl<'info> Validate<'info> for SetProposalMaxSelections<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.proposal.proposal_type == u8::from(ProposalType::Approval),
+            InvalidProposalType
+        );
+        invariant!(
+            self.proposal.get_state()? == ProposalState::Draft,
+            ProposalNotDraft
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [govern::set_proposal_max_selections].
+#[event]
+pub struct ProposalSetMaxSelectionsEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal.
+    #[index]
+    pub proposal: Pubkey,
+    /// The [Proposal::revision] after the edit.
+    pub revision: u32,
+    /// Maximum number of options a voter may select.
+    pub max_selections: u8,
+}
@@ -17,14 +17,20 @@ pub struct SetVote<'info> {
 impl<'info> SetVote<'info> {
     /// Queues a Transaction into the Smart Wallet.
     pub fn set_vote(&mut self, side: u8, voting_power: u64) -> Result<()> {
-        let vote = &self.vote;
+        invariant!(
+            self.proposal.proposal_type != u8::from(ProposalType::Approval),
+            InvalidProposalType
+        );
+        let had_quorum = unwrap_opt!(self.proposal.meets_quorum());
+        self.subtract_current_vote_weight()?;
 
+        self.track_unrewarded_voting_power(voting_power)?;
         let proposal = &mut self.proposal;
-        proposal.subtract_vote_weight(vote.side, vote.voting_power)?;
         proposal.add_vote_weight(side, voting_power)?;
 
         let vote = &mut self.vote;
         vote.side = side;
+        vote.split_bps = [0; 11];
         vote.voting_power = voting_power;
 
         emit!(VoteSetEvent {
@@ -36,6 +42,158 @@ impl<'info> SetVote<'info> {
             voting_power,
         });
 
+        self.extend_voting_on_late_quorum(had_quorum)?;
+
+        Ok(())
+    }
+
+    /// Splits the weight of a vote across options, in basis points of its voting power.
+    pub fn set_split_vote(&mut self, split_bps: Vec<u16>, voting_power: u64) -> Result<()> {
+        invariant!(
+            self.proposal.proposal_type != u8::from(ProposalType::Approval),
+            InvalidProposalType
+        );
+        invariant!(
+            split_bps.len() <= self.proposal.max_option as usize + 1,
+            InvalidVoteSplit
+        );
+        let mut split = [0u16; 11];
+        split[..split_bps.len()].copy_from_slice(&split_bps);
+
+        let had_quorum = unwrap_opt!(self.proposal.meets_quorum());
+        self.subtract_current_vote_weight()?;
+
+        self.track_unrewarded_voting_power(voting_power)?;
+        let proposal = &mut self.proposal;
+        proposal.add_split_vote_weight(&split, voting_power)?;
+
+        let vote = &mut self.vote;
+        vote.side = 0;
+        vote.split_bps = split;
+        vote.voting_power = voting_power;
+
+        emit!(SplitVoteSetEvent {
+            governor: proposal.governor,
+            proposal: proposal.key(),
+            voter: vote.voter,
+            vote: vote.key(),
+            split_bps,
+            voting_power,
+        });
+
+        self.extend_voting_on_late_quorum(had_quorum)?;
+
+        Ok(())
+    }
+
+    /// Extends voting if the vote made the [Proposal] reach quorum late in its voting period.
+    fn extend_voting_on_late_quorum(&mut self, had_quorum: bool) -> Result<()> {
+        let proposal = &mut self.proposal;
+        let now = Clock::get()?.unix_timestamp;
+        if let Some(previous_voting_ends_at) =
+            unwrap_opt!(proposal.extend_voting_on_late_quorum(had_quorum, now))
+        {
+            emit!(ProposalVotingExtendEvent {
+                governor: proposal.governor,
+                proposal: proposal.key(),
+                previous_voting_ends_at,
+                voting_ends_at: proposal.voting_ends_at,
+            });
+        }
+        Ok(())
+    }
+
+    /// Replaces the current weight of an [Vote::unrewarded] vote in the [Proposal::unrewarded_voting_power].
+    fn track_unrewarded_voting_power(&mut self, voting_power: u64) -> Result<()> {
+        if !self.vote.unrewarded {
+            return Ok(());
+        }
+        let proposal = &mut self.proposal;
+        proposal.unrewarded_voting_power = unwrap_int!(proposal
+            .unrewarded_voting_power
+            .checked_sub(self.vote.voting_power)
+            .and_then(|power| power.checked_add(voting_power)));
+        Ok(())
+    }
+
+    /// Removes the current weight of the [Vote] from the [Proposal].
+    fn subtract_current_vote_weight(&mut self) -> Result<()> {
+        let vote = &self.vote;
+        let proposal = &mut self.proposal;
+        if vote.is_split() {
+            proposal.subtract_split_vote_weight(&vote.split_bps, vote.voting_power)
+        } else {
+            proposal.subtract_vote_weight(vote.side, vote.voting_power)
+        }
+    }
+
+    /// Sets the selected options and weight of a vote on a [ProposalType::Approval] proposal.
+    pub fn set_approval_vote(&mut self, selections: u16, voting_power: u64) -> Result<()> {
+        invariant!(
+            self.proposal.proposal_type == u8::from(ProposalType::Approval),
+            NotApprovalProposal
+        );
+        let had_quorum = unwrap_opt!(self.proposal.meets_quorum());
+        self.track_unrewarded_voting_power(voting_power)?;
+        let vote = &self.vote;
+
+        let proposal = &mut self.proposal;
+        proposal.subtract_approval_vote_weight(vote.selections, vote.voting_power)?;
+        proposal.add_approval_vote_weight(selections, voting_power)?;
+
+        let vote = &mut self.vote;
+        vote.selections = selections;
+        vote.voting_power = voting_power;
+
+        emit!(ApprovalVoteSetEvent {
+            governor: proposal.governor,
+            proposal: proposal.key(),
+            voter: vote.voter,
+            vote: vote.key(),
+            selections,
+            voting_power,
+        });
+
+        self.extend_voting_on_late_quorum(had_quorum)?;
+
+        Ok(())
+    }
+
+    /// Marks the vote as set by the voter themselves, overriding their delegate.
+    pub fn set_vote_override(&mut self) -> Result<()> {
+        let vote = &mut self.vote;
+        vote.owner_override = true;
+
+        emit!(VoteOverrideEvent {
+            governor: self.proposal.governor,
+            proposal: self.proposal.key(),
+            voter: vote.voter,
+            vote: vote.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Marks the vote as cast by a voter pooling the voting power of escrows, which earns no rewards.
+    pub fn set_vote_unrewarded(&mut self) -> Result<()> {
+        let vote = &mut self.vote;
+        if vote.unrewarded {
+            return Ok(());
+        }
+        vote.unrewarded = true;
+        let proposal = &mut self.proposal;
+        proposal.unrewarded_voting_power = unwrap_int!(proposal
+            .unrewarded_voting_power
+            .checked_add(vote.voting_power));
+
+        emit!(VoteUnrewardedEvent {
+            governor: proposal.governor,
+            proposal: proposal.key(),
+            voter: vote.voter,
+            vote: vote.key(),
+            voting_power: vote.voting_power,
+        });
+
         Ok(())
     }
 }
@@ -43,6 +201,7 @@ impl<'info> SetVote<'info> {
 impl<'info> Validate<'info> for SetVote<'info> {
     fn validate(&self) -> Result<()> {
         assert_keys_eq!(self.governor.locker, self.locker);
+        self.governor.assert_not_paused(PAUSE_SET_VOTE)?;
         assert_keys_eq!(
             self.governor,
             self.proposal.governor,
@@ -53,6 +212,8 @@ impl<'info> Validate<'info> for SetVote<'info> {
             self.proposal,
             "vote proposal should match"
         );
+        invariant!(!self.proposal.is_commit_reveal(), CommitRevealProposal);
+        self.proposal.assert_voting_pause_applied(&self.governor)?;
         invariant!(
             self.proposal.get_state()? == ProposalState::Active,
             ProposalNotActive
@@ -82,3 +243,93 @@ pub struct VoteSetEvent {
     /// The vote's voting_power.
     pub voting_power: u64,
 }
+
+/// Event called in [govern::set_split_vote].
+#[event]
+pub struct SplitVoteSetEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal being voted on.
+    #[index]
+    pub proposal: Pubkey,
+    /// The voter.
+    #[index]
+    pub voter: Pubkey,
+    /// The vote.
+    #[index]
+    pub vote: Pubkey,
+    /// Share of the voting power given to each option, in basis points.
+    pub split_bps: Vec<u16>,
+    /// The vote's voting_power.
+    pub voting_power: u64,
+}
+
+/// Event called in [govern::set_approval_vote].
+#[event]
+pub struct ApprovalVoteSetEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal being voted on.
+    #[index]
+    pub proposal: Pubkey,
+    /// The voter.
+    #[index]
+    pub voter: Pubkey,
+    /// The vote.
+    #[index]
+    pub vote: Pubkey,
+    /// Bitmask of the selected options.
+    pub selections: u16,
+    /// The vote's voting_power.
+    pub voting_power: u64,
+}
+
+/// Event called in [govern::set_vote_override].
+#[event]
+pub struct VoteOverrideEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal being voted on.
+    #[index]
+    pub proposal: Pubkey,
+    /// The voter.
+    pub voter: Pubkey,
+    /// The vote.
+    pub vote: Pubkey,
+}
+
+/// Event called in [govern::set_vote_unrewarded].
+#[event]
+pub struct VoteUnrewardedEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal being voted on.
+    #[index]
+    pub proposal: Pubkey,
+    /// The voter.
+    pub voter: Pubkey,
+    /// The vote.
+    pub vote: Pubkey,
+    /// The vote's voting_power, excluded from the rewards.
+    pub voting_power: u64,
+}
+
+/// Event called when a vote in [govern::set_vote] makes a proposal reach quorum late,
+/// or when voting is extended for a pause with [govern::apply_voting_pause].
+#[event]
+pub struct ProposalVotingExtendEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal whose voting was extended.
+    #[index]
+    pub proposal: Pubkey,
+    /// When voting was going to end.
+    pub previous_voting_ends_at: i64,
+    /// When voting now ends.
+    pub voting_ends_at: i64,
+}
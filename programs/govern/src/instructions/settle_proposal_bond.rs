@@ -0,0 +1,118 @@
+use crate::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Accounts for [govern::settle_proposal_bond].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettleProposalBond<'info> {
+    /// The [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// The [Proposal].
+    #[account(has_one = governor)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [ProposalBond].
+    #[account(mut, has_one = proposal, has_one = proposer, has_one = vault, close = proposer)]
+    pub proposal_bond: Box<Account<'info, ProposalBond>>,
+    /// The [ProposalBond::vault].
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+    /// The [ProposalBond::proposer]. Receives the rent of the closed accounts.
+    /// CHECK: checked by the has_one constraint of the [ProposalBond]
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    /// The token account the bond is refunded to, owned by the proposer.
+    #[account(mut, constraint = proposer_token_account.owner == proposer.key())]
+    pub proposer_token_account: Account<'info, TokenAccount>,
+    /// The treasury token account the bond is forfeited to, owned by the smart wallet.
+    #[account(mut, constraint = treasury.owner == governor.smart_wallet)]
+    pub treasury: Account<'info, TokenAccount>,
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> SettleProposalBond<'info> {
+    /// Refunds the bond if the proposal reached quorum, otherwise forfeits it to the treasury.
+    /// [ProposalType::Optimistic] proposals need no quorum, so their bond is forfeited if they are vetoed by votes.
+    pub fn settle_proposal_bond(&mut self) -> Result<ProposalBondSettleEvent> {
+        let proposal_state = self.proposal.get_state()?;
+        let vetoed_by_votes =
+            self.proposal.is_optimistic() && proposal_state == ProposalState::Defeated;
+        let refunded = unwrap_opt!(self.proposal.meets_quorum(), "Math is overflow")
+            && proposal_state != ProposalState::Canceled
+            && !vetoed_by_votes;
+        let destination = if refunded {
+            self.proposer_token_account.to_account_info()
+        } else {
+            self.treasury.to_account_info()
+        };
+
+        let proposal_bond = &self.proposal_bond;
+        let seeds = proposal_bond_seeds!(proposal_bond);
+        let signer_seeds = &[&seeds[..]];
+        let amount = self.vault.amount;
+        if amount != 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    token::Transfer {
+                        from: self.vault.to_account_info(),
+                        to: destination,
+                        authority: proposal_bond.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+        token::close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            token::CloseAccount {
+                account: self.vault.to_account_info(),
+                destination: self.proposer.to_account_info(),
+                authority: proposal_bond.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+
+        Ok(ProposalBondSettleEvent {
+            governor: self.governor.key(),
+            proposal: self.proposal.key(),
+            proposer: self.proposer.key(),
+            amount,
+            refunded,
+        })
+    }
+}
+
+impl<'info> Validate<'info> for SettleProposalBond<'info> {
+    fn validate(&self) -> Result<()> {
+        self.proposal.assert_voting_pause_applied(&self.governor)?;
+        let now = Clock::get()?.unix_timestamp;
+        let proposal_state = unwrap_opt!(self.proposal.state(now), "invalid state");
+        invariant!(
+            proposal_state == ProposalState::Canceled
+                || unwrap_opt!(self.proposal.is_voting_over(now), "invalid state"),
+            ProposalBondNotSettleable
+        );
+        assert_keys_eq!(self.proposer_token_account.mint, self.vault.mint);
+        assert_keys_eq!(self.treasury.mint, self.vault.mint);
+        Ok(())
+    }
+}
+
+/// Event called in [govern::settle_proposal_bond].
+#[event]
+pub struct ProposalBondSettleEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal.
+    #[index]
+    pub proposal: Pubkey,
+    /// The proposer.
+    pub proposer: Pubkey,
+    /// Amount refunded or forfeited.
+    pub amount: u64,
+    /// Whether the bond was refunded to the proposer, or forfeited to the treasury.
+    pub refunded: bool,
+}
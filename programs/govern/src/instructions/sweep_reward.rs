@@ -0,0 +1,95 @@
+use crate::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Accounts for [govern::sweep_reward].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SweepReward<'info> {
+    /// The [Governor].
+    #[account(has_one = smart_wallet)]
+    pub governor: Box<Account<'info, Governor>>,
+    /// The [Proposal].
+    #[account(mut, has_one = governor)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [VotingReward::reward_vault] of the [Proposal].
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+    /// The treasury token account receiving the unclaimed reward.
+    #[account(mut)]
+    pub treasury: Account<'info, TokenAccount>,
+    /// The Smart Wallet.
+    pub smart_wallet: Signer<'info>,
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> SweepReward<'info> {
+    pub fn sweep_reward(&mut self) -> Result<RewardSweepEvent> {
+        let amount = unwrap_opt!(self.proposal.unclaimed_reward(), "Math is overflow");
+        self.proposal.reward_swept_at = Clock::get()?.unix_timestamp;
+
+        if amount != 0 {
+            let seeds = governor_seeds!(self.governor);
+            let signer_seeds = &[&seeds[..]];
+            token::transfer(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    token::Transfer {
+                        from: self.reward_vault.to_account_info(),
+                        to: self.treasury.to_account_info(),
+                        authority: self.governor.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+
+        Ok(RewardSweepEvent {
+            governor: self.governor.key(),
+            proposal: self.proposal.key(),
+            treasury: self.treasury.key(),
+            amount,
+        })
+    }
+}
+
+impl<'info> Validate<'info> for SweepReward<'info> {
+    fn validate(&self) -> Result<()> {
+        let voting_reward = self.proposal.voting_reward;
+        assert_keys_eq!(voting_reward.reward_vault, self.reward_vault);
+        assert_keys_eq!(voting_reward.reward_mint, self.treasury.mint);
+        invariant!(self.proposal.reward_swept_at == 0, RewardSwept);
+        self.proposal.assert_voting_pause_applied(&self.governor)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        invariant!(
+            unwrap_opt!(self.proposal.is_voting_over(now), "invalid state"),
+            "voting must be over"
+        );
+        // rewards of proposals which did not meet quorum cannot be claimed
+        if unwrap_opt!(self.proposal.meets_quorum(), "Math is overflow") {
+            let claim_deadline = unwrap_opt!(
+                unwrap_opt!(self.proposal.reward_claim_deadline()),
+                "voting reward has no claim deadline"
+            );
+            invariant!(now > claim_deadline, "claim deadline has not passed");
+        }
+        Ok(())
+    }
+}
+
+/// Event called in [govern::sweep_reward].
+#[event]
+pub struct RewardSweepEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal.
+    #[index]
+    pub proposal: Pubkey,
+    /// The treasury token account.
+    pub treasury: Pubkey,
+    /// Amount of unclaimed reward swept.
+    pub amount: u64,
+}
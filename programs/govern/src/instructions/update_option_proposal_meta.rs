@@ -0,0 +1,74 @@
+use crate::*;
+
+/// Accounts for [govern::update_option_proposal_meta].
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(option_descriptions: Vec<String>)]
+pub struct UpdateOptionProposalMeta<'info> {
+    /// The [Proposal].
+    #[account(mut, has_one = proposer)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [Proposal::proposer].
+    pub proposer: Signer<'info>,
+    /// The [OptionProposalMeta].
+    #[account(
+        mut,
+        has_one = proposal,
+        realloc = 8 + OptionProposalMeta::space(&option_descriptions),
+        realloc::payer = payer,
+        realloc::zero = false
+    )]
+    pub option_proposal_meta: Box<Account<'info, OptionProposalMeta>>,
+    /// Payer of the reallocation. Receives the rent if the [OptionProposalMeta] shrinks.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateOptionProposalMeta<'info> {
+    pub fn update_option_proposal_meta(
+        &mut self,
+        option_descriptions: Vec<String>,
+    ) -> Result<OptionProposalMetaUpdateEvent> {
+        invariant!(
+            option_descriptions.len() == self.proposal.max_option as usize,
+            InvalidOptionDescriptions
+        );
+        self.option_proposal_meta.option_descriptions = option_descriptions.clone();
+        let proposal = &mut self.proposal;
+        proposal.revision = unwrap_int!(proposal.revision.checked_add(1));
+
+        Ok(OptionProposalMetaUpdateEvent {
+            governor: proposal.governor,
+            proposal: proposal.key(),
+            revision: proposal.revision,
+            option_descriptions,
+        })
+    }
+}
+
+impl<'info> Validate<'info> for UpdateOptionProposalMeta<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.proposal.get_state()? == ProposalState::Draft,
+            ProposalNotDraft
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [govern::update_option_proposal_meta].
+#[event]
+pub struct OptionProposalMetaUpdateEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal.
+    #[index]
+    pub proposal: Pubkey,
+    /// The [Proposal::revision] after the edit.
+    pub revision: u32,
+    /// The option descriptions.
+    pub option_descriptions: Vec<String>,
+}
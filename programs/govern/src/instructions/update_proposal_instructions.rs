@@ -0,0 +1,75 @@
+use crate::*;
+
+/// Accounts for [govern::update_proposal_instructions].
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(instructions: Vec<ProposalInstruction>)]
+pub struct UpdateProposalInstructions<'info> {
+    /// The [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// The [Proposal].
+    #[account(
+        mut,
+        has_one = governor,
+        has_one = proposer,
+        realloc = 8 + Proposal::space(proposal.max_option + 1, instructions.clone()),
+        realloc::payer = payer,
+        realloc::zero = false
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [Proposal::proposer].
+    pub proposer: Signer<'info>,
+    /// Payer of the reallocation. Receives the rent if the [Proposal] shrinks.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateProposalInstructions<'info> {
+    pub fn update_proposal_instructions(
+        &mut self,
+        instructions: Vec<ProposalInstruction>,
+    ) -> Result<ProposalInstructionsUpdateEvent> {
+        let proposal = &mut self.proposal;
+        proposal.instructions = instructions.clone();
+        proposal.revision = unwrap_int!(proposal.revision.checked_add(1));
+
+        Ok(ProposalInstructionsUpdateEvent {
+            governor: proposal.governor,
+            proposal: proposal.key(),
+            revision: proposal.revision,
+            instructions,
+        })
+    }
+}
+
+impl<'info> Validate<'info> for UpdateProposalInstructions<'info> {
+    fn validate(&self) -> Result<()> {
+        self.governor.assert_not_paused(PAUSE_CREATE_PROPOSAL)?;
+        invariant!(
+            self.proposal.get_state()? == ProposalState::Draft,
+            ProposalNotDraft
+        );
+        invariant!(
+            !self.proposal.has_option_instructions,
+            "proposal already has option proposal instructions"
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [govern::update_proposal_instructions].
+#[event]
+pub struct ProposalInstructionsUpdateEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal.
+    #[index]
+    pub proposal: Pubkey,
+    /// The [Proposal::revision] after the edit.
+    pub revision: u32,
+    /// Instructions in the proposal.
+    pub instructions: Vec<ProposalInstruction>,
+}
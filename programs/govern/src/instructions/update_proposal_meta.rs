@@ -0,0 +1,76 @@
+use crate::*;
+
+/// Accounts for [govern::update_proposal_meta].
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(title: String, description_link: String)]
+pub struct UpdateProposalMeta<'info> {
+    /// The [Proposal].
+    #[account(mut, has_one = proposer)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [Proposal::proposer].
+    pub proposer: Signer<'info>,
+    /// The [ProposalMeta].
+    #[account(
+        mut,
+        has_one = proposal,
+        realloc = 8 + ProposalMeta::space(&title, &description_link),
+        realloc::payer = payer,
+        realloc::zero = false
+    )]
+    pub proposal_meta: Box<Account<'info, ProposalMeta>>,
+    /// Payer of the reallocation. Receives the rent if the [ProposalMeta] shrinks.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateProposalMeta<'info> {
+    pub fn update_proposal_meta(
+        &mut self,
+        title: String,
+        description_link: String,
+    ) -> Result<ProposalMetaUpdateEvent> {
+        let proposal_meta = &mut self.proposal_meta;
+        proposal_meta.title = title.clone();
+        proposal_meta.description_link = description_link.clone();
+        let proposal = &mut self.proposal;
+        proposal.revision = unwrap_int!(proposal.revision.checked_add(1));
+
+        Ok(ProposalMetaUpdateEvent {
+            governor: proposal.governor,
+            proposal: proposal.key(),
+            revision: proposal.revision,
+            title,
+            description_link,
+        })
+    }
+}
+
+impl<'info> Validate<'info> for UpdateProposalMeta<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.proposal.get_state()? == ProposalState::Draft,
+            ProposalNotDraft
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [govern::update_proposal_meta].
+#[event]
+pub struct ProposalMetaUpdateEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal.
+    #[index]
+    pub proposal: Pubkey,
+    /// The [Proposal::revision] after the edit.
+    pub revision: u32,
+    /// The title.
+    pub title: String,
+    /// The description.
+    pub description_link: String,
+}
@@ -0,0 +1,94 @@
+use crate::*;
+use smart_wallet::Transaction;
+
+/// Accounts for [govern::veto_proposal].
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VetoProposal<'info> {
+    /// The [Governor].
+    #[account(has_one = smart_wallet, has_one = veto_authority)]
+    pub governor: Box<Account<'info, Governor>>,
+    /// The [Proposal] to veto.
+    #[account(mut, has_one = governor)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [Transaction] queued by the [Proposal].
+    #[account(mut, has_one = smart_wallet)]
+    pub transaction: Box<Account<'info, Transaction>>,
+    /// The Smart Wallet.
+    pub smart_wallet: Box<Account<'info, SmartWallet>>,
+    /// The [Governor::veto_authority]. Receives the rent of the [Transaction].
+    #[account(mut)]
+    pub veto_authority: Signer<'info>,
+    /// The Smart Wallet program.
+    pub smart_wallet_program: Program<'info, smart_wallet::program::SmartWallet>,
+}
+
+impl<'info> VetoProposal<'info> {
+    /// Vetoes the proposal and cancels its queued [Transaction].
+    pub fn veto_proposal(&mut self, reason: String) -> Result<ProposalVetoEvent> {
+        let seeds = governor_seeds!(self.governor);
+        let signer_seeds = &[&seeds[..]];
+        smart_wallet::cpi::cancel_transaction(CpiContext::new_with_signer(
+            self.smart_wallet_program.to_account_info(),
+            smart_wallet::cpi::accounts::CancelTransaction {
+                smart_wallet: self.smart_wallet.to_account_info(),
+                transaction: self.transaction.to_account_info(),
+                proposer: self.governor.to_account_info(),
+                receiver: self.veto_authority.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+
+        let proposal = &mut self.proposal;
+        proposal.vetoed_at = Clock::get()?.unix_timestamp;
+
+        Ok(ProposalVetoEvent {
+            governor: proposal.governor,
+            proposal: proposal.key(),
+            transaction: self.transaction.key(),
+            veto_authority: self.veto_authority.key(),
+            reason,
+        })
+    }
+}
+
+impl<'info> Validate<'info> for VetoProposal<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.governor.veto_authority != Pubkey::default(),
+            "veto authority is not set"
+        );
+        assert_keys_eq!(
+            self.transaction,
+            self.proposal.queued_transaction,
+            "transaction should be queued by the proposal"
+        );
+        invariant!(
+            self.proposal.get_state()? == ProposalState::Queued,
+            ProposalNotQueued
+        );
+        let now = Clock::get()?.unix_timestamp;
+        invariant!(
+            self.transaction.eta != smart_wallet::NO_ETA && now < self.transaction.eta,
+            TimelockElapsed
+        );
+        Ok(())
+    }
+}
+
+/// Event called in [govern::veto_proposal].
+#[event]
+pub struct ProposalVetoEvent {
+    /// The governor.
+    #[index]
+    pub governor: Pubkey,
+    /// The proposal being vetoed.
+    #[index]
+    pub proposal: Pubkey,
+    /// The canceled transaction.
+    pub transaction: Pubkey,
+    /// The [Governor::veto_authority].
+    pub veto_authority: Pubkey,
+    /// Why the proposal was vetoed.
+    pub reason: String,
+}
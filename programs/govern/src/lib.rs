@@ -49,6 +49,8 @@ pub mod govern {
     ) -> Result<()> {
         let event = ctx.accounts.create_proposal(
             unwrap_bump!(ctx, "proposal"),
+            // only known if the proposal bond is escrowed
+            ctx.bumps.get("proposal_bond").copied().unwrap_or_default(),
             proposal_type,
             max_option,
             instructions,
@@ -57,12 +59,60 @@ pub mod govern {
         Ok(())
     }
 
+    /// Creates an [InstructionBuffer] for the proposer. Instructions that do not fit in one
+    /// transaction are appended to it with [govern::append_instruction_buffer], then consumed by
+    /// [govern::create_proposal].
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_instruction_buffer(ctx: Context<CreateInstructionBuffer>) -> Result<()> {
+        ctx.accounts
+            .create_instruction_buffer(unwrap_bump!(ctx, "instruction_buffer"))
+    }
+
+    /// Appends instructions to an [InstructionBuffer], reallocating it.
+    /// Only callable by the proposer who owns the buffer.
+    #[access_control(ctx.accounts.validate())]
+    pub fn append_instruction_buffer(
+        ctx: Context<AppendInstructionBuffer>,
+        instructions: Vec<ProposalInstruction>,
+    ) -> Result<()> {
+        ctx.accounts.append_instruction_buffer(instructions)
+    }
+
+    /// Closes an [InstructionBuffer] without creating a proposal.
+    /// Only callable by the proposer who owns the buffer.
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_instruction_buffer(ctx: Context<CloseInstructionBuffer>) -> Result<()> {
+        ctx.accounts.close_instruction_buffer()
+    }
+
+    /// Creates the [OptionProposalInstructions] of an option of an option [Proposal].
+    /// Only the winning option's instructions are queued in [govern::queue_proposal].
+    /// This is only callable by the proposer while the proposal is a [ProposalState::Draft].
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_option_proposal_instructions(
+        ctx: Context<CreateOptionProposalInstructions>,
+        option: u8,
+        instructions: Vec<ProposalInstruction>,
+    ) -> Result<()> {
+        let event = ctx.accounts.create_option_proposal_instructions(
+            unwrap_bump!(ctx, "option_proposal_instructions"),
+            option,
+            instructions,
+        )?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
     /// Activates a proposal.
     /// Only the [Governor::voter] may call this; that program
     /// may ensure that only certain types of users can activate proposals.
+    /// `locked_voting_power` is the voting power of the locked supply, used by [QuorumMode::LockedSupplyFraction].
     #[access_control(ctx.accounts.validate())]
-    pub fn activate_proposal(ctx: Context<ActivateProposal>) -> Result<()> {
-        ctx.accounts.activate_proposal()
+    pub fn activate_proposal(
+        ctx: Context<ActivateProposal>,
+        locked_voting_power: u64,
+    ) -> Result<()> {
+        ctx.accounts.activate_proposal(locked_voting_power)
     }
 
     /// Cancels a proposal.
@@ -82,12 +132,53 @@ pub mod govern {
         Ok(())
     }
 
+    /// Records the outcome of a queued proposal: [ProposalState::Executed] if its
+    /// [smart_wallet::Transaction] was executed, or [ProposalState::Expired] if the
+    /// grace period passed without execution. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
+        let event = ctx.accounts.finalize_proposal()?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    /// Vetoes a [ProposalState::Queued] proposal during its timelock, canceling its
+    /// [smart_wallet::Transaction].
+    /// This may only be called by the [Governor::veto_authority].
+    #[access_control(ctx.accounts.validate())]
+    pub fn veto_proposal(ctx: Context<VetoProposal>, reason: String) -> Result<()> {
+        let event = ctx.accounts.veto_proposal(reason)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
     /// Creates a new [Vote]. Anyone can call this.
     #[access_control(ctx.accounts.validate())]
     pub fn new_vote(ctx: Context<NewVote>, voter: Pubkey) -> Result<()> {
         ctx.accounts.new_vote(unwrap_bump!(ctx, "vote"), voter)
     }
 
+    /// Creates a new [VoteCommitment] for a [Vote]. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn new_vote_commitment(ctx: Context<NewVoteCommitment>) -> Result<()> {
+        ctx.accounts
+            .new_vote_commitment(unwrap_bump!(ctx, "vote_commitment"))
+    }
+
+    /// Commits the hash of a [Vote]'s side, voting power and salt on a commit-reveal [Proposal].
+    /// This may only be called by the [Governor::voter].
+    #[access_control(ctx.accounts.validate())]
+    pub fn commit_vote(ctx: Context<CommitVote>, commitment: [u8; 32], weight: u64) -> Result<()> {
+        ctx.accounts.commit_vote(commitment, weight)
+    }
+
+    /// Reveals a committed [Vote] while the [Proposal] is [ProposalState::Revealing].
+    /// Anyone who knows the salt can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn reveal_vote(ctx: Context<RevealVote>, side: u8, salt: [u8; 32]) -> Result<()> {
+        ctx.accounts.reveal_vote(side, salt)
+    }
+
     /// Sets a [Vote] weight and side.
     /// This may only be called by the [Governor::voter].
     #[access_control(ctx.accounts.validate())]
@@ -95,6 +186,38 @@ pub mod govern {
         ctx.accounts.set_vote(side, weight)
     }
 
+    /// Splits the weight of a [Vote] across options.
+    /// `split_bps[i]` is the share of the voting power given to option `i`, in basis points,
+    /// and the shares must add up to [MAX_BPS].
+    /// This may only be called by the [Governor::voter].
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_split_vote(ctx: Context<SetVote>, split_bps: Vec<u16>, weight: u64) -> Result<()> {
+        ctx.accounts.set_split_vote(split_bps, weight)
+    }
+
+    /// Sets the selected options and weight of a [Vote] on a [ProposalType::Approval] proposal.
+    /// `selections` is a bitmask of the options, where bit `i` selects option `i`.
+    /// This may only be called by the [Governor::voter].
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_approval_vote(ctx: Context<SetVote>, selections: u16, weight: u64) -> Result<()> {
+        ctx.accounts.set_approval_vote(selections, weight)
+    }
+
+    /// Marks a [Vote] as overridden by the voter, so that their vote delegate can no longer change it.
+    /// This may only be called by the [Governor::voter].
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_vote_override(ctx: Context<SetVote>) -> Result<()> {
+        ctx.accounts.set_vote_override()
+    }
+
+    /// Marks a [Vote] as cast by a program address pooling the voting power of escrows,
+    /// so that it earns no rewards and does not dilute the rewards of other votes.
+    /// This may only be called by the [Governor::voter].
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_vote_unrewarded(ctx: Context<SetVote>) -> Result<()> {
+        ctx.accounts.set_vote_unrewarded()
+    }
+
     /// Sets the [GovernanceParameters].
     /// This may only be called by the [Governor::smart_wallet].
     #[access_control(ctx.accounts.validate())]
@@ -105,6 +228,17 @@ pub mod govern {
         ctx.accounts.set_governance_params(params)
     }
 
+    /// Sets the [VotingParameters]: quorum mode, approval threshold, commit-reveal,
+    /// late quorum extension and optimistic veto threshold.
+    /// This may only be called by the [Governor::smart_wallet].
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_voting_params(
+        ctx: Context<SetGovernanceParams>,
+        params: VotingParameters,
+    ) -> Result<()> {
+        ctx.accounts.set_voting_params(params)
+    }
+
     /// Sets Voting Reward.
     /// This may only be called by the [Governor::smart_wallet].
     #[access_control(ctx.accounts.validate())]
@@ -115,10 +249,163 @@ pub mod govern {
         ctx.accounts.set_voting_reward(reward_per_proposal)
     }
 
-    /// Claim rewards, for voter
+    /// Sets the [Governor::reward_claim_period]. Zero means rewards can be claimed at any time.
+    /// This may only be called by the [Governor::smart_wallet].
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_reward_claim_period(
+        ctx: Context<SetGovernanceParams>,
+        claim_period: u64,
+    ) -> Result<()> {
+        ctx.accounts.set_reward_claim_period(claim_period)
+    }
+
+    /// Sets the bond proposers escrow at [govern::create_proposal]. Zero disables the bond.
+    /// This may only be called by the [Governor::smart_wallet].
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_proposal_bond(ctx: Context<SetProposalBond>, amount: u64) -> Result<()> {
+        ctx.accounts.set_proposal_bond(amount)
+    }
+
+    /// Settles the [ProposalBond] of a [Proposal] once voting is over or it was canceled.
+    /// The bond is refunded if the proposal reached quorum, otherwise it is forfeited to a
+    /// treasury token account of the [Governor::smart_wallet]. The bond of an optimistic proposal
+    /// defeated by veto votes is also forfeited. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn settle_proposal_bond(ctx: Context<SettleProposalBond>) -> Result<()> {
+        let event = ctx.accounts.settle_proposal_bond()?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    /// Claim rewards, for voter. The [Governor::locker] can also claim on behalf of the voter,
+    /// in which case the rewards are paid to the voter's associated token accounts.
+    #[access_control(ctx.accounts.validate())]
+    pub fn claim_reward<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ClaimReward<'info>>,
+    ) -> Result<()> {
+        let event = ctx.accounts.claim_reward(ctx.remaining_accounts)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    /// Claims the voting rewards of many [Vote]s of a voter at once. The [Proposal] and [Vote] pairs
+    /// are passed as remaining accounts, followed by a reward vault and voter token account pair for each reward vault.
+    /// [RewardStream]s are not paid: they are claimed with [govern::claim_reward].
+    #[access_control(ctx.accounts.validate())]
+    pub fn claim_rewards_batch<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ClaimRewardsBatch<'info>>,
+        vote_count: u8,
+    ) -> Result<()> {
+        let (events, event) = ctx
+            .accounts
+            .claim_rewards_batch(vote_count, ctx.remaining_accounts)?;
+        for event in events {
+            emit_cpi!(event);
+        }
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    /// Closes a [Vote] and its [VoteCommitment], if any, once the [Proposal] is final
+    /// and the voter has no reward or stream left to claim. If incentives were deposited for the proposal,
+    /// votes can only be closed after the [Proposal::incentive_claim_deadline]. Only callable by the voter.
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_vote(ctx: Context<CloseVote>) -> Result<()> {
+        let event = ctx.accounts.close_vote()?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    /// Closes a [ProposalState::Canceled] [Proposal], along with its [ProposalMeta],
+    /// [OptionProposalMeta] and [OptionProposalInstructions], if any. Only callable by the proposer,
+    /// once its bond is settled and its reward streams and incentive deposits are refunded.
+    #[access_control(ctx.accounts.validate())]
+    pub fn close_proposal<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, CloseProposal<'info>>,
+    ) -> Result<()> {
+        let event = ctx.accounts.close_proposal(ctx.remaining_accounts)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    /// Creates the [ProposalRewards] of a [Proposal]. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_proposal_rewards(ctx: Context<CreateProposalRewards>) -> Result<()> {
+        ctx.accounts
+            .create_proposal_rewards(unwrap_bump!(ctx, "proposal_rewards"))
+    }
+
+    /// Funds a [RewardStream] of a [Proposal] while it is a [ProposalState::Draft] or [ProposalState::Active].
+    /// The proposal must have a [Proposal::reward_claim_period], after which the funder is refunded.
+    /// Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn add_reward_stream(ctx: Context<AddRewardStream>, amount: u64) -> Result<()> {
+        let event = ctx.accounts.add_reward_stream(amount)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    /// Refunds the unclaimed reward of a [RewardStream] to its funder, once the [Proposal] was canceled,
+    /// did not meet quorum, or its reward claim deadline has passed. Only callable by the funder.
+    #[access_control(ctx.accounts.validate())]
+    pub fn refund_reward_stream(ctx: Context<RefundRewardStream>) -> Result<()> {
+        let event = ctx.accounts.refund_reward_stream()?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    /// Creates an [OptionIncentive] for an option of a [Proposal]. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_option_incentive(ctx: Context<CreateOptionIncentive>, option: u8) -> Result<()> {
+        ctx.accounts
+            .create_option_incentive(unwrap_bump!(ctx, "option_incentive"), option)
+    }
+
+    /// Creates a new [IncentiveDeposit]. Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_incentive_deposit(
+        ctx: Context<CreateIncentiveDeposit>,
+        depositor: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts
+            .create_incentive_deposit(unwrap_bump!(ctx, "incentive_deposit"), depositor)
+    }
+
+    /// Deposits tokens into an [OptionIncentive] while the [Proposal] is a
+    /// [ProposalState::Draft] or [ProposalState::Active].
+    #[access_control(ctx.accounts.validate())]
+    pub fn deposit_incentive(ctx: Context<DepositIncentive>, amount: u64) -> Result<()> {
+        let event = ctx.accounts.deposit_incentive(amount)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    /// Claims the share of an [OptionIncentive] of a voter, pro rata to the voting power
+    /// their [Vote] gave to the option. Only callable once voting is over, until the
+    /// [Proposal::incentive_claim_deadline].
+    #[access_control(ctx.accounts.validate())]
+    pub fn claim_incentive(ctx: Context<ClaimIncentive>) -> Result<()> {
+        let event = ctx.accounts.claim_incentive()?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    /// Refunds an [IncentiveDeposit] if the [Proposal] was canceled, or if the option
+    /// received no votes. After the [Proposal::incentive_claim_deadline], refunds the share of the deposit
+    /// in the unclaimed incentive instead, including rounding remainders and forfeited claims.
+    #[access_control(ctx.accounts.validate())]
+    pub fn refund_incentive(ctx: Context<RefundIncentive>) -> Result<()> {
+        let event = ctx.accounts.refund_incentive()?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    /// Sweeps the unclaimed voting reward of a [Proposal] to a treasury token account,
+    /// once its claim deadline has passed or if it did not meet quorum.
+    /// This may only be called by the [Governor::smart_wallet].
     #[access_control(ctx.accounts.validate())]
-    pub fn claim_reward(ctx: Context<ClaimReward>) -> Result<()> {
-        let event = ctx.accounts.claim_reward()?;
+    pub fn sweep_reward(ctx: Context<SweepReward>) -> Result<()> {
+        let event = ctx.accounts.sweep_reward()?;
         emit_cpi!(event);
         Ok(())
     }
@@ -129,6 +416,55 @@ pub mod govern {
         ctx.accounts.set_locker(new_locker)
     }
 
+    /// Sets the [Governor::veto_authority]. The default [Pubkey] disables vetoes.
+    /// This may only be called by the [Governor::smart_wallet].
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_veto_authority(
+        ctx: Context<SetGovernanceParams>,
+        veto_authority: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.set_veto_authority(veto_authority)
+    }
+
+    /// Sets the [Governor::pause_authority], a guardian that may pause the governor in an emergency.
+    /// The default [Pubkey] means only the smart wallet can pause.
+    /// This may only be called by the [Governor::smart_wallet].
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_pause_authority(
+        ctx: Context<SetGovernanceParams>,
+        pause_authority: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.set_pause_authority(pause_authority)
+    }
+
+    /// Pauses the instruction classes in the `instructions` bitmask for `duration` seconds,
+    /// at most [MAX_PAUSE_DURATION]. The pause expires on its own afterwards.
+    /// This may be called by the [Governor::smart_wallet] or the [Governor::pause_authority].
+    /// The pause authority cannot pause again until [MAX_PAUSE_DURATION] after the last pause expired.
+    #[access_control(ctx.accounts.validate())]
+    pub fn pause_governor(
+        ctx: Context<PauseGovernor>,
+        instructions: u8,
+        duration: i64,
+    ) -> Result<()> {
+        ctx.accounts.pause_governor(instructions, duration)
+    }
+
+    /// Lifts a pause early.
+    /// This may be called by the [Governor::smart_wallet] or the [Governor::pause_authority].
+    #[access_control(ctx.accounts.validate())]
+    pub fn unpause_governor(ctx: Context<PauseGovernor>) -> Result<()> {
+        ctx.accounts.unpause_governor()
+    }
+
+    /// Extends the voting of a [Proposal] by the time voting was paused while it was active.
+    /// Votes, reveals, queueing and reward claims of the proposal require it after a pause.
+    /// Anyone can call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn apply_voting_pause(ctx: Context<ApplyVotingPause>) -> Result<()> {
+        ctx.accounts.apply_voting_pause()
+    }
+
     /// Creates a [ProposalMeta].
     #[access_control(ctx.accounts.validate())]
     pub fn create_proposal_meta(
@@ -153,6 +489,57 @@ pub mod govern {
         emit_cpi!(event);
         Ok(())
     }
+
+    /// Replaces the instructions of a [Proposal], reallocating it.
+    /// Only callable by the proposer while the proposal is a [ProposalState::Draft].
+    #[access_control(ctx.accounts.validate())]
+    pub fn update_proposal_instructions(
+        ctx: Context<UpdateProposalInstructions>,
+        instructions: Vec<ProposalInstruction>,
+    ) -> Result<()> {
+        let event = ctx.accounts.update_proposal_instructions(instructions)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    /// Sets the maximum number of options a voter may select on a [ProposalType::Approval] [Proposal].
+    /// Only callable by the proposer while the proposal is a [ProposalState::Draft].
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_proposal_max_selections(
+        ctx: Context<SetProposalMaxSelections>,
+        max_selections: u8,
+    ) -> Result<()> {
+        let event = ctx.accounts.set_proposal_max_selections(max_selections)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    /// Updates a [ProposalMeta], reallocating it.
+    /// Only callable by the proposer while the proposal is a [ProposalState::Draft].
+    #[access_control(ctx.accounts.validate())]
+    pub fn update_proposal_meta(
+        ctx: Context<UpdateProposalMeta>,
+        title: String,
+        description_link: String,
+    ) -> Result<()> {
+        let event = ctx.accounts.update_proposal_meta(title, description_link)?;
+        emit_cpi!(event);
+        Ok(())
+    }
+
+    /// Updates an [OptionProposalMeta], reallocating it.
+    /// Only callable by the proposer while the proposal is a [ProposalState::Draft].
+    #[access_control(ctx.accounts.validate())]
+    pub fn update_option_proposal_meta(
+        ctx: Context<UpdateOptionProposalMeta>,
+        option_descriptions: Vec<String>,
+    ) -> Result<()> {
+        let event = ctx
+            .accounts
+            .update_option_proposal_meta(option_descriptions)?;
+        emit_cpi!(event);
+        Ok(())
+    }
 }
 
 /// Errors.
@@ -178,4 +565,66 @@ pub enum ErrorCode {
     NotOptionProposal,
     #[msg("Invalid option descriptions.")]
     InvalidOptionDescriptions,
+    #[msg("Invalid quorum mode.")]
+    InvalidQuorumMode,
+    #[msg("Quorum basis points must be between 1 and 10000.")]
+    InvalidQuorumBps,
+    #[msg("Approval threshold must be zero or between 5000 and 10000 basis points.")]
+    InvalidApprovalThreshold,
+    #[msg("Optimistic veto basis points must be between 1 and 10000.")]
+    InvalidOptimisticVetoBps,
+    #[msg("Optimistic veto votes must be greater than 0.")]
+    InvalidOptimisticVetoVotes,
+    #[msg("Votes of delegate records and delegation shares do not earn rewards.")]
+    VoteUnrewarded,
+    #[msg("Voting was paused: extend it with apply_voting_pause first.")]
+    VotingPauseNotApplied,
+    #[msg("Max selections is invalid")]
+    InvalidMaxSelections,
+    #[msg("Proposal is not Approval.")]
+    NotApprovalProposal,
+    #[msg("Too many options selected.")]
+    TooManySelections,
+    #[msg("Vote split is invalid")]
+    InvalidVoteSplit,
+    #[msg("Proposal is not commit-reveal.")]
+    NotCommitRevealProposal,
+    #[msg("Votes on a commit-reveal proposal must be committed.")]
+    CommitRevealProposal,
+    #[msg("The proposal must be revealing.")]
+    ProposalNotRevealing,
+    #[msg("The revealed vote does not match the commitment.")]
+    InvalidVoteReveal,
+    #[msg("The vote has already been revealed.")]
+    VoteAlreadyRevealed,
+    #[msg("The timelock of the queued transaction has elapsed.")]
+    TimelockElapsed,
+    #[msg("The proposal must be final.")]
+    ProposalNotFinal,
+    #[msg("The voting reward has been swept.")]
+    RewardSwept,
+    #[msg("The claim deadline of the voting reward has passed.")]
+    RewardClaimDeadlinePassed,
+    #[msg("Too many reward streams.")]
+    TooManyRewardStreams,
+    #[msg("The governor requires a proposal bond.")]
+    ProposalBondRequired,
+    #[msg("The proposal bond cannot be settled yet.")]
+    ProposalBondNotSettleable,
+    #[msg("The instruction is paused.")]
+    GovernorPaused,
+    #[msg("Invalid instructions to pause.")]
+    InvalidPauseInstructions,
+    #[msg("Pause duration must be positive and at most the maximum pause duration.")]
+    InvalidPauseDuration,
+    #[msg("The pause authority must wait for the pause cooldown.")]
+    PauseCooldown,
+    #[msg("The proposal must be queued.")]
+    ProposalNotQueued,
+    #[msg("The queued transaction has neither been executed nor expired.")]
+    QueuedTransactionPending,
+    #[msg("Every option must have option proposal instructions.")]
+    MissingOptionProposalInstructions,
+    #[msg("Reward streams require a reward claim period.")]
+    RewardClaimPeriodRequired,
 }
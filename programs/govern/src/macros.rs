@@ -11,3 +11,41 @@ macro_rules! governor_seeds {
         ]
     };
 }
+
+/// Generates the signer seeds for a ProposalRewards.
+#[macro_export]
+macro_rules! proposal_rewards_seeds {
+    ($proposal_rewards: expr) => {
+        &[
+            b"ProposalRewards" as &[u8],
+            &$proposal_rewards.proposal.as_ref(),
+            &[$proposal_rewards.bump],
+        ]
+    };
+}
+
+/// Generates the signer seeds for an OptionIncentive.
+#[macro_export]
+macro_rules! option_incentive_seeds {
+    ($option_incentive: expr) => {
+        &[
+            b"OptionIncentive" as &[u8],
+            &$option_incentive.proposal.as_ref(),
+            &[$option_incentive.option],
+            &$option_incentive.mint.as_ref(),
+            &[$option_incentive.bump],
+        ]
+    };
+}
+
+/// Generates the signer seeds for a ProposalBond.
+#[macro_export]
+macro_rules! proposal_bond_seeds {
+    ($proposal_bond: expr) => {
+        &[
+            b"ProposalBond" as &[u8],
+            &$proposal_bond.proposal.as_ref(),
+            &[$proposal_bond.bump],
+        ]
+    };
+}
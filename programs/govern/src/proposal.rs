@@ -1,11 +1,12 @@
 //! Proposal logic.
 use crate::ErrorCode::InvalidVoteSide;
 use crate::*;
+use anchor_lang::solana_program::hash::hashv;
 
 /// The state of a proposal.
 ///
-/// The `expired` state from Compound is missing here, because the
-/// Smart Wallet handles execution.
+/// The Smart Wallet handles execution; [ProposalState::Executed] and [ProposalState::Expired]
+/// are recorded from the queued [smart_wallet::Transaction] via [govern::finalize_proposal].
 #[derive(Debug, Eq, PartialEq)]
 #[repr(C)]
 pub enum ProposalState {
@@ -17,18 +18,50 @@ pub enum ProposalState {
     /// is to require the user to have a minimum amount of tokens.
     /// An [ProposalState::Active] proposal is one that is surfaced to the community to put up for voting.
     Active,
+    /// After voting ends, a commit-reveal proposal is [ProposalState::Revealing] until its
+    /// reveal window ends. Only revealed votes are tallied.
+    Revealing,
     /// If a proposal is still a [ProposalState::Draft], a proposal may be canceled by its creator.
     /// A canceled proposal cannot be reactivated; it simply just exists as a record.
     Canceled,
     /// After the voting period ends, votes are tallied up. A proposal is [ProposalState::Defeated] if one of
     /// two scenarios happen:
-    /// - More or equal votes are [VoteSide::Against] than [VoteSide::For].
+    /// - The [VoteSide::For] votes do not exceed the approval threshold of the [VoteSide::For] and [VoteSide::Against] votes.
     /// - The sum of all votes does not meet quorum.
+    ///
+    /// A [ProposalType::Option] proposal with [OptionProposalInstructions] is also [ProposalState::Defeated]
+    /// if there is no [Proposal::winning_option].
+    /// A [ProposalType::Optimistic] proposal is only [ProposalState::Defeated] if its Against votes exceed its veto threshold.
     Defeated,
     /// A proposal is [ProposalState::Succeeded] if it is not defeated and voting is over.
     Succeeded,
     /// A succeeded proposal may be [ProposalState::Queued] into the [SmartWallet].
     Queued,
+    /// A queued proposal was vetoed by the [Governor::veto_authority] during its timelock,
+    /// and its [smart_wallet::Transaction] was canceled.
+    Vetoed,
+    /// The queued [smart_wallet::Transaction] was executed by the [SmartWallet].
+    Executed,
+    /// The queued [smart_wallet::Transaction] was not executed before its ETA plus the
+    /// [SmartWallet] grace period.
+    Expired,
+}
+
+impl ProposalState {
+    /// Whether the outcome of the vote of the [Proposal] is settled.
+    /// A [ProposalState::Succeeded] proposal may still be queued, but no longer depends on its votes.
+    /// A [ProposalState::Queued] proposal is not final, unless its transaction has no ETA and cannot expire.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            ProposalState::Canceled
+                | ProposalState::Defeated
+                | ProposalState::Succeeded
+                | ProposalState::Vetoed
+                | ProposalState::Executed
+                | ProposalState::Expired
+        )
+    }
 }
 
 impl Default for ProposalState {
@@ -64,6 +97,118 @@ impl Proposal {
         Ok(())
     }
 
+    /// Computes the weight each option receives from a split vote.
+    /// Any rounding remainder goes to the last option in the split, so that the
+    /// weights always sum to the `voting_power`.
+    pub fn split_vote_weights(split_bps: &[u16; 11], voting_power: u64) -> Option<[u64; 11]> {
+        let mut weights = [0u64; 11];
+        let mut remaining = voting_power;
+        let mut last_option = None;
+        for (option, bps) in split_bps.iter().enumerate() {
+            if *bps == 0 {
+                continue;
+            }
+            let weight = u64::try_from(
+                (voting_power as u128)
+                    .checked_mul(*bps as u128)?
+                    .checked_div(MAX_BPS as u128)?,
+            )
+            .ok()?;
+            weights[option] = weight;
+            remaining = remaining.checked_sub(weight)?;
+            last_option = Some(option);
+        }
+        if let Some(option) = last_option {
+            weights[option] = weights[option].checked_add(remaining)?;
+        }
+        Some(weights)
+    }
+
+    /// Subtracts the weight of a split vote from each of its options.
+    pub(crate) fn subtract_split_vote_weight(
+        &mut self,
+        split_bps: &[u16; 11],
+        voting_power: u64,
+    ) -> Result<()> {
+        let weights = unwrap_opt!(Proposal::split_vote_weights(split_bps, voting_power));
+        for (option, weight) in weights.iter().enumerate() {
+            if *weight != 0 {
+                self.subtract_vote_weight(option as u8, *weight)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the weight of a split vote to each of its options.
+    pub(crate) fn add_split_vote_weight(
+        &mut self,
+        split_bps: &[u16; 11],
+        voting_power: u64,
+    ) -> Result<()> {
+        let mut total_bps: u16 = 0;
+        for (option, bps) in split_bps.iter().enumerate() {
+            invariant!(
+                *bps == 0 || option <= self.max_option as usize,
+                InvalidVoteSide
+            );
+            total_bps = unwrap_int!(total_bps.checked_add(*bps));
+        }
+        invariant!(total_bps == MAX_BPS, InvalidVoteSplit);
+
+        let weights = unwrap_opt!(Proposal::split_vote_weights(split_bps, voting_power));
+        for (option, weight) in weights.iter().enumerate() {
+            if *weight != 0 {
+                self.add_vote_weight(option as u8, *weight)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Subtracts the weight of an approval vote from each of its selected options.
+    pub(crate) fn subtract_approval_vote_weight(
+        &mut self,
+        selections: u16,
+        voting_power: u64,
+    ) -> Result<()> {
+        if selections == 0 {
+            return Ok(());
+        }
+        for option in 0..=self.max_option {
+            if selections & (1 << option) != 0 {
+                self.subtract_vote_weight(option, voting_power)?;
+            }
+        }
+        self.approval_voting_power =
+            unwrap_int!(self.approval_voting_power.checked_sub(voting_power));
+        Ok(())
+    }
+
+    /// Adds the weight of an approval vote to each of its selected options.
+    pub(crate) fn add_approval_vote_weight(
+        &mut self,
+        selections: u16,
+        voting_power: u64,
+    ) -> Result<()> {
+        invariant!(
+            selections != 0 && selections >> (self.max_option + 1) == 0,
+            InvalidVoteSide
+        );
+        // abstaining excludes selecting any other option
+        invariant!(selections & 1 == 0 || selections == 1, InvalidVoteSide);
+        invariant!(
+            selections.count_ones() <= self.max_selections.into(),
+            TooManySelections
+        );
+        for option in 0..=self.max_option {
+            if selections & (1 << option) != 0 {
+                self.add_vote_weight(option, voting_power)?;
+            }
+        }
+        self.approval_voting_power =
+            unwrap_int!(self.approval_voting_power.checked_add(voting_power));
+        Ok(())
+    }
+
     // Gets the state.
     pub fn get_state(&self) -> Result<ProposalState> {
         Ok(unwrap_opt!(
@@ -74,16 +219,75 @@ impl Proposal {
 
     /// total votes
     pub fn total_votes(&self) -> Option<u64> {
+        if self.proposal_type == u8::from(ProposalType::Approval) {
+            return Some(self.approval_voting_power);
+        }
         let total_vote = self.option_votes.iter().sum();
         Some(total_vote)
     }
 
     /// Checks if the proposal meets quorum; that is,
     /// enough votes were made on the proposal.
+    /// [ProposalType::Optimistic] proposals do not require a quorum.
     pub fn meets_quorum(&self) -> Option<bool> {
+        if self.is_optimistic() {
+            return Some(true);
+        }
         Some(self.total_votes()? >= self.quorum_votes)
     }
 
+    /// Whether the proposal is a [ProposalType::Optimistic] proposal.
+    pub fn is_optimistic(&self) -> bool {
+        self.proposal_type == u8::from(ProposalType::Optimistic)
+    }
+
+    /// Checks if the Against votes of a [ProposalType::Optimistic] proposal strictly exceed
+    /// its veto threshold.
+    pub fn meets_optimistic_veto_threshold(&self) -> Option<bool> {
+        Some(*self.option_votes.get(AGAINST_VOTE_INDEX)? > self.optimistic_veto_votes)
+    }
+
+    /// The approval threshold of the proposal, in basis points.
+    pub fn approval_threshold_bps(&self) -> u16 {
+        if self.approval_threshold_bps == 0 {
+            return SIMPLE_MAJORITY_BPS;
+        }
+        self.approval_threshold_bps
+    }
+
+    /// Checks if the For votes of a [ProposalType::YesNo] proposal strictly exceed
+    /// the approval threshold of the For and Against votes. Abstain votes are not counted.
+    pub fn meets_approval_threshold(&self) -> Option<bool> {
+        let for_votes = self.option_votes[FOR_VOTE_INDEX] as u128;
+        let against_votes = self.option_votes[AGAINST_VOTE_INDEX] as u128;
+        let threshold_votes = for_votes
+            .checked_add(against_votes)?
+            .checked_mul(self.approval_threshold_bps().into())?;
+        Some(for_votes.checked_mul(MAX_BPS.into())? > threshold_votes)
+    }
+
+    /// The option with the most votes, excluding abstain votes.
+    /// Returns `None` if no option received votes, or if two or more options are tied for the most votes.
+    pub fn winning_option(&self) -> Option<u8> {
+        let mut winning_option = None;
+        let mut winning_votes = 0;
+        for (option, &votes) in self.option_votes.iter().enumerate().skip(1) {
+            if votes > winning_votes {
+                winning_option = Some(option as u8);
+                winning_votes = votes;
+            } else if votes == winning_votes {
+                winning_option = None;
+            }
+        }
+        winning_option
+    }
+
+    /// Whether the instructions of every option are set, or the proposal has no option instructions.
+    /// Otherwise a winning option without [OptionProposalInstructions] could not be queued.
+    pub fn has_all_option_instructions(&self) -> bool {
+        !self.has_option_instructions || self.option_instructions_count == self.max_option
+    }
+
     // /// The state of the proposal. See [ProposalState] for more details.
     // /// Adapted from <https://github.com/compound-finance/compound-protocol/blob/4a8648ec0364d24c4ecfc7d6cae254f55030d65f/contracts/Governance/GovernorBravoDelegate.sol#L205>
     pub fn state(&self, current_time: i64) -> Option<ProposalState> {
@@ -93,48 +297,212 @@ impl Proposal {
             return Some(ProposalState::Draft);
         } else if current_time < self.voting_ends_at {
             return Some(ProposalState::Active);
+        } else if current_time < self.reveal_ends_at()? {
+            return Some(ProposalState::Revealing);
         } else if !self.meets_quorum()? {
             return Some(ProposalState::Defeated);
+        } else if self.vetoed_at > 0 {
+            return Some(ProposalState::Vetoed);
+        } else if self.executed_at > 0 {
+            return Some(ProposalState::Executed);
+        } else if self.expired_at > 0 {
+            return Some(ProposalState::Expired);
         } else if self.queued_at > 0 {
             return Some(ProposalState::Queued);
         }
 
-        if self.proposal_type == u8::from(ProposalType::YesNo) {
-            if self.option_votes[FOR_VOTE_INDEX] <= self.option_votes[AGAINST_VOTE_INDEX] {
-                return Some(ProposalState::Defeated);
-            }
+        let defeated = if self.proposal_type == u8::from(ProposalType::YesNo) {
+            !self.meets_approval_threshold()?
+        } else if self.proposal_type == u8::from(ProposalType::Option) {
+            // only the instructions of a winning option can be queued.
+            self.has_option_instructions && self.winning_option().is_none()
+        } else if self.is_optimistic() {
+            self.meets_optimistic_veto_threshold()?
+        } else {
+            false
+        };
+        if defeated {
+            return Some(ProposalState::Defeated);
         }
         Some(ProposalState::Succeeded)
     }
 
+    /// The voting power a [Vote] gives to an option.
+    pub fn option_vote_weight(&self, vote: &Vote, option: u8) -> Option<u64> {
+        if self.proposal_type == u8::from(ProposalType::Approval) {
+            if vote.selections & (1 << option) != 0 {
+                return Some(vote.voting_power);
+            }
+            return Some(0);
+        }
+        if vote.is_split() {
+            let weights = Proposal::split_vote_weights(&vote.split_bps, vote.voting_power)?;
+            return weights.get(option as usize).copied();
+        }
+        if vote.side == option {
+            return Some(vote.voting_power);
+        }
+        Some(0)
+    }
+
+    /// The share of an `amount` deposited for an option that a [Vote] earns,
+    /// pro rata to the voting power it gave to the option.
+    pub fn get_option_incentive_share(&self, amount: u64, vote: &Vote, option: u8) -> Option<u64> {
+        // the share of [Vote::unrewarded] votes is refunded to the depositors with [govern::refund_incentive].
+        if vote.unrewarded {
+            return Some(0);
+        }
+        let option_votes = *self.option_votes.get(option as usize)? as u128;
+        if option_votes == 0 {
+            return Some(0);
+        }
+        let weight = self.option_vote_weight(vote, option)? as u128;
+        (amount as u128)
+            .checked_mul(weight)?
+            .checked_div(option_votes)?
+            .try_into()
+            .ok()
+    }
+
+    /// Whether the voting of the proposal is over and its votes are final.
+    pub fn is_voting_over(&self, current_time: i64) -> Option<bool> {
+        Some(!matches!(
+            self.state(current_time)?,
+            ProposalState::Draft
+                | ProposalState::Active
+                | ProposalState::Revealing
+                | ProposalState::Canceled
+        ))
+    }
+
+    /// The timestamp after which [OptionIncentive]s can no longer be claimed.
+    pub fn incentive_claim_deadline(&self) -> Option<i64> {
+        self.reveal_ends_at()?.checked_add(INCENTIVE_CLAIM_PERIOD)
+    }
+
+    /// Whether votes on the proposal are committed and revealed.
+    pub fn is_commit_reveal(&self) -> bool {
+        self.reveal_period > 0
+    }
+
+    /// The timestamp when the reveal window ends.
+    /// This is [Proposal::voting_ends_at] if the proposal is not commit-reveal.
+    pub fn reveal_ends_at(&self) -> Option<i64> {
+        self.voting_ends_at
+            .checked_add(self.reveal_period.to_i64()?)
+    }
+
+    /// The extended [Proposal::voting_ends_at] if quorum is reached at `current_time`,
+    /// or [None] if voting should not be extended.
+    pub fn late_quorum_voting_ends_at(&self, current_time: i64) -> Option<i64> {
+        if self.late_quorum_extension == 0 || self.late_quorum_extended_at > 0 {
+            return None;
+        }
+        let extended_ends_at = current_time.checked_add(self.late_quorum_extension.to_i64()?)?;
+        if extended_ends_at > self.voting_ends_at {
+            Some(extended_ends_at)
+        } else {
+            None
+        }
+    }
+
+    /// Extends voting if the proposal reached quorum at `current_time` and did not have it before.
+    /// Voting is extended only once, so withdrawing and recasting votes to reach quorum again
+    /// cannot extend it any further. Returns the previous [Proposal::voting_ends_at] if extended.
+    pub fn extend_voting_on_late_quorum(
+        &mut self,
+        had_quorum: bool,
+        current_time: i64,
+    ) -> Option<Option<i64>> {
+        if had_quorum || !self.meets_quorum()? {
+            return Some(None);
+        }
+        let voting_ends_at = match self.late_quorum_voting_ends_at(current_time) {
+            Some(voting_ends_at) => voting_ends_at,
+            None => return Some(None),
+        };
+        let previous_voting_ends_at = self.voting_ends_at;
+        self.voting_ends_at = voting_ends_at;
+        self.late_quorum_extended_at = current_time;
+        Some(Some(previous_voting_ends_at))
+    }
+
+    /// The time by which voting should be extended for the pauses of the [Governor] since it
+    /// was last extended. Only a pause that started before voting ended extends it.
+    pub fn voting_pause_extension(&self, governor: &Governor, current_time: i64) -> Option<i64> {
+        if self.activated_at == 0
+            || self.canceled_at > 0
+            || self.queued_at > 0
+            || self.voting_ends_at <= governor.paused_at
+        {
+            return Some(0);
+        }
+        governor
+            .voting_paused_duration_at(current_time)?
+            .checked_sub(self.voting_paused_duration)
+    }
+
+    /// Checks that voting was extended for the pauses of the [Governor] with [govern::apply_voting_pause].
+    pub fn assert_voting_pause_applied(&self, governor: &Governor) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        invariant!(
+            unwrap_int!(self.voting_pause_extension(governor, now)) == 0,
+            VotingPauseNotApplied
+        );
+        Ok(())
+    }
+
     /// Converts this proposal to Smart Wallet [smart_wallet::TXInstruction]s.
     pub fn to_smart_wallet_instructions(&self) -> Vec<smart_wallet::TXInstruction> {
         self.instructions
             .iter()
-            .map(
-                |ProposalInstruction {
-                     program_id,
-                     keys,
-                     data,
-                 }| smart_wallet::TXInstruction {
-                    program_id: *program_id,
-                    keys: keys
-                        .iter()
-                        .map(
-                            |&ProposalAccountMeta {
-                                 pubkey,
-                                 is_signer,
-                                 is_writable,
-                             }| smart_wallet::TXAccountMeta {
-                                pubkey,
-                                is_signer,
-                                is_writable,
-                            },
-                        )
-                        .collect(),
-                    data: data.clone(),
-                },
-            )
+            .map(ProposalInstruction::to_smart_wallet_instruction)
+            .collect()
+    }
+}
+
+impl VoteCommitment {
+    /// Computes the commitment of a vote.
+    pub fn hash(side: u8, voting_power: u64, salt: &[u8; 32]) -> [u8; 32] {
+        hashv(&[&[side], &voting_power.to_le_bytes(), salt]).to_bytes()
+    }
+}
+
+impl OptionProposalInstructions {
+    /// Converts the instructions of this option to Smart Wallet [smart_wallet::TXInstruction]s.
+    pub fn to_smart_wallet_instructions(&self) -> Vec<smart_wallet::TXInstruction> {
+        self.instructions
+            .iter()
+            .map(ProposalInstruction::to_smart_wallet_instruction)
             .collect()
     }
 }
+
+impl ProposalInstruction {
+    /// Converts this instruction to a Smart Wallet [smart_wallet::TXInstruction].
+    pub fn to_smart_wallet_instruction(&self) -> smart_wallet::TXInstruction {
+        let ProposalInstruction {
+            program_id,
+            keys,
+            data,
+        } = self;
+        smart_wallet::TXInstruction {
+            program_id: *program_id,
+            keys: keys
+                .iter()
+                .map(
+                    |&ProposalAccountMeta {
+                         pubkey,
+                         is_signer,
+                         is_writable,
+                     }| smart_wallet::TXAccountMeta {
+                        pubkey,
+                        is_signer,
+                        is_writable,
+                    },
+                )
+                .collect(),
+            data: data.clone(),
+        }
+    }
+}
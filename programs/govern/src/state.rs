@@ -1,6 +1,6 @@
 //! Struct definitions for accounts that hold state.
 
-use anchor_lang::prelude::*;
+use crate::*;
 use vipers::program_err;
 /// A Governor is the "DAO": it is the account that holds control over important protocol functions,
 /// including treasury, protocol parameters, and more.
@@ -29,17 +29,89 @@ pub struct Governor {
     /// optional reward, can set by smartwallet
     pub voting_reward: VotingReward,
 
+    /// Authority that may veto queued proposals during their timelock, set by the smart wallet.
+    /// The default [Pubkey] disables vetoes.
+    pub veto_authority: Pubkey,
+
+    /// Bond escrowed by proposers at [govern::create_proposal], set by the smart wallet.
+    pub proposal_bond: ProposalBondParams,
+
+    /// Guardian that may pause the governor in an emergency, set by the smart wallet.
+    /// The default [Pubkey] means only the smart wallet can pause.
+    pub pause_authority: Pubkey,
+    /// The timestamp until which the [Governor::paused_instructions] are paused.
+    pub paused_until: i64,
+    /// Bitmask of the instruction classes that are paused, e.g. [PAUSE_CREATE_PROPOSAL].
+    pub paused_instructions: u8,
+    /// padding for further use
+    pub padding: [u8; 7],
+    /// The timestamp when the last pause started.
+    pub paused_at: i64,
+    /// Total time that voting was paused, up to the last pause.
+    /// Proposals that were active during a pause get their voting extended by the paused time.
+    pub voting_paused_duration: i64,
+
+    /// Voting parameters, set by the smart wallet.
+    pub voting_params: VotingParameters,
+
+    /// The claim deadline of voting rewards and reward streams, in seconds after voting ends, set by the smart wallet.
+    /// Zero means rewards can be claimed at any time.
+    /// Unclaimed rewards can be swept back to the treasury once the deadline passes.
+    pub reward_claim_period: u64,
+
     /// buffer for further use
-    pub buffers: [u128; 32],
+    pub buffers: [u128; 21],
 }
 impl Governor {
     /// LEN of Governor
-    pub const LEN: usize = std::mem::size_of::<Pubkey>() * 3
+    pub const LEN: usize = std::mem::size_of::<Pubkey>() * 5
         + 1
         + 8
-        + 16 * 32
         + std::mem::size_of::<GovernanceParameters>()
-        + std::mem::size_of::<VotingReward>();
+        + std::mem::size_of::<VotingReward>()
+        + std::mem::size_of::<ProposalBondParams>()
+        + 8
+        + 1
+        + 7
+        + 8
+        + 8
+        + VotingParameters::LEN
+        + 8
+        + 16 * 21;
+
+    /// Whether an instruction class, e.g. [PAUSE_SET_VOTE], is paused at `now`.
+    /// A pause expires on its own at [Governor::paused_until].
+    pub fn is_paused(&self, instruction: u8, now: i64) -> bool {
+        now < self.paused_until && self.paused_instructions & instruction != 0
+    }
+
+    /// Total time that voting, i.e. [PAUSE_SET_VOTE], was paused up to `now`.
+    pub fn voting_paused_duration_at(&self, now: i64) -> Option<i64> {
+        if self.paused_instructions & PAUSE_SET_VOTE == 0 {
+            return Some(self.voting_paused_duration);
+        }
+        let paused_duration = now.min(self.paused_until).checked_sub(self.paused_at)?;
+        self.voting_paused_duration
+            .checked_add(paused_duration.max(0))
+    }
+
+    /// Checks that an instruction class is not paused.
+    pub fn assert_not_paused(&self, instruction: u8) -> Result<()> {
+        invariant!(
+            !self.is_paused(instruction, Clock::get()?.unix_timestamp),
+            GovernorPaused
+        );
+        Ok(())
+    }
+}
+
+/// Proposal bond parameters.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProposalBondParams {
+    /// Bond mint
+    pub mint: Pubkey,
+    /// Bond amount. Zero means proposals do not require a bond.
+    pub amount: u64,
 }
 
 /// Governance parameters.
@@ -66,6 +138,120 @@ pub struct GovernanceParameters {
     pub timelock_delay_seconds: i64,
 }
 
+impl GovernanceParameters {
+    /// Validates the [GovernanceParameters].
+    pub fn validate(&self) -> Result<()> {
+        invariant!(
+            self.timelock_delay_seconds >= 0,
+            "timelock delay must be at least 0 seconds"
+        );
+        Ok(())
+    }
+}
+
+/// Voting parameters.
+/// Zero values keep the behavior of a [Governor] that never set them.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct VotingParameters {
+    /// How the quorum of a proposal is computed. See [QuorumMode].
+    pub quorum_mode: u8,
+    /// Quorum in basis points of the locker's voting power.
+    /// Only used when [VotingParameters::quorum_mode] is [QuorumMode::LockedSupplyFraction].
+    ///
+    /// The locker's voting power is a deliberate upper bound: the whole locked supply is counted
+    /// as if it were max locked, including escrows whose lockup has expired but which were not
+    /// withdrawn yet. The voting power that can actually be cast is lower, so the quorum is
+    /// at least as hard to reach as `quorum_bps` of it.
+    pub quorum_bps: u16,
+    /// The share of For votes, in basis points of For and Against votes, that a [ProposalType::YesNo]
+    /// proposal must strictly exceed in order to pass. Zero means a simple majority.
+    pub approval_threshold_bps: u16,
+    /// The duration of the reveal window after voting ends, in seconds.
+    /// If non-zero, proposals use commit-reveal voting: votes are committed as hashes while the
+    /// proposal is [ProposalState::Active], and only count once revealed.
+    /// Commit-reveal votes have a single side: [ProposalType::Approval] proposals and split votes are not allowed.
+    pub reveal_period: u64,
+    /// If non-zero, a proposal reaching quorum less than this many seconds before voting ends
+    /// has its voting extended to this many seconds after quorum was reached.
+    pub late_quorum_extension: u64,
+    /// How the veto threshold of a [ProposalType::Optimistic] proposal is computed. See [QuorumMode].
+    pub optimistic_veto_mode: u8,
+    /// The number of Against votes a [ProposalType::Optimistic] proposal must strictly exceed to be defeated.
+    /// Only used when [VotingParameters::optimistic_veto_mode] is [QuorumMode::Absolute].
+    pub optimistic_veto_votes: u64,
+    /// The veto threshold in basis points of the locker's voting power.
+    /// Only used when [VotingParameters::optimistic_veto_mode] is [QuorumMode::LockedSupplyFraction].
+    pub optimistic_veto_bps: u16,
+}
+
+impl VotingParameters {
+    /// Serialized size of the [VotingParameters].
+    pub const LEN: usize = 1 + 2 + 2 + 8 + 8 + 1 + 8 + 2;
+
+    /// Validates the [VotingParameters].
+    pub fn validate(&self) -> Result<()> {
+        let quorum_mode = QuorumMode::try_from(self.quorum_mode)?;
+        if quorum_mode == QuorumMode::LockedSupplyFraction {
+            invariant!(
+                self.quorum_bps > 0 && self.quorum_bps <= MAX_BPS,
+                InvalidQuorumBps
+            );
+        }
+        invariant!(
+            self.approval_threshold_bps == 0
+                || (self.approval_threshold_bps >= SIMPLE_MAJORITY_BPS
+                    && self.approval_threshold_bps <= MAX_BPS),
+            InvalidApprovalThreshold
+        );
+        let optimistic_veto_mode = QuorumMode::try_from(self.optimistic_veto_mode)?;
+        if optimistic_veto_mode == QuorumMode::LockedSupplyFraction {
+            invariant!(
+                self.optimistic_veto_bps > 0 && self.optimistic_veto_bps <= MAX_BPS,
+                InvalidOptimisticVetoBps
+            );
+        } else {
+            // a single Against vote must not defeat an optimistic proposal.
+            invariant!(self.optimistic_veto_votes > 0, InvalidOptimisticVetoVotes);
+        }
+        Ok(())
+    }
+}
+
+/// Quorum mode
+#[derive(Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum QuorumMode {
+    /// Quorum is a fixed number of votes, [GovernanceParameters::quorum_votes].
+    Absolute = 0,
+    /// Quorum is [VotingParameters::quorum_bps] of the voting power of the locked supply,
+    /// snapshotted when the proposal is activated.
+    LockedSupplyFraction = 1,
+}
+
+impl Default for QuorumMode {
+    fn default() -> Self {
+        QuorumMode::Absolute
+    }
+}
+
+impl From<QuorumMode> for u8 {
+    fn from(quorum_mode: QuorumMode) -> Self {
+        quorum_mode as u8
+    }
+}
+
+impl TryFrom<u8> for QuorumMode {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(QuorumMode::Absolute),
+            1 => Ok(QuorumMode::LockedSupplyFraction),
+            _ => program_err!(InvalidQuorumMode),
+        }
+    }
+}
+
 /// Proposal type
 #[derive(Debug, Eq, PartialEq)]
 #[repr(u8)]
@@ -74,6 +260,12 @@ pub enum ProposalType {
     YesNo = 0,
     /// Option
     Option = 1,
+    /// Approval: voters may select up to [Proposal::max_selections] options,
+    /// and each selected option receives the full voting power.
+    Approval = 2,
+    /// Optimistic: the proposal succeeds when voting ends, unless its Against votes exceed
+    /// the veto threshold snapshotted into [Proposal::optimistic_veto_votes]. For votes are not required.
+    Optimistic = 3,
 }
 
 impl Default for ProposalType {
@@ -95,6 +287,8 @@ impl TryFrom<u8> for ProposalType {
         match value {
             0 => Ok(ProposalType::YesNo),
             1 => Ok(ProposalType::Option),
+            2 => Ok(ProposalType::Approval),
+            3 => Ok(ProposalType::Optimistic),
             _ => program_err!(InvalidProposalType),
         }
     }
@@ -114,7 +308,9 @@ pub struct Proposal {
     /// The public key of the proposer.
     pub proposer: Pubkey,
 
-    /// The number of votes in support of a proposal required in order for a quorum to be reached and for a vote to succeed
+    /// The number of votes in support of a proposal required in order for a quorum to be reached and for a vote to succeed.
+    /// With [QuorumMode::LockedSupplyFraction], this is computed when the proposal is activated.
+    /// For a [ProposalType::Optimistic] proposal, this is the veto threshold of Against votes instead.
     pub quorum_votes: u64,
 
     /// maximum options of the proposal
@@ -147,8 +343,64 @@ pub struct Proposal {
 
     pub proposal_type: u8,
 
+    /// The [QuorumMode] of the [Governor] when the proposal was created.
+    pub quorum_mode: u8,
+    /// The [VotingParameters::quorum_bps] when the proposal was created.
+    pub quorum_bps: u16,
+    /// The [VotingParameters::approval_threshold_bps] when the proposal was created.
+    pub approval_threshold_bps: u16,
+
+    /// The timestamp when the queued transaction was executed.
+    pub executed_at: i64,
+    /// The timestamp when the queued transaction was marked as expired.
+    pub expired_at: i64,
+    /// Whether the options of the proposal carry their own [OptionProposalInstructions].
+    pub has_option_instructions: bool,
+    /// Maximum number of options a voter may select. Only greater than 1 for [ProposalType::Approval].
+    pub max_selections: u8,
+    /// Total voting power of the votes on a [ProposalType::Approval] proposal.
+    /// Each vote is counted once, regardless of how many options it selects.
+    pub approval_voting_power: u64,
+    /// The [VotingParameters::reveal_period] when the proposal was created.
+    pub reveal_period: u64,
+    /// The [VotingParameters::late_quorum_extension] when the proposal was created.
+    pub late_quorum_extension: u64,
+    /// The timestamp when the proposal was vetoed by the [Governor::veto_authority].
+    pub vetoed_at: i64,
+    /// The timestamp when the unclaimed voting reward was swept back to the treasury.
+    pub reward_swept_at: i64,
+    /// Whether incentives were deposited for an option of the proposal.
+    /// Votes cannot be closed until the [Proposal::incentive_claim_deadline].
+    pub has_option_incentives: bool,
+    /// The [VotingParameters::optimistic_veto_mode] when the proposal was created.
+    pub optimistic_veto_mode: u8,
+    /// The [VotingParameters::optimistic_veto_bps] when the proposal was created.
+    pub optimistic_veto_bps: u16,
+    /// The number of options with [OptionProposalInstructions].
+    /// Every option must have them for the proposal to be activated.
+    pub option_instructions_count: u8,
+    /// The number of times the proposal and its metadata were edited as a draft.
+    pub revision: u32,
+    /// The [Governor::reward_claim_period] when the proposal was created.
+    pub reward_claim_period: u64,
+    /// The number of Against votes a [ProposalType::Optimistic] proposal must strictly exceed to be defeated.
+    /// Computed from the [Proposal::optimistic_veto_mode] when the proposal is activated.
+    pub optimistic_veto_votes: u64,
+    /// Total voting power of the [Vote::unrewarded] votes, excluded when sharing rewards.
+    pub unrewarded_voting_power: u64,
+    /// The [Governor::voting_paused_duration_at] when voting was last extended for a pause,
+    /// or when the proposal was activated.
+    pub voting_paused_duration: i64,
+
+    /// The timestamp when voting was extended because quorum was reached late.
+    /// Voting is extended at most once.
+    pub late_quorum_extended_at: i64,
+    /// The number of [OptionIncentive]s holding deposits that were not refunded.
+    /// A canceled proposal cannot be closed until every deposit is refunded.
+    pub funded_option_incentives: u64,
+
     /// buffers for future use
-    pub buffers: [u128; 10],
+    pub buffers: [u64; 5],
 
     /// The instructions associated with the proposal.
     pub instructions: Vec<ProposalInstruction>,
@@ -158,7 +410,7 @@ impl Proposal {
     /// Space that the [Proposal] takes up.
     pub fn space(max_option: u8, instructions: Vec<ProposalInstruction>) -> usize {
         std::mem::size_of::<Pubkey>() * 3
-        + 8 * 8 + 3 + 16 * 10 + std::mem::size_of::<VotingReward>()
+        + 8 * 8 + 3 + 1 + 2 + 2 + 8 + 8 + 1 + 1 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 2 + 1 + 4 + 8 + 8 + 8 + 8 + 8 + 8 + 8 * 5 + std::mem::size_of::<VotingReward>()
         + 4 // Vec discriminator
         + (max_option as usize * 8)
         + 4 // Vec discriminator            
@@ -166,17 +418,100 @@ impl Proposal {
     }
 
     pub fn get_voting_reward(&self, vote: &Vote) -> Option<u64> {
-        let total_vote = self.total_votes()? as u128;
+        self.get_reward_share(self.voting_reward.reward_per_proposal, vote)
+    }
+
+    /// The share of a reward `amount` that a [Vote] earns, pro rata to its voting power.
+    /// [Vote::unrewarded] votes earn nothing and are left out of the total voting power.
+    pub fn get_reward_share(&self, amount: u64, vote: &Vote) -> Option<u64> {
+        if vote.unrewarded {
+            return Some(0);
+        }
+        let total_vote = self
+            .total_votes()?
+            .checked_sub(self.unrewarded_voting_power)? as u128;
         if total_vote == 0 {
             return Some(0);
         }
-        let reward_per_proposal = self.voting_reward.reward_per_proposal as u128;
         let voting_power = vote.voting_power as u128;
-        let voting_reward = reward_per_proposal
+        let voting_reward = (amount as u128)
             .checked_mul(voting_power)?
             .checked_div(total_vote)?;
         return voting_reward.try_into().ok();
     }
+
+    /// Whether the [Vote] has a reward it can still claim with [govern::claim_reward],
+    /// either the voting reward or a [RewardStream] of the [ProposalRewards].
+    pub fn has_unclaimed_reward(
+        &self,
+        vote: &Vote,
+        proposal_rewards: Option<&ProposalRewards>,
+    ) -> Option<bool> {
+        if !self.meets_quorum()? {
+            return Some(false);
+        }
+        if !vote.claimed && self.reward_swept_at == 0 && self.get_voting_reward(vote)? > 0 {
+            return Some(true);
+        }
+        for (index, stream) in proposal_rewards
+            .map(|proposal_rewards| proposal_rewards.streams.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+        {
+            if vote.claimed_streams & (1 << index) == 0
+                && stream.refunded_at == 0
+                && self.get_reward_share(stream.amount, vote)? > 0
+            {
+                return Some(true);
+            }
+        }
+        Some(false)
+    }
+
+    /// The timestamp after which voting rewards can no longer be claimed, if any.
+    pub fn reward_claim_deadline(&self) -> Option<Option<i64>> {
+        if self.reward_claim_period == 0 {
+            return Some(None);
+        }
+        Some(Some(
+            self.reveal_ends_at()?
+                .checked_add(self.reward_claim_period.to_i64()?)?,
+        ))
+    }
+
+    /// The voting reward that has not been claimed.
+    pub fn unclaimed_reward(&self) -> Option<u64> {
+        self.voting_reward
+            .reward_per_proposal
+            .checked_sub(self.total_claimed_reward)
+    }
+
+    /// Computes the quorum votes from the voting power of the locked supply,
+    /// according to the [Proposal::quorum_mode].
+    pub fn compute_quorum_votes(&self, locked_voting_power: u64) -> Option<u64> {
+        if self.quorum_mode == u8::from(QuorumMode::LockedSupplyFraction) {
+            return locked_supply_fraction(locked_voting_power, self.quorum_bps);
+        }
+        Some(self.quorum_votes)
+    }
+
+    /// Computes the veto threshold of a [ProposalType::Optimistic] proposal from the voting power
+    /// of the locked supply, according to the [Proposal::optimistic_veto_mode].
+    pub fn compute_optimistic_veto_votes(&self, locked_voting_power: u64) -> Option<u64> {
+        if self.optimistic_veto_mode == u8::from(QuorumMode::LockedSupplyFraction) {
+            return locked_supply_fraction(locked_voting_power, self.optimistic_veto_bps);
+        }
+        Some(self.optimistic_veto_votes)
+    }
+}
+
+/// The `bps` share of the voting power of the locked supply.
+fn locked_supply_fraction(locked_voting_power: u64, bps: u16) -> Option<u64> {
+    let votes = (locked_voting_power as u128)
+        .checked_mul(bps.into())?
+        .checked_div(MAX_BPS.into())?;
+    votes.try_into().ok()
 }
 
 /// Metadata about a proposal.
@@ -191,6 +526,17 @@ pub struct ProposalMeta {
     pub description_link: String,
 }
 
+impl ProposalMeta {
+    /// Space that a [ProposalMeta] takes up.
+    pub fn space(title: &str, description_link: &str) -> usize {
+        std::mem::size_of::<Pubkey>()
+            + 4
+            + title.as_bytes().len()
+            + 4
+            + description_link.as_bytes().len()
+    }
+}
+
 /// Metadata about an option proposal.
 #[account]
 #[derive(Debug, Default)]
@@ -212,6 +558,60 @@ impl OptionProposalMeta {
     }
 }
 
+/// Instructions executed if an option of an option [Proposal] wins.
+#[account]
+#[derive(Debug, Default)]
+pub struct OptionProposalInstructions {
+    /// The [Proposal].
+    pub proposal: Pubkey,
+    /// The option, from 1 to [Proposal::max_option].
+    pub option: u8,
+    /// Bump seed
+    pub bump: u8,
+    /// The instructions queued into the [smart_wallet::SmartWallet] if the option wins.
+    pub instructions: Vec<ProposalInstruction>,
+}
+
+impl OptionProposalInstructions {
+    /// Space that the [OptionProposalInstructions] takes up.
+    pub fn space(instructions: &[ProposalInstruction]) -> usize {
+        std::mem::size_of::<Pubkey>()
+            + 1
+            + 1
+            + 4 // Vec discriminator
+            + (instructions.iter().map(|ix| ix.space()).sum::<usize>())
+    }
+}
+
+/// Instructions uploaded by a proposer across several transactions, consumed by [govern::create_proposal].
+#[account]
+#[derive(Debug, Default)]
+pub struct InstructionBuffer {
+    /// The [Governor].
+    pub governor: Pubkey,
+    /// The proposer who owns the buffer.
+    pub proposer: Pubkey,
+    /// Bump seed
+    pub bump: u8,
+    /// The instructions uploaded so far.
+    pub instructions: Vec<ProposalInstruction>,
+}
+
+impl InstructionBuffer {
+    /// Space that the [InstructionBuffer] takes up.
+    pub fn space(instructions: &[ProposalInstruction]) -> usize {
+        std::mem::size_of::<Pubkey>() * 2
+            + 1
+            + 4 // Vec discriminator
+            + (instructions.iter().map(|ix| ix.space()).sum::<usize>())
+    }
+
+    /// Space that the instructions of the [InstructionBuffer] take up in a [Proposal].
+    pub fn instructions_space(&self) -> usize {
+        self.instructions.iter().map(|ix| ix.space()).sum::<usize>()
+    }
+}
+
 /// A [Vote] is a vote made by a `voter`
 #[account]
 #[derive(Debug, Default)]
@@ -228,12 +628,195 @@ pub struct Vote {
     pub voting_power: u64,
     /// Flag to check whether voter has claim the reward or not
     pub claimed: bool,
+    /// Bitmask of the options selected on a [ProposalType::Approval] proposal,
+    /// where bit `i` is set if option `i` is selected.
+    pub selections: u16,
+    /// Share of the voting power given to each option, in basis points,
+    /// if the vote is split across options. All zero otherwise.
+    pub split_bps: [u16; 11],
+    /// Bitmask of the [ProposalRewards::streams] claimed, where bit `i` is set if stream `i` was claimed.
+    pub claimed_streams: u8,
+    /// Whether the voter overrode the vote cast by their delegate. Set by the [Governor::locker].
+    pub owner_override: bool,
+    /// Whether the vote was cast by a program address of the [Governor::locker] pooling the voting power
+    /// of escrows, such as a delegate record or a delegation share. Set by the [Governor::locker].
+    /// Such votes cannot claim rewards, and their voting power does not dilute the rewards of other votes.
+    pub unrewarded: bool,
     /// buffers for future use
-    pub buffers: [u8; 32],
+    pub buffers: [u8; 5],
 }
 impl Vote {
     /// LEN of Vote
-    pub const LEN: usize = std::mem::size_of::<Pubkey>() * 2 + 1 + 1 + 8 + 1 + 32;
+    pub const LEN: usize =
+        std::mem::size_of::<Pubkey>() * 2 + 1 + 1 + 8 + 1 + 2 + 2 * 11 + 1 + 1 + 1 + 5;
+
+    /// Whether the voting power of the vote is split across options.
+    pub fn is_split(&self) -> bool {
+        self.split_bps.iter().any(|bps| *bps != 0)
+    }
+}
+
+/// Additional voting rewards of a [Proposal], funded by anyone.
+#[account]
+#[derive(Debug, Default)]
+pub struct ProposalRewards {
+    /// The [Proposal].
+    pub proposal: Pubkey,
+    /// Bump seed
+    pub bump: u8,
+    /// The reward streams. At most [MAX_REWARD_STREAMS].
+    pub streams: Vec<RewardStream>,
+}
+impl ProposalRewards {
+    /// LEN of ProposalRewards
+    pub const LEN: usize = std::mem::size_of::<Pubkey>()
+        + 1
+        + 4 // Vec discriminator
+        + MAX_REWARD_STREAMS * RewardStream::LEN;
+}
+
+/// A reward paid to voters of a [Proposal], pro rata to their voting power.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct RewardStream {
+    /// Reward mint
+    pub mint: Pubkey,
+    /// Reward vault, owned by the [ProposalRewards].
+    pub vault: Pubkey,
+    /// The funder, who gets the unclaimed reward back with [govern::refund_reward_stream].
+    pub funder: Pubkey,
+    /// Total reward of the stream
+    pub amount: u64,
+    /// Total claimed reward of the stream
+    pub total_claimed: u64,
+    /// The timestamp when the unclaimed reward was refunded to the funder.
+    pub refunded_at: i64,
+}
+impl RewardStream {
+    /// LEN of RewardStream
+    pub const LEN: usize = std::mem::size_of::<Pubkey>() * 3 + 8 + 8 + 8;
+}
+
+/// Tokens deposited by third parties for the voters of an option of a [Proposal].
+#[account]
+#[derive(Debug, Default)]
+pub struct OptionIncentive {
+    /// The [Proposal].
+    pub proposal: Pubkey,
+    /// The option voters must vote for to share the incentive.
+    pub option: u8,
+    /// Incentive mint
+    pub mint: Pubkey,
+    /// Incentive vault, owned by the [OptionIncentive].
+    pub vault: Pubkey,
+    /// Bump seed
+    pub bump: u8,
+    /// Total amount deposited, excluding refunds.
+    pub total_deposited: u64,
+    /// Total amount claimed by voters.
+    pub total_claimed: u64,
+    /// Total amount of the deposits refunded after the [Proposal::incentive_claim_deadline].
+    pub refunded_deposits: u64,
+    /// Total amount refunded after the [Proposal::incentive_claim_deadline].
+    pub total_refunded: u64,
+    /// buffers for future use
+    pub buffers: [u8; 16],
+}
+impl OptionIncentive {
+    /// LEN of OptionIncentive
+    pub const LEN: usize = std::mem::size_of::<Pubkey>() * 3 + 1 + 1 + 8 + 8 + 8 + 8 + 16;
+
+    /// The share of the unclaimed incentive refunded for a `deposit` after the [Proposal::incentive_claim_deadline],
+    /// pro rata to the deposits. The last deposit refunded gets the rounding remainder.
+    pub fn unclaimed_refund(&self, deposit: u64) -> Option<u64> {
+        let unclaimed = self.total_deposited.checked_sub(self.total_claimed)?;
+        if self.refunded_deposits.checked_add(deposit)? == self.total_deposited {
+            return unclaimed.checked_sub(self.total_refunded);
+        }
+        (deposit as u128)
+            .checked_mul(unclaimed.into())?
+            .checked_div(self.total_deposited.into())?
+            .try_into()
+            .ok()
+    }
+}
+
+/// The deposit of a depositor into an [OptionIncentive].
+#[account]
+#[derive(Debug, Default)]
+pub struct IncentiveDeposit {
+    /// The [OptionIncentive].
+    pub option_incentive: Pubkey,
+    /// The depositor.
+    pub depositor: Pubkey,
+    /// Bump seed
+    pub bump: u8,
+    /// Amount deposited.
+    pub amount: u64,
+}
+impl IncentiveDeposit {
+    /// LEN of IncentiveDeposit
+    pub const LEN: usize = std::mem::size_of::<Pubkey>() * 2 + 1 + 8;
+}
+
+/// Receipt of a [Vote] claiming its share of an [OptionIncentive].
+#[account]
+#[derive(Debug, Default)]
+pub struct IncentiveClaim {
+    /// The [OptionIncentive].
+    pub option_incentive: Pubkey,
+    /// The [Vote].
+    pub vote: Pubkey,
+    /// Amount claimed.
+    pub amount: u64,
+}
+impl IncentiveClaim {
+    /// LEN of IncentiveClaim
+    pub const LEN: usize = std::mem::size_of::<Pubkey>() * 2 + 8;
+}
+
+/// A [VoteCommitment] holds the hidden side of a [Vote] on a commit-reveal [Proposal].
+#[account]
+#[derive(Debug, Default)]
+pub struct VoteCommitment {
+    /// The [Vote].
+    pub vote: Pubkey,
+    /// Bump seed
+    pub bump: u8,
+    /// The voting power committed.
+    pub voting_power: u64,
+    /// Hash of the side, voting power and salt. See [VoteCommitment::hash].
+    pub commitment: [u8; 32],
+    /// Whether the vote has been revealed.
+    pub revealed: bool,
+    /// buffers for future use
+    pub buffers: [u8; 32],
+}
+impl VoteCommitment {
+    /// LEN of VoteCommitment
+    pub const LEN: usize = std::mem::size_of::<Pubkey>() + 1 + 8 + 32 + 1 + 32;
+}
+
+/// The bond escrowed by the proposer of a [Proposal].
+/// Refunded if the proposal reaches quorum, forfeited to the treasury otherwise.
+#[account]
+#[derive(Debug, Default)]
+pub struct ProposalBond {
+    /// The [Proposal].
+    pub proposal: Pubkey,
+    /// The [Proposal::proposer].
+    pub proposer: Pubkey,
+    /// Bond vault, owned by the [ProposalBond].
+    pub vault: Pubkey,
+    /// Bump seed
+    pub bump: u8,
+    /// Amount escrowed.
+    pub amount: u64,
+    /// buffers for future use
+    pub buffers: [u8; 32],
+}
+impl ProposalBond {
+    /// LEN of ProposalBond
+    pub const LEN: usize = std::mem::size_of::<Pubkey>() * 3 + 1 + 8 + 32;
 }
 
 /// Instruction.
@@ -275,6 +858,14 @@ mod state_test {
     use anchor_lang::{prelude::Pubkey, AnchorSerialize, Discriminator};
     use std::assert_eq;
 
+    #[test]
+    fn test_governor_space() {
+        let serialized_bytes = Governor::default().try_to_vec().unwrap().len();
+        assert_eq!(serialized_bytes, Governor::LEN);
+        // new fields are taken from the buffers, so existing governors keep their size
+        assert_eq!(Governor::LEN, 721);
+    }
+
     #[test]
     fn test_proposal_instruction_space() {
         let proposal_ix = ProposalInstruction {
@@ -298,6 +889,36 @@ mod state_test {
         assert_eq!(serialized_bytes, proposal_ix_rent_space);
     }
 
+    #[test]
+    fn test_instruction_buffer_space() {
+        let proposal_ix = ProposalInstruction {
+            program_id: Pubkey::default(),
+            data: vec![0u8; 64],
+            keys: vec![
+                ProposalAccountMeta {
+                    is_signer: false,
+                    is_writable: true,
+                    pubkey: Pubkey::default(),
+                };
+                4
+            ],
+        };
+        let instruction_buffer = InstructionBuffer {
+            instructions: vec![proposal_ix; 3],
+            ..InstructionBuffer::default()
+        };
+
+        let serialized_bytes = instruction_buffer.try_to_vec().unwrap().len();
+        assert_eq!(
+            InstructionBuffer::space(&instruction_buffer.instructions),
+            serialized_bytes
+        );
+        assert_eq!(
+            InstructionBuffer::space(&[]) + instruction_buffer.instructions_space(),
+            serialized_bytes
+        );
+    }
+
     #[test]
     fn test_proposal_empty_ix_space() {
         let empty_proposal = Proposal::default();
@@ -330,6 +951,9 @@ mod state_test {
         let extra_bytes = proposal_rental_space - bytes_length;
         assert_eq!(extra_bytes, 16);
         assert_eq!(bytes_length <= proposal_rental_space, true);
+
+        // new fields are taken from the buffers, so existing proposals keep their size
+        assert_eq!(Proposal::space(0, vec![]), 403);
     }
 
     #[test]
@@ -361,6 +985,507 @@ mod state_test {
         assert_eq!(bytes_length <= proposal_rental_space, true);
     }
 
+    #[test]
+    fn test_compute_quorum_votes() {
+        let mut proposal = Proposal {
+            quorum_votes: 1_000,
+            ..Proposal::default()
+        };
+        assert_eq!(proposal.compute_quorum_votes(50_000), Some(1_000));
+
+        proposal.quorum_mode = QuorumMode::LockedSupplyFraction.into();
+        proposal.quorum_bps = 400; // 4%
+        assert_eq!(proposal.compute_quorum_votes(50_000), Some(2_000));
+        assert_eq!(proposal.compute_quorum_votes(0), Some(0));
+        assert_eq!(
+            proposal.compute_quorum_votes(u64::MAX),
+            Some(u64::MAX / 10_000 * 400 + (u64::MAX % 10_000) * 400 / 10_000)
+        );
+
+        // the veto threshold is computed separately from the quorum
+        proposal.optimistic_veto_votes = 300;
+        assert_eq!(proposal.compute_optimistic_veto_votes(50_000), Some(300));
+        proposal.optimistic_veto_mode = QuorumMode::LockedSupplyFraction.into();
+        proposal.optimistic_veto_bps = 1_000; // 10%
+        assert_eq!(proposal.compute_optimistic_veto_votes(50_000), Some(5_000));
+        assert_eq!(proposal.compute_quorum_votes(50_000), Some(2_000));
+    }
+
+    #[test]
+    fn test_yes_no_approval_threshold() {
+        let mut proposal = Proposal {
+            proposal_type: ProposalType::YesNo.into(),
+            max_option: 2,
+            activated_at: 1,
+            voting_ends_at: 10,
+            option_votes: vec![100, 50, 50],
+            ..Proposal::default()
+        };
+        // ties are defeated with a simple majority
+        assert_eq!(proposal.state(20), Some(ProposalState::Defeated));
+
+        proposal.option_votes = vec![100, 40, 60];
+        assert_eq!(proposal.state(20), Some(ProposalState::Succeeded));
+
+        // abstain votes do not count towards the threshold
+        proposal.approval_threshold_bps = 6_000;
+        assert_eq!(proposal.state(20), Some(ProposalState::Defeated));
+        proposal.approval_threshold_bps = 5_900;
+        assert_eq!(proposal.state(20), Some(ProposalState::Succeeded));
+    }
+
+    #[test]
+    fn test_optimistic_states() {
+        let mut proposal = Proposal {
+            proposal_type: ProposalType::Optimistic.into(),
+            max_option: 2,
+            activated_at: 1,
+            voting_ends_at: 10,
+            quorum_votes: 1_000,
+            optimistic_veto_votes: 100,
+            option_votes: vec![0, 0, 0],
+            ..Proposal::default()
+        };
+        assert_eq!(proposal.state(5), Some(ProposalState::Active));
+        // succeeds without any votes
+        assert_eq!(proposal.state(10), Some(ProposalState::Succeeded));
+
+        // against votes must exceed the veto threshold
+        proposal.option_votes = vec![0, 100, 0];
+        assert_eq!(proposal.state(10), Some(ProposalState::Succeeded));
+        proposal.option_votes = vec![0, 101, 500];
+        assert_eq!(proposal.state(10), Some(ProposalState::Defeated));
+
+        proposal.option_votes = vec![0, 50, 0];
+        proposal.queued_at = 11;
+        assert_eq!(proposal.state(12), Some(ProposalState::Queued));
+    }
+
+    #[test]
+    fn test_executed_and_expired_states() {
+        let mut proposal = Proposal {
+            proposal_type: ProposalType::YesNo.into(),
+            max_option: 2,
+            activated_at: 1,
+            voting_ends_at: 10,
+            queued_at: 20,
+            option_votes: vec![0, 0, 1],
+            ..Proposal::default()
+        };
+        assert_eq!(proposal.state(30), Some(ProposalState::Queued));
+
+        proposal.executed_at = 25;
+        assert_eq!(proposal.state(30), Some(ProposalState::Executed));
+
+        proposal.executed_at = 0;
+        proposal.expired_at = 30;
+        assert_eq!(proposal.state(30), Some(ProposalState::Expired));
+
+        proposal.expired_at = 0;
+        proposal.vetoed_at = 22;
+        assert_eq!(proposal.state(30), Some(ProposalState::Vetoed));
+    }
+
+    #[test]
+    fn test_commit_reveal_states() {
+        let mut proposal = Proposal {
+            proposal_type: ProposalType::YesNo.into(),
+            max_option: 2,
+            activated_at: 1,
+            voting_ends_at: 10,
+            reveal_period: 5,
+            option_votes: vec![0, 0, 0],
+            ..Proposal::default()
+        };
+        assert_eq!(proposal.state(5), Some(ProposalState::Active));
+        assert_eq!(proposal.state(10), Some(ProposalState::Revealing));
+        assert_eq!(proposal.state(14), Some(ProposalState::Revealing));
+        // quorum is evaluated after the reveal window
+        assert_eq!(proposal.state(15), Some(ProposalState::Defeated));
+
+        proposal.add_vote_weight(FOR_VOTE_INDEX as u8, 1).unwrap();
+        assert_eq!(proposal.state(15), Some(ProposalState::Succeeded));
+
+        let salt = [7u8; 32];
+        let commitment = VoteCommitment::hash(2, 100, &salt);
+        assert_eq!(VoteCommitment::hash(2, 100, &salt), commitment);
+        assert_ne!(VoteCommitment::hash(1, 100, &salt), commitment);
+        assert_ne!(VoteCommitment::hash(2, 99, &salt), commitment);
+        assert_ne!(VoteCommitment::hash(2, 100, &[8u8; 32]), commitment);
+    }
+
+    #[test]
+    fn test_has_unclaimed_reward() {
+        let mut proposal = Proposal {
+            quorum_votes: 100,
+            option_votes: vec![0, 0, 50],
+            ..Proposal::default()
+        };
+        let mut vote = Vote {
+            voting_power: 50,
+            ..Vote::default()
+        };
+        // no reward
+        assert_eq!(proposal.has_unclaimed_reward(&vote, None), Some(false));
+
+        // quorum not met
+        proposal.voting_reward.reward_per_proposal = 1_000;
+        assert_eq!(proposal.has_unclaimed_reward(&vote, None), Some(false));
+
+        proposal.option_votes = vec![0, 50, 50];
+        assert_eq!(proposal.has_unclaimed_reward(&vote, None), Some(true));
+        assert_eq!(proposal.get_voting_reward(&vote), Some(500));
+        // reward streams are shared pro rata as well
+        assert_eq!(proposal.get_reward_share(300, &vote), Some(150));
+
+        vote.claimed = true;
+        assert_eq!(proposal.has_unclaimed_reward(&vote, None), Some(false));
+
+        // reward streams must be claimed as well
+        let proposal_rewards = ProposalRewards {
+            streams: vec![
+                RewardStream {
+                    amount: 300,
+                    ..RewardStream::default()
+                },
+                RewardStream::default(),
+            ],
+            ..ProposalRewards::default()
+        };
+        assert_eq!(
+            proposal.has_unclaimed_reward(&vote, Some(&proposal_rewards)),
+            Some(true)
+        );
+        vote.claimed_streams = 0b01;
+        assert_eq!(
+            proposal.has_unclaimed_reward(&vote, Some(&proposal_rewards)),
+            Some(false)
+        );
+
+        // sweeping the voting reward does not cut off reward streams, but refunding them does
+        vote.claimed = false;
+        vote.claimed_streams = 0;
+        proposal.reward_swept_at = 1;
+        let mut proposal_rewards = proposal_rewards;
+        assert_eq!(
+            proposal.has_unclaimed_reward(&vote, Some(&proposal_rewards)),
+            Some(true)
+        );
+        proposal_rewards.streams[0].refunded_at = 1;
+        assert_eq!(
+            proposal.has_unclaimed_reward(&vote, Some(&proposal_rewards)),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_unrewarded_reward_share() {
+        let proposal = Proposal {
+            option_votes: vec![0, 50, 150],
+            unrewarded_voting_power: 100,
+            ..Proposal::default()
+        };
+        let vote = Vote {
+            voting_power: 50,
+            side: 1,
+            ..Vote::default()
+        };
+        // unrewarded voting power does not dilute the other votes
+        assert_eq!(proposal.get_reward_share(1_000, &vote), Some(500));
+        assert_eq!(
+            proposal.get_option_incentive_share(1_000, &vote, 1),
+            Some(1_000)
+        );
+
+        let unrewarded = Vote {
+            voting_power: 100,
+            side: 2,
+            unrewarded: true,
+            ..Vote::default()
+        };
+        assert_eq!(proposal.get_reward_share(1_000, &unrewarded), Some(0));
+        assert_eq!(
+            proposal.get_option_incentive_share(1_000, &unrewarded, 2),
+            Some(0)
+        );
+        assert_eq!(
+            proposal.has_unclaimed_reward(&unrewarded, None),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_proposal_state_is_final() {
+        assert!(ProposalState::Defeated.is_final());
+        assert!(ProposalState::Succeeded.is_final());
+        assert!(ProposalState::Executed.is_final());
+        assert!(!ProposalState::Active.is_final());
+        assert!(!ProposalState::Revealing.is_final());
+        // queued proposals depend on their transaction
+        assert!(!ProposalState::Queued.is_final());
+    }
+
+    #[test]
+    fn test_reward_claim_deadline() {
+        let mut proposal = Proposal {
+            voting_ends_at: 100,
+            voting_reward: VotingReward {
+                reward_per_proposal: 1_000,
+                ..VotingReward::default()
+            },
+            total_claimed_reward: 400,
+            ..Proposal::default()
+        };
+        assert_eq!(proposal.reward_claim_deadline(), Some(None));
+        assert_eq!(proposal.unclaimed_reward(), Some(600));
+
+        proposal.reward_claim_period = 50;
+        assert_eq!(proposal.reward_claim_deadline(), Some(Some(150)));
+
+        // the deadline starts after the reveal window
+        proposal.reveal_period = 10;
+        assert_eq!(proposal.reward_claim_deadline(), Some(Some(160)));
+    }
+
+    #[test]
+    fn test_late_quorum_voting_ends_at() {
+        let mut proposal = Proposal {
+            activated_at: 1,
+            voting_ends_at: 100,
+            ..Proposal::default()
+        };
+        assert_eq!(proposal.late_quorum_voting_ends_at(95), None);
+
+        proposal.late_quorum_extension = 10;
+        assert_eq!(proposal.late_quorum_voting_ends_at(50), None);
+        assert_eq!(proposal.late_quorum_voting_ends_at(90), None);
+        assert_eq!(proposal.late_quorum_voting_ends_at(95), Some(105));
+    }
+
+    #[test]
+    fn test_extend_voting_on_late_quorum_once() {
+        let mut proposal = Proposal {
+            activated_at: 1,
+            voting_ends_at: 100,
+            late_quorum_extension: 10,
+            quorum_votes: 50,
+            option_votes: vec![0, 0, 0],
+            ..Proposal::default()
+        };
+
+        // quorum is reached late: voting is extended
+        proposal.option_votes[FOR_VOTE_INDEX] = 50;
+        assert_eq!(
+            proposal.extend_voting_on_late_quorum(false, 95),
+            Some(Some(100))
+        );
+        assert_eq!(proposal.voting_ends_at, 105);
+        assert_eq!(proposal.late_quorum_extended_at, 95);
+
+        // a vote that keeps the quorum does not extend voting
+        assert_eq!(proposal.extend_voting_on_late_quorum(true, 100), Some(None));
+
+        // withdrawing and recasting votes flips quorum again, but voting is extended only once
+        for now in [100, 102, 104] {
+            proposal.option_votes[FOR_VOTE_INDEX] = 0;
+            assert_eq!(proposal.extend_voting_on_late_quorum(true, now), Some(None));
+            proposal.option_votes[FOR_VOTE_INDEX] = 50;
+            assert_eq!(
+                proposal.extend_voting_on_late_quorum(false, now),
+                Some(None)
+            );
+            assert_eq!(proposal.voting_ends_at, 105);
+        }
+    }
+
+    #[test]
+    fn test_winning_option() {
+        let mut proposal = Proposal {
+            proposal_type: ProposalType::Option.into(),
+            max_option: 3,
+            activated_at: 1,
+            voting_ends_at: 10,
+            option_votes: vec![0, 0, 0, 0],
+            has_option_instructions: true,
+            ..Proposal::default()
+        };
+        assert_eq!(proposal.winning_option(), None);
+        assert_eq!(proposal.state(20), Some(ProposalState::Defeated));
+
+        // abstain votes never win
+        proposal.option_votes = vec![100, 10, 30, 20];
+        assert_eq!(proposal.winning_option(), Some(2));
+        assert_eq!(proposal.state(20), Some(ProposalState::Succeeded));
+
+        // a tie for the most votes has no winner
+        proposal.option_votes = vec![0, 30, 30, 20];
+        assert_eq!(proposal.winning_option(), None);
+        assert_eq!(proposal.state(20), Some(ProposalState::Defeated));
+
+        proposal.option_votes = vec![0, 20, 20, 30];
+        assert_eq!(proposal.winning_option(), Some(3));
+
+        // every option needs instructions to be activated, so the winner can always be queued
+        proposal.option_instructions_count = 2;
+        assert!(!proposal.has_all_option_instructions());
+        proposal.option_instructions_count = 3;
+        assert!(proposal.has_all_option_instructions());
+
+        // without option instructions, the shared instructions are queued regardless of the winner
+        proposal.has_option_instructions = false;
+        proposal.option_votes = vec![0, 30, 30, 20];
+        assert_eq!(proposal.state(20), Some(ProposalState::Succeeded));
+    }
+
+    #[test]
+    fn test_approval_vote_weight() {
+        let mut proposal = Proposal {
+            proposal_type: ProposalType::Approval.into(),
+            max_option: 3,
+            max_selections: 2,
+            option_votes: vec![0, 0, 0, 0],
+            ..Proposal::default()
+        };
+
+        // options 1 and 3
+        proposal.add_approval_vote_weight(0b1010, 100).unwrap();
+        assert_eq!(proposal.option_votes, vec![0, 100, 0, 100]);
+        assert_eq!(proposal.total_votes(), Some(100));
+
+        // abstain
+        proposal.add_approval_vote_weight(0b0001, 50).unwrap();
+        assert_eq!(proposal.option_votes, vec![50, 100, 0, 100]);
+        assert_eq!(proposal.total_votes(), Some(150));
+
+        // too many selections, abstain with options, options out of range
+        assert!(proposal.add_approval_vote_weight(0b1110, 10).is_err());
+        assert!(proposal.add_approval_vote_weight(0b0011, 10).is_err());
+        assert!(proposal.add_approval_vote_weight(0b10000, 10).is_err());
+        assert!(proposal.add_approval_vote_weight(0, 10).is_err());
+
+        // changing the vote
+        proposal.subtract_approval_vote_weight(0b1010, 100).unwrap();
+        proposal.add_approval_vote_weight(0b0100, 100).unwrap();
+        assert_eq!(proposal.option_votes, vec![50, 0, 100, 0]);
+        assert_eq!(proposal.total_votes(), Some(150));
+    }
+
+    #[test]
+    fn test_split_vote_weight() {
+        let mut proposal = Proposal {
+            proposal_type: ProposalType::Option.into(),
+            max_option: 3,
+            option_votes: vec![0, 0, 0, 0],
+            ..Proposal::default()
+        };
+
+        let mut split = [0u16; 11];
+        split[1] = 3_333;
+        split[2] = 3_333;
+        split[3] = 3_334;
+        proposal.add_split_vote_weight(&split, 100).unwrap();
+        // the rounding remainder goes to the last option
+        assert_eq!(proposal.option_votes, vec![0, 33, 33, 34]);
+        assert_eq!(proposal.total_votes(), Some(100));
+
+        proposal.add_vote_weight(1, 7).unwrap();
+        proposal.subtract_split_vote_weight(&split, 100).unwrap();
+        assert_eq!(proposal.option_votes, vec![0, 7, 0, 0]);
+
+        // shares must add up to 100%
+        split[3] = 3_000;
+        assert!(proposal.add_split_vote_weight(&split, 100).is_err());
+
+        // options out of range
+        let mut split = [0u16; 11];
+        split[4] = 10_000;
+        assert!(proposal.add_split_vote_weight(&split, 100).is_err());
+    }
+
+    #[test]
+    fn test_unclaimed_refund() {
+        let mut option_incentive = OptionIncentive {
+            total_deposited: 1_000,
+            total_claimed: 665,
+            ..OptionIncentive::default()
+        };
+        // deposits of 300 and 700 share the 335 left
+        assert_eq!(option_incentive.unclaimed_refund(300), Some(100));
+        option_incentive.refunded_deposits = 300;
+        option_incentive.total_refunded = 100;
+        // the last deposit gets the rounding remainder
+        assert_eq!(option_incentive.unclaimed_refund(700), Some(235));
+    }
+
+    #[test]
+    fn test_option_incentive_share() {
+        let mut proposal = Proposal {
+            proposal_type: ProposalType::Option.into(),
+            max_option: 3,
+            option_votes: vec![0, 0, 0, 0],
+            ..Proposal::default()
+        };
+        let single = Vote {
+            side: 1,
+            voting_power: 100,
+            ..Vote::default()
+        };
+        let mut split_bps = [0u16; 11];
+        split_bps[1] = 5_000;
+        split_bps[2] = 5_000;
+        let split = Vote {
+            voting_power: 200,
+            split_bps,
+            ..Vote::default()
+        };
+        proposal.add_vote_weight(1, 100).unwrap();
+        proposal.add_split_vote_weight(&split_bps, 200).unwrap();
+        assert_eq!(proposal.option_votes, vec![0, 200, 100, 0]);
+
+        assert_eq!(proposal.option_vote_weight(&single, 1), Some(100));
+        assert_eq!(proposal.option_vote_weight(&single, 2), Some(0));
+        assert_eq!(proposal.option_vote_weight(&split, 1), Some(100));
+
+        // voters of option 1 share pro rata
+        assert_eq!(
+            proposal.get_option_incentive_share(1_000, &single, 1),
+            Some(500)
+        );
+        assert_eq!(
+            proposal.get_option_incentive_share(1_000, &split, 1),
+            Some(500)
+        );
+        // only voters of the option earn its incentive
+        assert_eq!(
+            proposal.get_option_incentive_share(1_000, &single, 2),
+            Some(0)
+        );
+        assert_eq!(
+            proposal.get_option_incentive_share(1_000, &split, 2),
+            Some(1_000)
+        );
+        // no votes for the option
+        assert_eq!(
+            proposal.get_option_incentive_share(1_000, &single, 3),
+            Some(0)
+        );
+        assert_eq!(proposal.get_option_incentive_share(1_000, &single, 4), None);
+    }
+
+    #[test]
+    fn test_proposal_meta_space() {
+        let proposal_meta = ProposalMeta {
+            proposal: Pubkey::default(),
+            title: "Fund the developer grants".to_string(),
+            description_link: "https://example.com/proposals/1".to_string(),
+        };
+        let serialized_bytes = proposal_meta.try_to_vec().unwrap().len();
+        assert_eq!(
+            ProposalMeta::space(&proposal_meta.title, &proposal_meta.description_link),
+            serialized_bytes
+        );
+    }
+
     #[test]
     fn test_option_proposal_meta_data() {
         let option_descriptions: Vec<String> = vec![
@@ -382,4 +1507,53 @@ mod state_test {
         println!("meta data size {}", proposal_ix_rent_space);
         assert_eq!(proposal_ix_rent_space, 396);
     }
+
+    #[test]
+    fn test_governor_pause() {
+        let mut governor = Governor::default();
+        assert!(!governor.is_paused(PAUSE_SET_VOTE, 0));
+
+        governor.paused_instructions = PAUSE_SET_VOTE | PAUSE_QUEUE_PROPOSAL;
+        governor.paused_until = 100;
+        assert!(governor.is_paused(PAUSE_SET_VOTE, 50));
+        assert!(governor.is_paused(PAUSE_QUEUE_PROPOSAL, 99));
+        assert!(!governor.is_paused(PAUSE_CREATE_PROPOSAL, 50));
+        assert!(!governor.is_paused(PAUSE_CLAIM_REWARD, 50));
+
+        // the pause expires on its own.
+        assert!(!governor.is_paused(PAUSE_SET_VOTE, 100));
+    }
+
+    #[test]
+    fn test_voting_pause_extension() {
+        let mut governor = Governor {
+            paused_instructions: PAUSE_SET_VOTE,
+            paused_at: 50,
+            paused_until: 100,
+            voting_paused_duration: 30,
+            ..Governor::default()
+        };
+        assert_eq!(governor.voting_paused_duration_at(40), Some(30));
+        assert_eq!(governor.voting_paused_duration_at(60), Some(40));
+        // the pause expired on its own.
+        assert_eq!(governor.voting_paused_duration_at(200), Some(80));
+
+        let mut proposal = Proposal {
+            activated_at: 10,
+            voting_ends_at: 70,
+            voting_paused_duration: 30,
+            ..Proposal::default()
+        };
+        assert_eq!(proposal.voting_pause_extension(&governor, 60), Some(10));
+        assert_eq!(proposal.voting_pause_extension(&governor, 200), Some(50));
+
+        // pauses that started after voting ended do not extend it.
+        proposal.voting_ends_at = 50;
+        assert_eq!(proposal.voting_pause_extension(&governor, 200), Some(0));
+
+        // neither do pauses of other instructions.
+        proposal.voting_ends_at = 70;
+        governor.paused_instructions = PAUSE_QUEUE_PROPOSAL;
+        assert_eq!(proposal.voting_pause_extension(&governor, 200), Some(0));
+    }
 }
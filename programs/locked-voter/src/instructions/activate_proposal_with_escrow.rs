@@ -0,0 +1,72 @@
+use crate::*;
+
+/// Accounts for [voter::activate_proposal_with_escrow].
+#[derive(Accounts)]
+pub struct ActivateProposalWithEscrow<'info> {
+    /// The [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// The [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// The [Proposal].
+    #[account(mut)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The user's [Escrow].
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// The [Escrow::owner] or the [Escrow::vote_delegate].
+    pub authority: Signer<'info>,
+    /// The [govern] program.
+    pub govern_program: Program<'info, govern::program::Govern>,
+}
+
+impl<'info> ActivateProposalWithEscrow<'info> {
+    /// Activates the proposal.
+    pub fn activate_proposal(&mut self) -> Result<()> {
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+
+        govern::cpi::activate_proposal(
+            CpiContext::new(
+                self.govern_program.to_account_info(),
+                self.to_activate_proposal_accounts(),
+            )
+            .with_signer(seeds),
+            unwrap_int!(self.locker.locked_voting_power()),
+        )?;
+
+        Ok(())
+    }
+
+    /// Conversion.
+    fn to_activate_proposal_accounts(&self) -> govern::cpi::accounts::ActivateProposal<'info> {
+        govern::cpi::accounts::ActivateProposal {
+            governor: self.governor.to_account_info(),
+            proposal: self.proposal.to_account_info(),
+            locker: self.locker.to_account_info(),
+        }
+    }
+
+    /// The current voting power of the escrow.
+    fn current_voting_power(&self) -> Result<u64> {
+        self.escrow.voting_power(&self.locker)
+    }
+}
+
+impl<'info> Validate<'info> for ActivateProposalWithEscrow<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.locker, self.governor.locker);
+        assert_keys_eq!(self.governor, self.locker.governor);
+        assert_keys_eq!(self.proposal.governor, self.governor);
+        assert_keys_eq!(self.escrow.locker, self.locker);
+        invariant!(
+            self.authority.key() == self.escrow.owner
+                || self.authority.key() == self.escrow.vote_delegate,
+            InvalidProposalActivator
+        );
+
+        invariant!(
+            self.current_voting_power()? >= self.locker.params.proposal_activation_min_votes,
+            InsufficientActivationVotingPower
+        );
+
+        Ok(())
+    }
+}
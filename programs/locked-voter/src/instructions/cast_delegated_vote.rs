@@ -0,0 +1,79 @@
+use crate::*;
+use govern::ProposalState;
+
+/// Accounts for [voter::cast_delegated_vote].
+#[derive(Accounts)]
+pub struct CastDelegatedVote<'info> {
+    /// The [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// The [DelegateRecord] that is voting.
+    #[account(mut, has_one = locker, has_one = delegate)]
+    pub delegate_record: Box<Account<'info, DelegateRecord>>,
+    /// Vote delegate of the [DelegateRecord].
+    pub delegate: Signer<'info>,
+
+    /// The [Proposal] being voted on.
+    #[account(mut)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [Vote] of the [DelegateRecord].
+    #[account(mut)]
+    pub vote: Box<Account<'info, Vote>>,
+
+    /// The [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// The [govern] program.
+    pub govern_program: Program<'info, govern::program::Govern>,
+}
+
+impl<'info> CastDelegatedVote<'info> {
+    /// Votes with the aggregated voting power of every [Escrow] in the [DelegateRecord].
+    /// The vote earns no rewards, as they cannot be paid to the owners of the escrows.
+    pub fn cast_delegated_vote(&mut self, side: u8) -> Result<()> {
+        let voting_power = unwrap_int!(self.delegate_record.voting_power(&self.locker));
+
+        // zero votes should short circuit.
+        if voting_power == 0 {
+            return Ok(());
+        }
+
+        // escrows cannot leave the record while the vote may still be counted.
+        unwrap_int!(self.delegate_record.record_vote(
+            &self.proposal,
+            &self.governor,
+            Clock::get()?.unix_timestamp
+        ));
+
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        govern::cpi::set_vote(self.set_vote_ctx().with_signer(seeds), side, voting_power)?;
+        if !self.vote.unrewarded {
+            govern::cpi::set_vote_unrewarded(self.set_vote_ctx().with_signer(seeds))?;
+        }
+        Ok(())
+    }
+
+    fn set_vote_ctx(&self) -> CpiContext<'_, '_, '_, 'info, govern::cpi::accounts::SetVote<'info>> {
+        CpiContext::new(
+            self.govern_program.to_account_info(),
+            govern::cpi::accounts::SetVote {
+                governor: self.governor.to_account_info(),
+                proposal: self.proposal.to_account_info(),
+                vote: self.vote.to_account_info(),
+                locker: self.locker.to_account_info(),
+            },
+        )
+    }
+}
+
+impl<'info> Validate<'info> for CastDelegatedVote<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.locker.governor, self.governor);
+        assert_keys_eq!(self.proposal.governor, self.governor);
+        assert_keys_eq!(self.vote.proposal, self.proposal);
+        assert_keys_eq!(self.vote.voter, self.delegate_record);
+        invariant!(
+            self.proposal.get_state()? == ProposalState::Active,
+            "proposal must be active"
+        );
+        Ok(())
+    }
+}
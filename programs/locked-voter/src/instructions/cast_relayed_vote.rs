@@ -0,0 +1,162 @@
+use crate::relay::{parse_ed25519_instruction, relayed_vote_message};
+use crate::*;
+use anchor_lang::solana_program::{ed25519_program, sysvar::instructions as ix_sysvar};
+use govern::ProposalState;
+
+/// Accounts for [voter::cast_relayed_vote].
+#[derive(Accounts)]
+pub struct CastRelayedVote<'info> {
+    /// The [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// The [Escrow] that is voting.
+    #[account(mut)]
+    pub escrow: Box<Account<'info, Escrow>>,
+
+    /// The [Proposal] being voted on.
+    #[account(mut)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [Vote]. The relayer pays for it with [govern::new_vote].
+    #[account(mut)]
+    pub vote: Box<Account<'info, Vote>>,
+
+    /// The [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// The [govern] program.
+    pub govern_program: Program<'info, govern::program::Govern>,
+
+    /// Submitter of the relayed vote.
+    pub relayer: Signer<'info>,
+    /// CHECK: Instructions sysvar, used to read the ed25519 verification instruction.
+    #[account(address = ix_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+impl<'info> CastRelayedVote<'info> {
+    /// Casts a vote signed off-chain by the [Escrow::owner] or [Escrow::vote_delegate].
+    /// The instruction right before this one must be the ed25519 program verifying the signature
+    /// of [relayed_vote_message] with the escrow's [Escrow::relay_nonce].
+    pub fn cast_relayed_vote(&mut self, side: u8, nonce: u64) -> Result<()> {
+        let voter = self.verified_voter(side, nonce)?;
+        let is_owner_override =
+            voter == self.escrow.owner && self.escrow.vote_delegate != self.escrow.owner;
+        if !is_owner_override {
+            assert_keys_eq!(self.escrow.vote_delegate, voter);
+            invariant!(
+                !self.vote.owner_override,
+                "the owner has overridden the delegate's vote"
+            );
+        }
+
+        // the message cannot be relayed again.
+        self.escrow.relay_nonce = unwrap_int!(self.escrow.relay_nonce.checked_add(1));
+
+        let voting_power = unwrap_int!(self
+            .escrow
+            .voting_power_at_time(&self.locker, self.proposal.voting_ends_at));
+
+        // zero votes should short circuit.
+        if voting_power == 0 {
+            return Ok(());
+        }
+
+        // the escrow cannot join a delegate record while the vote may still be counted.
+        unwrap_int!(self.escrow.record_vote(
+            &self.proposal,
+            &self.governor,
+            Clock::get()?.unix_timestamp
+        ));
+
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        govern::cpi::set_vote(self.set_vote_ctx().with_signer(seeds), side, voting_power)?;
+        if is_owner_override && !self.vote.owner_override {
+            govern::cpi::set_vote_override(self.set_vote_ctx().with_signer(seeds))?;
+        }
+
+        emit!(CastRelayedVoteEvent {
+            escrow: self.escrow.key(),
+            proposal: self.proposal.key(),
+            voter,
+            relayer: self.relayer.key(),
+            side,
+            nonce,
+        });
+
+        Ok(())
+    }
+
+    /// Reads the ed25519 verification instruction preceding this one,
+    /// and returns the key that signed the relayed vote message.
+    fn verified_voter(&self, side: u8, nonce: u64) -> Result<Pubkey> {
+        invariant!(nonce == self.escrow.relay_nonce, InvalidRelayNonce);
+
+        let instructions_sysvar = self.instructions_sysvar.to_account_info();
+        let current_index = ix_sysvar::load_current_index_checked(&instructions_sysvar)?;
+        let ed25519_index = unwrap_int!(current_index.checked_sub(1));
+        let ed25519_ix =
+            ix_sysvar::load_instruction_at_checked(ed25519_index.into(), &instructions_sysvar)?;
+        assert_keys_eq!(
+            ed25519_ix.program_id,
+            ed25519_program::ID,
+            InvalidRelayedVoteSignature
+        );
+
+        let (voter, message) = unwrap_opt!(
+            parse_ed25519_instruction(&ed25519_ix.data),
+            InvalidRelayedVoteSignature
+        );
+        invariant!(
+            message == relayed_vote_message(self.escrow.key(), self.proposal.key(), side, nonce),
+            InvalidRelayedVoteSignature
+        );
+        Ok(voter)
+    }
+
+    fn set_vote_ctx(&self) -> CpiContext<'_, '_, '_, 'info, govern::cpi::accounts::SetVote<'info>> {
+        CpiContext::new(
+            self.govern_program.to_account_info(),
+            govern::cpi::accounts::SetVote {
+                governor: self.governor.to_account_info(),
+                proposal: self.proposal.to_account_info(),
+                vote: self.vote.to_account_info(),
+                locker: self.locker.to_account_info(),
+            },
+        )
+    }
+}
+
+impl<'info> Validate<'info> for CastRelayedVote<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.escrow.locker, self.locker);
+        // the voting power of the escrow is cast by its delegate record or delegates.
+        invariant!(!self.escrow.is_aggregated(), EscrowIsAggregated);
+        invariant!(!self.escrow.has_delegation_table, EscrowHasDelegationTable);
+        assert_keys_eq!(self.locker.governor, self.governor);
+        assert_keys_eq!(self.proposal.governor, self.governor);
+        assert_keys_eq!(self.vote.proposal, self.proposal);
+        assert_keys_eq!(self.vote.voter, self.escrow.owner);
+        invariant!(
+            self.proposal.get_state()? == ProposalState::Active,
+            "proposal must be active"
+        );
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [voter::cast_relayed_vote].
+pub struct CastRelayedVoteEvent {
+    /// The [Escrow] that voted.
+    pub escrow: Pubkey,
+    /// The [Proposal] voted on.
+    #[index]
+    pub proposal: Pubkey,
+    /// The owner or vote delegate that signed the vote.
+    #[index]
+    pub voter: Pubkey,
+    /// The relayer that submitted the vote.
+    pub relayer: Pubkey,
+    /// The side voted for.
+    pub side: u8,
+    /// The nonce of the relayed vote message.
+    pub nonce: u64,
+}
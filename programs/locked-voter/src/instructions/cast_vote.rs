@@ -7,8 +7,10 @@ pub struct CastVote<'info> {
     /// The [Locker].
     pub locker: Box<Account<'info, Locker>>,
     /// The [Escrow] that is voting.
+    #[account(mut)]
     pub escrow: Box<Account<'info, Escrow>>,
-    /// Vote delegate of the [Escrow].
+    /// Vote delegate of the [Escrow], or its owner.
+    /// A vote cast by the owner overrides the vote of their delegate on the proposal.
     pub vote_delegate: Signer<'info>,
 
     /// The [Proposal] being voted on.
@@ -22,6 +24,9 @@ pub struct CastVote<'info> {
     pub governor: Box<Account<'info, Governor>>,
     /// The [govern] program.
     pub govern_program: Program<'info, govern::program::Govern>,
+
+    /// The [DelegationTable] of the [Escrow]. Required if its voting power is split across delegates.
+    pub delegation_table: Option<Box<Account<'info, DelegationTable>>>,
 }
 
 impl<'info> CastVote<'info> {
@@ -33,6 +38,13 @@ impl<'info> CastVote<'info> {
             return Ok(());
         }
 
+        // the escrow cannot join a delegate record while the vote may still be counted.
+        unwrap_int!(self.escrow.record_vote(
+            &self.proposal,
+            &self.governor,
+            Clock::get()?.unix_timestamp
+        ));
+
         let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
         let cpi_ctx = CpiContext::new(
             self.govern_program.to_account_info(),
@@ -46,6 +58,131 @@ impl<'info> CastVote<'info> {
         .with_signer(seeds);
 
         govern::cpi::set_vote(cpi_ctx, side, voting_power)?;
+        self.override_delegate_vote()?;
+        self.mark_share_vote_unrewarded()
+    }
+
+    pub fn cast_split_vote(&mut self, split_bps: Vec<u16>) -> Result<()> {
+        let voting_power = self.future_voting_power()?;
+
+        // zero votes should short circuit.
+        if voting_power == 0 {
+            return Ok(());
+        }
+
+        // the escrow cannot join a delegate record while the vote may still be counted.
+        unwrap_int!(self.escrow.record_vote(
+            &self.proposal,
+            &self.governor,
+            Clock::get()?.unix_timestamp
+        ));
+
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        let cpi_ctx = CpiContext::new(
+            self.govern_program.to_account_info(),
+            govern::cpi::accounts::SetVote {
+                governor: self.governor.to_account_info(),
+                proposal: self.proposal.to_account_info(),
+                vote: self.vote.to_account_info(),
+                locker: self.locker.to_account_info(),
+            },
+        )
+        .with_signer(seeds);
+
+        govern::cpi::set_split_vote(cpi_ctx, split_bps, voting_power)?;
+        self.override_delegate_vote()?;
+        self.mark_share_vote_unrewarded()
+    }
+
+    pub fn cast_approval_vote(&mut self, selections: u16) -> Result<()> {
+        let voting_power = self.future_voting_power()?;
+
+        // zero votes should short circuit.
+        if voting_power == 0 {
+            return Ok(());
+        }
+
+        // the escrow cannot join a delegate record while the vote may still be counted.
+        unwrap_int!(self.escrow.record_vote(
+            &self.proposal,
+            &self.governor,
+            Clock::get()?.unix_timestamp
+        ));
+
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        let cpi_ctx = CpiContext::new(
+            self.govern_program.to_account_info(),
+            govern::cpi::accounts::SetVote {
+                governor: self.governor.to_account_info(),
+                proposal: self.proposal.to_account_info(),
+                vote: self.vote.to_account_info(),
+                locker: self.locker.to_account_info(),
+            },
+        )
+        .with_signer(seeds);
+
+        govern::cpi::set_approval_vote(cpi_ctx, selections, voting_power)?;
+        self.override_delegate_vote()?;
+        self.mark_share_vote_unrewarded()
+    }
+
+    /// Whether the owner of the escrow is voting instead of their delegate.
+    fn is_owner_override(&self) -> bool {
+        !self.escrow.has_delegation_table
+            && self.vote_delegate.key() == self.escrow.owner
+            && self.escrow.vote_delegate != self.escrow.owner
+    }
+
+    /// Marks the vote as overridden if the owner cast it, so their delegate cannot overwrite it.
+    fn override_delegate_vote(&self) -> Result<()> {
+        if !self.is_owner_override() || self.vote.owner_override {
+            return Ok(());
+        }
+
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        let cpi_ctx = CpiContext::new(
+            self.govern_program.to_account_info(),
+            govern::cpi::accounts::SetVote {
+                governor: self.governor.to_account_info(),
+                proposal: self.proposal.to_account_info(),
+                vote: self.vote.to_account_info(),
+                locker: self.locker.to_account_info(),
+            },
+        )
+        .with_signer(seeds);
+
+        govern::cpi::set_vote_override(cpi_ctx)
+    }
+
+    /// Marks the vote of a delegate's share of the escrow as unrewarded,
+    /// since the rewards of its program address voter cannot be claimed.
+    /// See [DelegationTable].
+    fn mark_share_vote_unrewarded(&self) -> Result<()> {
+        if !self.escrow.has_delegation_table || self.vote.unrewarded {
+            return Ok(());
+        }
+
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        let cpi_ctx = CpiContext::new(
+            self.govern_program.to_account_info(),
+            govern::cpi::accounts::SetVote {
+                governor: self.governor.to_account_info(),
+                proposal: self.proposal.to_account_info(),
+                vote: self.vote.to_account_info(),
+                locker: self.locker.to_account_info(),
+            },
+        )
+        .with_signer(seeds);
+
+        govern::cpi::set_vote_unrewarded(cpi_ctx)?;
+
+        emit!(ShareVoteUnrewardedEvent {
+            proposal: self.proposal.key(),
+            escrow: self.escrow.key(),
+            delegate: self.vote_delegate.key(),
+            share_voter: self.vote.voter,
+        });
+
         Ok(())
     }
 
@@ -54,22 +191,56 @@ impl<'info> CastVote<'info> {
     /// so at the end of proposal user can still earn max voting power, while they can just wait for (max_stake_duration - voting_period) to withdraw the full token
     /// in the fact that they should wait for max_stake_duration to withdraw the full token
     /// To mitigate the issue, when deploying the DAO, admin should pick max_stake_duration >> voting_period
+    /// If the voting power is split across delegates, this is only the share of the voting delegate.
     fn future_voting_power(&self) -> Result<u64> {
-        Ok(unwrap_int!(self.escrow.voting_power_at_time(
-            &self.locker,
-            self.proposal.voting_ends_at
-        )))
+        let voting_power = unwrap_int!(self
+            .escrow
+            .voting_power_at_time(&self.locker, self.proposal.voting_ends_at));
+        if !self.escrow.has_delegation_table {
+            return Ok(voting_power);
+        }
+
+        let delegation_table = unwrap_opt!(self.delegation_table.as_ref());
+        let bps = unwrap_opt!(delegation_table.delegate_bps(self.vote_delegate.key()));
+        Ok(unwrap_int!((voting_power as u128)
+            .checked_mul(bps.into())
+            .and_then(|power| power.checked_div(govern::MAX_BPS.into()))
+            .and_then(|power| u64::try_from(power).ok())))
     }
 }
 
 impl<'info> Validate<'info> for CastVote<'info> {
     fn validate(&self) -> Result<()> {
         assert_keys_eq!(self.escrow.locker, self.locker);
-        assert_keys_eq!(self.escrow.vote_delegate, self.vote_delegate);
+        // the voting power of the escrow is cast by its delegate record.
+        invariant!(!self.escrow.is_aggregated(), EscrowIsAggregated);
+        if self.escrow.has_delegation_table {
+            // each delegate votes with their share on a separate vote.
+            let delegation_table = unwrap_opt!(self.delegation_table.as_ref());
+            assert_keys_eq!(delegation_table.escrow, self.escrow);
+            invariant!(
+                delegation_table
+                    .delegate_bps(self.vote_delegate.key())
+                    .is_some(),
+                "signer must be a delegate of the escrow"
+            );
+            assert_keys_eq!(
+                self.vote.voter,
+                DelegationTable::share_voter(self.escrow.key(), self.vote_delegate.key())
+            );
+        } else {
+            if !self.is_owner_override() {
+                assert_keys_eq!(self.escrow.vote_delegate, self.vote_delegate);
+                invariant!(
+                    !self.vote.owner_override,
+                    "the owner has overridden the delegate's vote"
+                );
+            }
+            assert_keys_eq!(self.vote.voter, self.escrow.owner);
+        }
         assert_keys_eq!(self.locker.governor, self.governor);
         assert_keys_eq!(self.proposal.governor, self.governor);
         assert_keys_eq!(self.vote.proposal, self.proposal);
-        assert_keys_eq!(self.vote.voter, self.escrow.owner);
         invariant!(
             self.proposal.get_state()? == ProposalState::Active,
             "proposal must be active"
@@ -77,3 +248,18 @@ impl<'info> Validate<'info> for CastVote<'info> {
         Ok(())
     }
 }
+
+#[event]
+/// Event called in [voter::cast_vote] when a delegate first votes with their share of a [DelegationTable].
+/// The vote earns no rewards.
+pub struct ShareVoteUnrewardedEvent {
+    /// The [Proposal] being voted on.
+    #[index]
+    pub proposal: Pubkey,
+    /// The [Escrow] whose voting power is split.
+    pub escrow: Pubkey,
+    /// The delegate voting with their share.
+    pub delegate: Pubkey,
+    /// The program address voter of the share.
+    pub share_voter: Pubkey,
+}
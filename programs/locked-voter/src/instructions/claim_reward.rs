@@ -0,0 +1,85 @@
+use crate::*;
+use govern::ProposalRewards;
+
+/// Accounts for [voter::claim_reward].
+#[derive(Accounts)]
+pub struct ClaimReward<'info> {
+    /// The [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// The [Escrow] that voted.
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// Vote delegate of the [Escrow].
+    pub vote_delegate: Signer<'info>,
+
+    /// The [Governor].
+    #[account(mut)]
+    pub governor: Box<Account<'info, Governor>>,
+    /// The reward vault of the [Governor].
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+    /// The [Proposal].
+    #[account(mut)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [Vote].
+    #[account(mut)]
+    pub vote: Box<Account<'info, Vote>>,
+    /// The owner of the [Escrow].
+    /// CHECK: checked against the escrow owner
+    pub owner: UncheckedAccount<'info>,
+    /// The associated token account of the owner.
+    #[account(mut)]
+    pub owner_token_account: Account<'info, TokenAccount>,
+    /// The [ProposalRewards] of the [Proposal], if any.
+    #[account(mut)]
+    pub proposal_rewards: Option<Box<Account<'info, ProposalRewards>>>,
+
+    /// The event authority of the [govern] program.
+    /// CHECK: checked by the [govern] program
+    pub govern_event_authority: UncheckedAccount<'info>,
+    /// The [govern] program.
+    pub govern_program: Program<'info, govern::program::Govern>,
+    /// Token program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ClaimReward<'info> {
+    /// Claims the rewards of the escrow owner. The rewards are paid to the owner's
+    /// associated token accounts, the reward stream accounts are passed as remaining accounts.
+    pub fn claim_reward(&self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        let cpi_ctx = CpiContext::new(
+            self.govern_program.to_account_info(),
+            govern::cpi::accounts::ClaimReward {
+                governor: self.governor.to_account_info(),
+                reward_vault: self.reward_vault.to_account_info(),
+                proposal: self.proposal.to_account_info(),
+                vote: self.vote.to_account_info(),
+                voter: self.owner.to_account_info(),
+                authority: Some(self.locker.to_account_info()),
+                voter_token_account: self.owner_token_account.to_account_info(),
+                proposal_rewards: self
+                    .proposal_rewards
+                    .as_ref()
+                    .map(|proposal_rewards| proposal_rewards.to_account_info()),
+                token_program: self.token_program.to_account_info(),
+                event_authority: self.govern_event_authority.to_account_info(),
+                program: self.govern_program.to_account_info(),
+            },
+        )
+        .with_signer(seeds)
+        .with_remaining_accounts(remaining_accounts.to_vec());
+
+        govern::cpi::claim_reward(cpi_ctx)
+    }
+}
+
+impl<'info> Validate<'info> for ClaimReward<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.escrow.locker, self.locker);
+        assert_keys_eq!(self.escrow.vote_delegate, self.vote_delegate);
+        assert_keys_eq!(self.escrow.owner, self.owner);
+        assert_keys_eq!(self.locker.governor, self.governor);
+        assert_keys_eq!(self.vote.voter, self.escrow.owner);
+        Ok(())
+    }
+}
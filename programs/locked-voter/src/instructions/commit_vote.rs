@@ -0,0 +1,86 @@
+use crate::*;
+use govern::{ProposalState, VoteCommitment};
+
+/// Accounts for [voter::commit_vote].
+#[derive(Accounts)]
+pub struct CommitVote<'info> {
+    /// The [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// The [Escrow] that is voting.
+    #[account(mut)]
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// Vote delegate of the [Escrow].
+    pub vote_delegate: Signer<'info>,
+
+    /// The [Proposal] being voted on.
+    pub proposal: Box<Account<'info, Proposal>>,
+    /// The [Vote].
+    pub vote: Box<Account<'info, Vote>>,
+    /// The [VoteCommitment].
+    #[account(mut)]
+    pub vote_commitment: Box<Account<'info, VoteCommitment>>,
+
+    /// The [Governor].
+    pub governor: Box<Account<'info, Governor>>,
+    /// The [govern] program.
+    pub govern_program: Program<'info, govern::program::Govern>,
+}
+
+impl<'info> CommitVote<'info> {
+    /// Commits the hash of the side, voting power and salt of a vote.
+    /// The voting power committed is the voting power of the escrow when the proposal's voting ends,
+    /// and the hash must be computed with [VoteCommitment::hash] from that voting power.
+    pub fn commit_vote(&mut self, commitment: [u8; 32]) -> Result<()> {
+        let voting_power = unwrap_int!(self
+            .escrow
+            .voting_power_at_time(&self.locker, self.proposal.voting_ends_at));
+
+        // zero votes should short circuit.
+        if voting_power == 0 {
+            return Ok(());
+        }
+
+        // the escrow cannot join a delegate record while the vote may still be counted.
+        unwrap_int!(self.escrow.record_vote(
+            &self.proposal,
+            &self.governor,
+            Clock::get()?.unix_timestamp
+        ));
+
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        let cpi_ctx = CpiContext::new(
+            self.govern_program.to_account_info(),
+            govern::cpi::accounts::CommitVote {
+                governor: self.governor.to_account_info(),
+                proposal: self.proposal.to_account_info(),
+                vote: self.vote.to_account_info(),
+                vote_commitment: self.vote_commitment.to_account_info(),
+                locker: self.locker.to_account_info(),
+            },
+        )
+        .with_signer(seeds);
+
+        govern::cpi::commit_vote(cpi_ctx, commitment, voting_power)?;
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for CommitVote<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.escrow.locker, self.locker);
+        // the voting power of the escrow is cast by its delegate record.
+        invariant!(!self.escrow.is_aggregated(), EscrowIsAggregated);
+        invariant!(!self.escrow.has_delegation_table, EscrowHasDelegationTable);
+        assert_keys_eq!(self.escrow.vote_delegate, self.vote_delegate);
+        assert_keys_eq!(self.locker.governor, self.governor);
+        assert_keys_eq!(self.proposal.governor, self.governor);
+        assert_keys_eq!(self.vote.proposal, self.proposal);
+        assert_keys_eq!(self.vote.voter, self.escrow.owner);
+        assert_keys_eq!(self.vote_commitment.vote, self.vote);
+        invariant!(
+            self.proposal.get_state()? == ProposalState::Active,
+            "proposal must be active"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use crate::*;
+
+/// Accounts for [voter::new_delegate_record].
+#[derive(Accounts)]
+pub struct NewDelegateRecord<'info> {
+    /// [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+
+    /// [DelegateRecord].
+    #[account(
+        init,
+        seeds = [
+            b"DelegateRecord".as_ref(),
+            locker.key().as_ref(),
+            delegate.key().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + DelegateRecord::LEN
+    )]
+    pub delegate_record: Account<'info, DelegateRecord>,
+
+    /// CHECK: Vote delegate of the [DelegateRecord] to be created.
+    pub delegate: UncheckedAccount<'info>,
+
+    /// Payer of the initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> NewDelegateRecord<'info> {
+    /// Creates a new [DelegateRecord].
+    pub fn new_delegate_record(&mut self, bump: u8) -> Result<()> {
+        let delegate_record = &mut self.delegate_record;
+        delegate_record.locker = self.locker.key();
+        delegate_record.delegate = self.delegate.key();
+        delegate_record.bump = bump;
+        delegate_record.delegated_amount = 0;
+        delegate_record.escrow_count = 0;
+        delegate_record.votes_locked_until = 0;
+        delegate_record.votes_paused_duration = 0;
+
+        emit!(NewDelegateRecordEvent {
+            delegate_record: delegate_record.key(),
+            delegate: delegate_record.delegate,
+            locker: delegate_record.locker,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for NewDelegateRecord<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [voter::new_delegate_record].
+pub struct NewDelegateRecordEvent {
+    /// The [DelegateRecord] being created.
+    pub delegate_record: Pubkey,
+    /// The vote delegate of the [DelegateRecord].
+    #[index]
+    pub delegate: Pubkey,
+    /// The locker for the [DelegateRecord].
+    #[index]
+    pub locker: Pubkey,
+}
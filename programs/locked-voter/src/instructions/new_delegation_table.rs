@@ -0,0 +1,46 @@
+use crate::*;
+
+/// Accounts for [voter::new_delegation_table].
+#[derive(Accounts)]
+pub struct NewDelegationTable<'info> {
+    /// [Escrow].
+    pub escrow: Box<Account<'info, Escrow>>,
+
+    /// [DelegationTable].
+    #[account(
+        init,
+        seeds = [
+            b"DelegationTable".as_ref(),
+            escrow.key().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + DelegationTable::LEN
+    )]
+    pub delegation_table: Account<'info, DelegationTable>,
+
+    /// Payer of the initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> NewDelegationTable<'info> {
+    /// Creates a new, empty [DelegationTable].
+    pub fn new_delegation_table(&mut self, bump: u8) -> Result<()> {
+        let delegation_table = &mut self.delegation_table;
+        delegation_table.escrow = self.escrow.key();
+        delegation_table.bump = bump;
+        delegation_table.delegates = vec![];
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for NewDelegationTable<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -67,6 +67,8 @@ impl<'info> OpenPartialUnstaking<'info> {
 
 impl<'info> Validate<'info> for OpenPartialUnstaking<'info> {
     fn validate(&self) -> Result<()> {
+        // the aggregated amount of a delegate record cannot decrease.
+        invariant!(!self.escrow.is_aggregated(), EscrowIsAggregated);
         Ok(())
     }
 }
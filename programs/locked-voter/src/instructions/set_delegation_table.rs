@@ -0,0 +1,68 @@
+use crate::*;
+
+/// Accounts for [voter::set_delegation_table].
+#[derive(Accounts)]
+pub struct SetDelegationTable<'info> {
+    /// The [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// The [Governor] of the [Locker], whose voting pauses extend the votes lock.
+    pub governor: Box<Account<'info, Governor>>,
+    /// The [Escrow].
+    #[account(mut)]
+    pub escrow: Box<Account<'info, Escrow>>,
+    /// The owner of the [Escrow].
+    pub escrow_owner: Signer<'info>,
+    /// The [DelegationTable] of the [Escrow].
+    #[account(mut, has_one = escrow)]
+    pub delegation_table: Box<Account<'info, DelegationTable>>,
+}
+
+impl<'info> SetDelegationTable<'info> {
+    pub fn set_delegation_table(&mut self, delegates: Vec<DelegationShare>) -> Result<()> {
+        invariant!(
+            DelegationTable::validate_shares(&delegates),
+            InvalidDelegationShares
+        );
+
+        // an empty table votes with the whole escrow through [Escrow::vote_delegate] again.
+        self.escrow.has_delegation_table = !delegates.is_empty();
+        self.delegation_table.delegates = delegates.clone();
+
+        emit!(SetDelegationTableEvent {
+            escrow: self.escrow.key(),
+            escrow_owner: self.escrow.owner,
+            delegates,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for SetDelegationTable<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.escrow.owner, self.escrow_owner);
+        assert_keys_eq!(self.escrow.locker, self.locker);
+        assert_keys_eq!(self.locker.governor, self.governor);
+        invariant!(!self.escrow.is_aggregated(), EscrowIsAggregated);
+        // votes cast with the current shares may still be counted.
+        let now = Clock::get()?.unix_timestamp;
+        invariant!(
+            now >= unwrap_int!(self.escrow.votes_counted_until(&self.governor, now)),
+            VotesInProgress
+        );
+
+        Ok(())
+    }
+}
+
+#[event]
+/// Event called in [voter::set_delegation_table].
+pub struct SetDelegationTableEvent {
+    /// The [Escrow].
+    pub escrow: Pubkey,
+    /// The owner of the [Escrow].
+    #[index]
+    pub escrow_owner: Pubkey,
+    /// The new delegates and their shares.
+    pub delegates: Vec<DelegationShare>,
+}
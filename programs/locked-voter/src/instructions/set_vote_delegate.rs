@@ -3,22 +3,52 @@ use crate::*;
 /// Accounts for set_vote_delegate
 #[derive(Accounts)]
 pub struct SetVoteDelegate<'info> {
+    /// The [Locker].
+    pub locker: Box<Account<'info, Locker>>,
+    /// The [Governor] of the [Locker], whose voting pauses extend the votes lock.
+    pub governor: Box<Account<'info, Governor>>,
     /// The [Escrow].
     #[account(mut)]
     pub escrow: Box<Account<'info, Escrow>>,
     /// The owner of the [Escrow].
     pub escrow_owner: Signer<'info>,
+    /// The [DelegateRecord] of the old delegate. Required if the escrow is aggregated into it.
+    #[account(mut)]
+    pub old_delegate_record: Option<Box<Account<'info, DelegateRecord>>>,
+    /// The [DelegateRecord] of the new delegate, if the escrow should be aggregated into it.
+    #[account(mut)]
+    pub new_delegate_record: Option<Box<Account<'info, DelegateRecord>>>,
 }
 
 impl<'info> SetVoteDelegate<'info> {
     pub fn set_vote_delegate(&mut self, new_delegate: Pubkey) -> Result<()> {
+        // the escrow must be aggregated into the record of the delegate that votes for it.
+        // checked here since [Validate] does not receive the instruction arguments.
+        if let Some(new_delegate_record) = self.new_delegate_record.as_ref() {
+            assert_keys_eq!(new_delegate_record.delegate, new_delegate);
+        }
+
         let old_delegate = self.escrow.vote_delegate;
-        self.escrow.vote_delegate = new_delegate;
+        let escrow = &mut self.escrow;
+
+        if escrow.is_aggregated() {
+            let old_delegate_record = unwrap_opt!(self.old_delegate_record.as_mut());
+            unwrap_int!(old_delegate_record.remove_escrow(escrow.aggregated_amount));
+            escrow.aggregated_amount = 0;
+        }
+
+        if let Some(new_delegate_record) = self.new_delegate_record.as_mut() {
+            unwrap_int!(new_delegate_record.add_escrow(escrow.amount));
+            escrow.aggregated_amount = escrow.amount;
+        }
+
+        escrow.vote_delegate = new_delegate;
 
         emit!(SetVoteDelegateEvent {
-            escrow_owner: self.escrow.owner,
+            escrow_owner: escrow.owner,
             old_delegate,
             new_delegate,
+            aggregated_amount: escrow.aggregated_amount,
         });
 
         Ok(())
@@ -28,6 +58,36 @@ impl<'info> SetVoteDelegate<'info> {
 impl<'info> Validate<'info> for SetVoteDelegate<'info> {
     fn validate(&self) -> Result<()> {
         assert_keys_eq!(self.escrow.owner, self.escrow_owner);
+        assert_keys_eq!(self.escrow.locker, self.locker);
+        assert_keys_eq!(self.locker.governor, self.governor);
+        let now = Clock::get()?.unix_timestamp;
+
+        if self.escrow.is_aggregated() {
+            let old_delegate_record = unwrap_opt!(self.old_delegate_record.as_ref());
+            assert_keys_eq!(old_delegate_record.locker, self.escrow.locker);
+            assert_keys_eq!(old_delegate_record.delegate, self.escrow.vote_delegate);
+            // the aggregated votes of the old delegate may still be counted.
+            invariant!(
+                now >= unwrap_int!(old_delegate_record.votes_counted_until(&self.governor, now)),
+                VotesInProgress
+            );
+        }
+
+        if let Some(new_delegate_record) = self.new_delegate_record.as_ref() {
+            assert_keys_eq!(new_delegate_record.locker, self.escrow.locker);
+            invariant!(
+                self.escrow.is_max_lock,
+                MaxLockIsNotSet,
+                "only max locked escrows can be aggregated"
+            );
+            invariant!(self.escrow.amount > 0, AmountIsZero);
+            invariant!(!self.escrow.has_delegation_table, EscrowHasDelegationTable);
+            // the escrow's own votes may still be counted.
+            invariant!(
+                now >= unwrap_int!(self.escrow.votes_counted_until(&self.governor, now)),
+                VotesInProgress
+            );
+        }
 
         Ok(())
     }
@@ -43,4 +103,6 @@ pub struct SetVoteDelegateEvent {
     pub old_delegate: Pubkey,
     /// The new escrow delegate.
     pub new_delegate: Pubkey,
+    /// The amount of tokens aggregated into the [DelegateRecord] of the new delegate.
+    pub aggregated_amount: u64,
 }
@@ -41,6 +41,8 @@ impl<'info> Validate<'info> for ToggleMaxLock<'info> {
     fn validate(&self) -> Result<()> {
         assert_keys_eq!(self.locker, self.escrow.locker);
         assert_keys_eq!(self.escrow.owner, self.escrow_owner);
+        // only max locked escrows can stay aggregated into a delegate record.
+        invariant!(!self.escrow.is_aggregated(), EscrowIsAggregated);
 
         Ok(())
     }
@@ -12,6 +12,7 @@ use vipers::prelude::*;
 
 mod instructions;
 pub mod locker;
+pub mod relay;
 mod state;
 
 pub use instructions::*;
@@ -80,13 +81,89 @@ pub mod locked_voter {
         ctx.accounts.activate_proposal()
     }
 
+    /// Activates a proposal on behalf of an [Escrow] whose voting power meets
+    /// [LockerParams::proposal_activation_min_votes].
+    /// May be called by either the [Escrow::owner] or the [Escrow::vote_delegate].
+    #[access_control(ctx.accounts.validate())]
+    pub fn activate_proposal_with_escrow(ctx: Context<ActivateProposalWithEscrow>) -> Result<()> {
+        ctx.accounts.activate_proposal()
+    }
+
     /// Casts a vote.
     #[access_control(ctx.accounts.validate())]
     pub fn cast_vote(ctx: Context<CastVote>, side: u8) -> Result<()> {
         ctx.accounts.cast_vote(side)
     }
 
+    /// Casts a vote signed off-chain by the owner or vote delegate of an [Escrow], submitted by a relayer.
+    /// The relayer pays for the transaction and the [Vote], so the voter does not need any SOL.
+    /// Must be preceded by an ed25519 program instruction verifying the signature of the
+    /// [relay::relayed_vote_message], whose nonce must be the [Escrow::relay_nonce].
+    #[access_control(ctx.accounts.validate())]
+    pub fn cast_relayed_vote(ctx: Context<CastRelayedVote>, side: u8, nonce: u64) -> Result<()> {
+        ctx.accounts.cast_relayed_vote(side, nonce)
+    }
+
+    /// Commits a hidden vote on a commit-reveal proposal.
+    #[access_control(ctx.accounts.validate())]
+    pub fn commit_vote(ctx: Context<CommitVote>, commitment: [u8; 32]) -> Result<()> {
+        ctx.accounts.commit_vote(commitment)
+    }
+
+    /// Casts a vote split across options, in basis points of the escrow's voting power.
+    #[access_control(ctx.accounts.validate())]
+    pub fn cast_split_vote(ctx: Context<CastVote>, split_bps: Vec<u16>) -> Result<()> {
+        ctx.accounts.cast_split_vote(split_bps)
+    }
+
+    /// Casts a vote on an approval proposal, selecting the options in the `selections` bitmask.
+    #[access_control(ctx.accounts.validate())]
+    pub fn cast_approval_vote(ctx: Context<CastVote>, selections: u16) -> Result<()> {
+        ctx.accounts.cast_approval_vote(selections)
+    }
+
+    /// Claims the voting rewards of an escrow owner on their behalf. Only callable by the vote delegate,
+    /// and the rewards are always paid to the owner's associated token accounts.
+    #[access_control(ctx.accounts.validate())]
+    pub fn claim_reward<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ClaimReward<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.claim_reward(ctx.remaining_accounts)
+    }
+
+    /// Creates a new [DelegateRecord] for a vote delegate.
+    #[access_control(ctx.accounts.validate())]
+    pub fn new_delegate_record(ctx: Context<NewDelegateRecord>) -> Result<()> {
+        ctx.accounts
+            .new_delegate_record(unwrap_bump!(ctx, "delegate_record"))
+    }
+
+    /// Casts a vote with the aggregated voting power of a [DelegateRecord].
+    #[access_control(ctx.accounts.validate())]
+    pub fn cast_delegated_vote(ctx: Context<CastDelegatedVote>, side: u8) -> Result<()> {
+        ctx.accounts.cast_delegated_vote(side)
+    }
+
+    /// Creates a new [DelegationTable] for an [Escrow].
+    #[access_control(ctx.accounts.validate())]
+    pub fn new_delegation_table(ctx: Context<NewDelegationTable>) -> Result<()> {
+        ctx.accounts
+            .new_delegation_table(unwrap_bump!(ctx, "delegation_table"))
+    }
+
+    /// Splits the voting power of an [Escrow] across delegates, in basis points.
+    /// Each delegate then votes with their share through [locked_voter::cast_vote].
+    /// An empty list of delegates reverts to voting through [Escrow::vote_delegate].
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_delegation_table(
+        ctx: Context<SetDelegationTable>,
+        delegates: Vec<DelegationShare>,
+    ) -> Result<()> {
+        ctx.accounts.set_delegation_table(delegates)
+    }
+
     /// Delegate escrow vote.
+    /// Max locked escrows may also be aggregated into the [DelegateRecord] of the new delegate.
     #[access_control(ctx.accounts.validate())]
     pub fn set_vote_delegate(ctx: Context<SetVoteDelegate>, new_delegate: Pubkey) -> Result<()> {
         ctx.accounts.set_vote_delegate(new_delegate)
@@ -165,4 +242,20 @@ pub enum ErrorCode {
     PartialUnstakingIsNotEnded,
     #[msg("Amount is too large")]
     AmountIsTooLarge,
+    #[msg("Escrow is aggregated into a delegate record")]
+    EscrowIsAggregated,
+    #[msg("Votes may still be counted")]
+    VotesInProgress,
+    #[msg("Delegation shares must be distinct, non-zero and sum up to 10000 bps")]
+    InvalidDelegationShares,
+    #[msg("Escrow voting power is split across delegates")]
+    EscrowHasDelegationTable,
+    #[msg("Relayed vote is not signed by an ed25519 instruction for this vote")]
+    InvalidRelayedVoteSignature,
+    #[msg("Relayed vote nonce does not match the escrow relay nonce")]
+    InvalidRelayNonce,
+    #[msg("Authority must be the escrow owner or its vote delegate")]
+    InvalidProposalActivator,
+    #[msg("Insufficient voting power to activate a proposal")]
+    InsufficientActivationVotingPower,
 }
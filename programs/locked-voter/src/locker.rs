@@ -5,6 +5,15 @@ use crate::*;
 use num_traits::ToPrimitive;
 
 impl Locker {
+    /// Calculates the voting power of the [Locker::locked_supply], as if it were max locked.
+    ///
+    /// This is an upper bound of the voting power of all [Escrow]s: it also counts tokens of
+    /// escrows that are not max locked or whose lockup has expired.
+    pub fn locked_voting_power(&self) -> Option<u64> {
+        self.locked_supply
+            .checked_mul(self.params.max_stake_vote_multiplier.into())
+    }
+
     /// Calculates the amount of voting power an [Escrow] has.
     pub fn calculate_voter_power(&self, escrow: &Escrow, now: i64) -> Option<u64> {
         // invalid `now` argument, should never happen.
@@ -0,0 +1,124 @@
+//! Relayed vote messages.
+
+use crate::*;
+
+/// Domain separator of relayed vote messages, so that they cannot be mistaken for other signed messages.
+pub const RELAYED_VOTE_DOMAIN: &[u8] = b"locked-voter:relayed-vote";
+
+/// Size of the offsets of a signature in an ed25519 program instruction.
+const ED25519_SIGNATURE_OFFSETS_SIZE: usize = 14;
+
+/// The message that an escrow owner or vote delegate signs to have their vote relayed.
+/// It is the [RELAYED_VOTE_DOMAIN] followed by the escrow, the proposal, the side and the little-endian nonce.
+pub fn relayed_vote_message(escrow: Pubkey, proposal: Pubkey, side: u8, nonce: u64) -> Vec<u8> {
+    let mut message = Vec::with_capacity(RELAYED_VOTE_DOMAIN.len() + 32 + 32 + 1 + 8);
+    message.extend_from_slice(RELAYED_VOTE_DOMAIN);
+    message.extend_from_slice(escrow.as_ref());
+    message.extend_from_slice(proposal.as_ref());
+    message.push(side);
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message
+}
+
+/// Parses the data of an ed25519 program instruction verifying a single signature,
+/// returning the public key and the message that were verified.
+///
+/// Returns [None] if the instruction verifies more than one signature,
+/// or if it reads its signature, public key or message from another instruction.
+pub fn parse_ed25519_instruction(data: &[u8]) -> Option<(Pubkey, &[u8])> {
+    if *data.first()? != 1 {
+        return None;
+    }
+    let offsets = data.get(2..2 + ED25519_SIGNATURE_OFFSETS_SIZE)?;
+    let read_u16 = |index: usize| -> Option<u16> {
+        let bytes = offsets.get(index * 2..index * 2 + 2)?;
+        Some(u16::from_le_bytes(bytes.try_into().ok()?))
+    };
+
+    // signature, public key and message must all be in the ed25519 instruction itself.
+    let signature_instruction_index = read_u16(1)?;
+    let public_key_instruction_index = read_u16(3)?;
+    let message_instruction_index = read_u16(6)?;
+    if signature_instruction_index != u16::MAX
+        || public_key_instruction_index != u16::MAX
+        || message_instruction_index != u16::MAX
+    {
+        return None;
+    }
+
+    let public_key_offset = usize::from(read_u16(2)?);
+    let message_data_offset = usize::from(read_u16(4)?);
+    let message_data_size = usize::from(read_u16(5)?);
+
+    let public_key = data.get(public_key_offset..public_key_offset.checked_add(32)?)?;
+    let message =
+        data.get(message_data_offset..message_data_offset.checked_add(message_data_size)?)?;
+    Some((Pubkey::try_from(public_key).ok()?, message))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    /// Builds the data of an ed25519 program instruction the way the native client does.
+    fn ed25519_instruction_data(public_key: Pubkey, message: &[u8]) -> Vec<u8> {
+        let public_key_offset: u16 = 16;
+        let signature_offset: u16 = public_key_offset + 32;
+        let message_data_offset: u16 = signature_offset + 64;
+
+        let mut data = vec![1, 0];
+        for value in [
+            signature_offset,
+            u16::MAX,
+            public_key_offset,
+            u16::MAX,
+            message_data_offset,
+            message.len() as u16,
+            u16::MAX,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(public_key.as_ref());
+        data.extend_from_slice(&[0; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn test_parse_ed25519_instruction() {
+        let voter = Pubkey::new_unique();
+        let message = relayed_vote_message(Pubkey::new_unique(), Pubkey::new_unique(), 2, 7);
+        let data = ed25519_instruction_data(voter, &message);
+
+        let (public_key, signed_message) = parse_ed25519_instruction(&data).unwrap();
+        assert_eq!(public_key, voter);
+        assert_eq!(signed_message, message.as_slice());
+
+        // more than one signature.
+        let mut multiple = data.clone();
+        multiple[0] = 2;
+        assert!(parse_ed25519_instruction(&multiple).is_none());
+
+        // message read from another instruction.
+        let mut other_instruction = data.clone();
+        other_instruction[14..16].copy_from_slice(&0u16.to_le_bytes());
+        assert!(parse_ed25519_instruction(&other_instruction).is_none());
+
+        // truncated message.
+        assert!(parse_ed25519_instruction(&data[..data.len() - 1]).is_none());
+    }
+
+    #[test]
+    fn test_relayed_vote_message() {
+        let escrow = Pubkey::new_unique();
+        let proposal = Pubkey::new_unique();
+        let message = relayed_vote_message(escrow, proposal, 1, 0);
+        assert!(message.starts_with(RELAYED_VOTE_DOMAIN));
+
+        // every field is bound by the message.
+        assert_ne!(message, relayed_vote_message(proposal, escrow, 1, 0));
+        assert_ne!(message, relayed_vote_message(escrow, proposal, 2, 0));
+        assert_ne!(message, relayed_vote_message(escrow, proposal, 1, 1));
+    }
+}
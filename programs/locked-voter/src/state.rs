@@ -77,10 +77,21 @@ pub struct Escrow {
     pub is_max_lock: bool,
     /// total amount of partial unstaking amount
     pub partial_unstaking_amount: u64,
+    /// Amount of tokens aggregated into the [DelegateRecord] of [Escrow::vote_delegate].
+    /// Zero if the escrow votes on its own.
+    pub aggregated_amount: u64,
+    /// Until when the escrow cannot join a [DelegateRecord], because it voted on a proposal that may still be counting votes.
+    pub votes_locked_until: i64,
+    /// Nonce that the next relayed vote message of the escrow must be signed with.
+    pub relay_nonce: u64,
+    /// Whether the voting power is split across the delegates of the escrow's [DelegationTable].
+    pub has_delegation_table: bool,
     /// padding for further use
-    pub padding: u64,
+    pub padding: [u8; 7],
+    /// The [Governor::voting_paused_duration] already applied to [Escrow::votes_locked_until].
+    pub votes_paused_duration: i64,
     /// buffer for further use
-    pub buffers: [u128; 9],
+    pub buffers: [u128; 7],
 }
 
 impl Escrow {
@@ -156,6 +167,254 @@ impl Escrow {
         self.partial_unstaking_amount = self.partial_unstaking_amount.checked_sub(amount)?;
         Some(())
     }
+
+    /// Whether the escrow's voting power is aggregated into a [DelegateRecord].
+    pub fn is_aggregated(&self) -> bool {
+        self.aggregated_amount > 0
+    }
+
+    /// Until when the votes of the escrow may still be counted, including the voting pauses of the [Governor].
+    pub fn votes_counted_until(&self, governor: &Governor, now: i64) -> Option<i64> {
+        votes_counted_until(
+            self.votes_locked_until,
+            self.votes_paused_duration,
+            governor,
+            now,
+        )
+    }
+
+    /// Records a vote of the escrow on a proposal.
+    pub fn record_vote(
+        &mut self,
+        proposal: &Proposal,
+        governor: &Governor,
+        now: i64,
+    ) -> Option<()> {
+        let counted_until = self.votes_counted_until(governor, now)?;
+        (self.votes_locked_until, self.votes_paused_duration) = lock_votes(
+            self.votes_locked_until,
+            self.votes_paused_duration,
+            counted_until,
+            proposal,
+            now,
+        )?;
+        Some(())
+    }
+}
+
+/// Splits the voting power of an [Escrow] across multiple vote delegates.
+///
+/// Each delegate votes with their share through a program address voter, whose [Vote] is
+/// marked unrewarded: share votes earn no voting rewards, reward streams or option incentives,
+/// neither for the delegate nor for the escrow owner. Escrows that want rewards must vote
+/// through [Escrow::vote_delegate] instead. Each unrewarded share vote emits a [ShareVoteUnrewardedEvent].
+#[account]
+#[derive(Debug, Default)]
+pub struct DelegationTable {
+    /// The [Escrow] pubkey.
+    pub escrow: Pubkey,
+    /// Bump seed.
+    pub bump: u8,
+    /// buffer for further use
+    pub buffers: [u128; 2],
+    /// Delegates and their share of the escrow's voting power.
+    pub delegates: Vec<DelegationShare>,
+}
+
+impl DelegationTable {
+    /// Maximum number of delegates of an [Escrow].
+    pub const MAX_DELEGATES: usize = 8;
+
+    /// LEN of DelegationTable
+    pub const LEN: usize =
+        std::mem::size_of::<Pubkey>() + 1 + 16 * 2 + 4 + Self::MAX_DELEGATES * DelegationShare::LEN;
+
+    /// Gets the share of a delegate in basis points.
+    pub fn delegate_bps(&self, delegate: Pubkey) -> Option<u16> {
+        self.delegates
+            .iter()
+            .find(|share| share.delegate == delegate)
+            .map(|share| share.bps)
+    }
+
+    /// Gets the voter of the [Vote] a delegate casts with their share of an [Escrow].
+    pub fn share_voter(escrow: Pubkey, delegate: Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                b"DelegationShare".as_ref(),
+                escrow.as_ref(),
+                delegate.as_ref(),
+            ],
+            &crate::ID,
+        )
+        .0
+    }
+
+    /// Checks that the shares are valid: at most [DelegationTable::MAX_DELEGATES] distinct delegates,
+    /// each with a non-zero share, and summing up to [govern::MAX_BPS] unless empty.
+    pub fn validate_shares(delegates: &[DelegationShare]) -> bool {
+        if delegates.is_empty() {
+            return true;
+        }
+        if delegates.len() > Self::MAX_DELEGATES {
+            return false;
+        }
+        let mut total_bps: u16 = 0;
+        for (i, share) in delegates.iter().enumerate() {
+            if share.bps == 0 || delegates[..i].iter().any(|s| s.delegate == share.delegate) {
+                return false;
+            }
+            total_bps = match total_bps.checked_add(share.bps) {
+                Some(total_bps) => total_bps,
+                None => return false,
+            };
+        }
+        total_bps == govern::MAX_BPS
+    }
+}
+
+/// The share of a delegate in a [DelegationTable].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DelegationShare {
+    /// The vote delegate.
+    pub delegate: Pubkey,
+    /// Share of the escrow's voting power, in basis points.
+    pub bps: u16,
+}
+
+impl DelegationShare {
+    /// LEN of DelegationShare
+    pub const LEN: usize = std::mem::size_of::<Pubkey>() + 2;
+}
+
+/// Aggregates the voting power of the max locked [Escrow]s delegated to a vote delegate,
+/// so that the delegate can vote for all of them at once.
+#[account]
+#[derive(Copy, Debug, Default)]
+pub struct DelegateRecord {
+    /// The [Locker] that this [DelegateRecord] is part of.
+    pub locker: Pubkey,
+    /// The vote delegate.
+    pub delegate: Pubkey,
+    /// Bump seed.
+    pub bump: u8,
+    /// Total amount of tokens of the [Escrow]s aggregated into this record.
+    pub delegated_amount: u64,
+    /// Number of [Escrow]s aggregated into this record.
+    pub escrow_count: u64,
+    /// Until when no [Escrow] can leave this record, because the delegate voted on a proposal that may still be counting votes.
+    pub votes_locked_until: i64,
+    /// The [Governor::voting_paused_duration] already applied to [DelegateRecord::votes_locked_until].
+    pub votes_paused_duration: i64,
+    /// padding for further use
+    pub padding: [u8; 8],
+    /// buffer for further use
+    pub buffers: [u128; 3],
+}
+
+impl DelegateRecord {
+    /// LEN of DelegateRecord
+    pub const LEN: usize = std::mem::size_of::<Pubkey>() * 2 + 1 + 8 + 8 + 8 + 8 + 8 + 16 * 3;
+
+    /// Gets the aggregated voting power of the record.
+    /// Only max locked escrows are aggregated, so this does not decay over time.
+    pub fn voting_power(&self, locker: &Locker) -> Option<u64> {
+        self.delegated_amount
+            .checked_mul(locker.params.max_stake_vote_multiplier.into())
+    }
+
+    /// Aggregates the amount of an [Escrow] into the record.
+    pub fn add_escrow(&mut self, amount: u64) -> Option<()> {
+        self.delegated_amount = self.delegated_amount.checked_add(amount)?;
+        self.escrow_count = self.escrow_count.checked_add(1)?;
+        Some(())
+    }
+
+    /// Removes the amount of an [Escrow] from the record.
+    pub fn remove_escrow(&mut self, amount: u64) -> Option<()> {
+        self.delegated_amount = self.delegated_amount.checked_sub(amount)?;
+        self.escrow_count = self.escrow_count.checked_sub(1)?;
+        Some(())
+    }
+
+    /// Until when the votes of the delegate may still be counted, including the voting pauses of the [Governor].
+    pub fn votes_counted_until(&self, governor: &Governor, now: i64) -> Option<i64> {
+        votes_counted_until(
+            self.votes_locked_until,
+            self.votes_paused_duration,
+            governor,
+            now,
+        )
+    }
+
+    /// Records a vote of the delegate on a proposal.
+    pub fn record_vote(
+        &mut self,
+        proposal: &Proposal,
+        governor: &Governor,
+        now: i64,
+    ) -> Option<()> {
+        let counted_until = self.votes_counted_until(governor, now)?;
+        (self.votes_locked_until, self.votes_paused_duration) = lock_votes(
+            self.votes_locked_until,
+            self.votes_paused_duration,
+            counted_until,
+            proposal,
+            now,
+        )?;
+        Some(())
+    }
+}
+
+/// The latest time at which votes on the proposal may still be counted, accounting for
+/// the reveal period and a late quorum extension that has not happened yet.
+/// Voting pauses that were not applied to the proposal yet are not included.
+fn vote_lock_end(proposal: &Proposal) -> Option<i64> {
+    let late_quorum_extension = if proposal.late_quorum_extended_at == 0 {
+        proposal.late_quorum_extension.try_into().ok()?
+    } else {
+        0
+    };
+    proposal
+        .reveal_ends_at()?
+        .checked_add(late_quorum_extension)
+}
+
+/// Extends `votes_locked_until` by the time voting was paused since `votes_paused_duration`,
+/// because every proposal that was voting during a pause gets its voting extended by it.
+fn votes_counted_until(
+    votes_locked_until: i64,
+    votes_paused_duration: i64,
+    governor: &Governor,
+    now: i64,
+) -> Option<i64> {
+    if votes_locked_until == 0 {
+        return Some(0);
+    }
+    let unapplied_pause = governor
+        .voting_paused_duration_at(now)?
+        .checked_sub(votes_paused_duration)?;
+    votes_locked_until.checked_add(unapplied_pause.max(0))
+}
+
+/// Adds a vote on the proposal to the votes lock, returning the new
+/// `votes_locked_until` and `votes_paused_duration`.
+/// Votes that are not counted anymore are dropped from the lock.
+fn lock_votes(
+    votes_locked_until: i64,
+    votes_paused_duration: i64,
+    counted_until: i64,
+    proposal: &Proposal,
+    now: i64,
+) -> Option<(i64, i64)> {
+    let lock_end = vote_lock_end(proposal)?;
+    if counted_until <= now {
+        return Some((lock_end, proposal.voting_paused_duration));
+    }
+    Some((
+        votes_locked_until.max(lock_end),
+        votes_paused_duration.min(proposal.voting_paused_duration),
+    ))
 }
 
 /// Account to store infor for partial unstaking
@@ -384,4 +643,223 @@ mod tests {
         assert_eq!(locker.locked_supply, 0);
         assert_escrow(&locker, bob, current_ts, 0.0);
     }
+
+    #[test]
+    fn test_delegate_record_voting_power() {
+        let locker = &mut Locker {
+            params: LockerParams {
+                max_stake_duration: MAX_TIME as u64,
+                max_stake_vote_multiplier: 10,
+                ..LockerParams::default()
+            },
+            ..Locker::default()
+        };
+        let alice = &mut Escrow {
+            is_max_lock: true,
+            ..Escrow::default()
+        };
+        let bob = &mut Escrow {
+            is_max_lock: true,
+            ..Escrow::default()
+        };
+        alice
+            .record_increase_locked_amount_event(locker, DEFAULT_LOCK_AMOUNT)
+            .unwrap();
+        bob.record_increase_locked_amount_event(locker, 2 * DEFAULT_LOCK_AMOUNT)
+            .unwrap();
+
+        let record = &mut DelegateRecord::default();
+        record.add_escrow(alice.amount).unwrap();
+        record.add_escrow(bob.amount).unwrap();
+        assert_eq!(record.escrow_count, 2);
+
+        // the aggregated voting power is the sum of the escrows' voting powers.
+        let current_ts = CANONICAL_START_TIME;
+        assert_eq!(
+            record.voting_power(locker).unwrap(),
+            alice.voting_power_at_time(locker, current_ts).unwrap()
+                + bob.voting_power_at_time(locker, current_ts).unwrap()
+        );
+
+        record.remove_escrow(alice.amount).unwrap();
+        assert_eq!(record.escrow_count, 1);
+        assert_eq!(
+            record.voting_power(locker).unwrap(),
+            bob.voting_power_at_time(locker, current_ts).unwrap()
+        );
+
+        record.remove_escrow(bob.amount).unwrap();
+        assert_eq!(record.voting_power(locker).unwrap(), 0);
+
+        // cannot remove more than was aggregated.
+        assert!(record.remove_escrow(alice.amount).is_none());
+    }
+
+    #[test]
+    fn test_locked_voting_power_upper_bound() {
+        let locker = &mut Locker {
+            params: LockerParams {
+                max_stake_duration: MAX_TIME as u64,
+                max_stake_vote_multiplier: 10,
+                ..LockerParams::default()
+            },
+            ..Locker::default()
+        };
+        let alice = &mut Escrow {
+            is_max_lock: true,
+            ..Escrow::default()
+        };
+        let bob = &mut Escrow::default();
+        let carol = &mut Escrow::default();
+
+        let current_ts = CANONICAL_START_TIME;
+        alice
+            .record_increase_locked_amount_event(locker, DEFAULT_LOCK_AMOUNT)
+            .unwrap();
+        // Bob locks for 1 week, Carol's lockup has expired without a withdrawal
+        bob.record_increase_locked_amount_event(locker, DEFAULT_LOCK_AMOUNT)
+            .unwrap();
+        bob.record_extend_lock_duration_event(current_ts, current_ts + WEEK)
+            .unwrap();
+        carol
+            .record_increase_locked_amount_event(locker, DEFAULT_LOCK_AMOUNT)
+            .unwrap();
+        carol
+            .record_extend_lock_duration_event(current_ts - WEEK, current_ts - DAY)
+            .unwrap();
+
+        // the whole locked supply is counted as max locked.
+        assert_eq!(
+            locker.locked_voting_power().unwrap(),
+            3 * DEFAULT_LOCK_AMOUNT * 10
+        );
+
+        let escrows_voting_power = alice.voting_power_at_time(locker, current_ts).unwrap()
+            + bob.voting_power_at_time(locker, current_ts).unwrap()
+            + carol.voting_power_at_time(locker, current_ts).unwrap();
+        assert_eq!(carol.voting_power_at_time(locker, current_ts).unwrap(), 0);
+        assert!(locker.locked_voting_power().unwrap() > escrows_voting_power);
+    }
+
+    #[test]
+    fn test_record_vote() {
+        let governor = Governor::default();
+        let proposal = Proposal {
+            voting_ends_at: 100,
+            reveal_period: 20,
+            late_quorum_extension: 10,
+            ..Proposal::default()
+        };
+        let escrow = &mut Escrow::default();
+        escrow.record_vote(&proposal, &governor, 0).unwrap();
+        assert_eq!(escrow.votes_locked_until, 130);
+
+        // an earlier proposal does not shorten the lock.
+        let earlier = Proposal {
+            voting_ends_at: 50,
+            ..Proposal::default()
+        };
+        let record = &mut DelegateRecord::default();
+        record.record_vote(&proposal, &governor, 0).unwrap();
+        record.record_vote(&earlier, &governor, 0).unwrap();
+        assert_eq!(record.votes_locked_until, 130);
+
+        // the late quorum extension is already included once voting was extended.
+        let extended = Proposal {
+            late_quorum_extended_at: 90,
+            ..proposal.clone()
+        };
+        let escrow = &mut Escrow::default();
+        escrow.record_vote(&extended, &governor, 0).unwrap();
+        assert_eq!(escrow.votes_locked_until, 120);
+    }
+
+    #[test]
+    fn test_votes_counted_until_voting_pause() {
+        let proposal = Proposal {
+            voting_ends_at: 100,
+            reveal_period: 20,
+            late_quorum_extension: 10,
+            voting_paused_duration: 5,
+            ..Proposal::default()
+        };
+        let mut governor = Governor {
+            voting_paused_duration: 5,
+            ..Governor::default()
+        };
+        let escrow = &mut Escrow::default();
+        assert_eq!(escrow.votes_counted_until(&governor, 0), Some(0));
+        escrow.record_vote(&proposal, &governor, 0).unwrap();
+        assert_eq!(escrow.votes_counted_until(&governor, 0), Some(130));
+
+        // voting is paused: the lock is extended while the pause lasts.
+        governor.paused_instructions = govern::PAUSE_SET_VOTE;
+        governor.paused_at = 110;
+        governor.paused_until = 200;
+        assert_eq!(escrow.votes_counted_until(&governor, 125), Some(145));
+        assert_eq!(escrow.votes_counted_until(&governor, 300), Some(220));
+
+        // a vote during the pause keeps the extension of earlier votes.
+        let later = Proposal {
+            voting_ends_at: 150,
+            ..proposal.clone()
+        };
+        escrow.record_vote(&later, &governor, 125).unwrap();
+        assert_eq!(escrow.votes_locked_until, 180);
+        assert_eq!(escrow.votes_counted_until(&governor, 125), Some(195));
+
+        // votes that are not counted anymore do not extend a new lock.
+        governor.paused_instructions = 0;
+        governor.voting_paused_duration = 95;
+        assert_eq!(escrow.votes_counted_until(&governor, 300), Some(270));
+        let last = Proposal {
+            voting_ends_at: 300,
+            voting_paused_duration: 95,
+            ..proposal.clone()
+        };
+        escrow.record_vote(&last, &governor, 300).unwrap();
+        assert_eq!(escrow.votes_locked_until, 330);
+        assert_eq!(escrow.votes_counted_until(&governor, 300), Some(330));
+    }
+
+    #[test]
+    fn test_validate_delegation_shares() {
+        let research = Pubkey::new_unique();
+        let community = Pubkey::new_unique();
+        let share = |delegate, bps| DelegationShare { delegate, bps };
+
+        assert!(DelegationTable::validate_shares(&[]));
+        assert!(DelegationTable::validate_shares(&[
+            share(research, 5_000),
+            share(community, 5_000)
+        ]));
+        // shares must sum up to 100%.
+        assert!(!DelegationTable::validate_shares(&[
+            share(research, 5_000),
+            share(community, 4_000)
+        ]));
+        // delegates must be distinct.
+        assert!(!DelegationTable::validate_shares(&[
+            share(research, 5_000),
+            share(research, 5_000)
+        ]));
+        // shares must be non-zero.
+        assert!(!DelegationTable::validate_shares(&[
+            share(research, 10_000),
+            share(community, 0)
+        ]));
+        // too many delegates.
+        let shares: Vec<DelegationShare> = (0..DelegationTable::MAX_DELEGATES + 1)
+            .map(|_| share(Pubkey::new_unique(), 1))
+            .collect();
+        assert!(!DelegationTable::validate_shares(&shares));
+
+        let table = DelegationTable {
+            delegates: vec![share(research, 2_500), share(community, 7_500)],
+            ..DelegationTable::default()
+        };
+        assert_eq!(table.delegate_bps(research), Some(2_500));
+        assert_eq!(table.delegate_bps(community), Some(7_500));
+        assert_eq!(table.delegate_bps(Pubkey::new_unique()), None);
+    }
 }
@@ -27,6 +27,7 @@ impl<'info> ActivateProposalInitialPhase<'info> {
                 self.to_activate_proposal_accounts(),
             )
             .with_signer(seeds),
+            unwrap_int!(self.locker.locked_voting_power()),
         )?;
 
         Ok(())
@@ -8,7 +8,8 @@ pub struct CastVote<'info> {
     pub locker: Box<Account<'info, Locker>>,
     /// The [Escrow] that is voting.
     pub escrow: Box<Account<'info, Escrow>>,
-    /// Vote delegate of the [Escrow].
+    /// Vote delegate of the [Escrow], or its owner.
+    /// A vote cast by the owner overrides the vote of their delegate on the proposal.
     pub vote_delegate: Signer<'info>,
 
     /// The [Proposal] being voted on.
@@ -46,7 +47,82 @@ impl<'info> CastVote<'info> {
         .with_signer(seeds);
 
         govern::cpi::set_vote(cpi_ctx, side, voting_power)?;
-        Ok(())
+        self.override_delegate_vote()
+    }
+
+    pub fn cast_split_vote(&mut self, split_bps: Vec<u16>) -> Result<()> {
+        let voting_power = self.future_voting_power()?;
+
+        // zero votes should short circuit.
+        if voting_power == 0 {
+            return Ok(());
+        }
+
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        let cpi_ctx = CpiContext::new(
+            self.govern_program.to_account_info(),
+            govern::cpi::accounts::SetVote {
+                governor: self.governor.to_account_info(),
+                proposal: self.proposal.to_account_info(),
+                vote: self.vote.to_account_info(),
+                locker: self.locker.to_account_info(),
+            },
+        )
+        .with_signer(seeds);
+
+        govern::cpi::set_split_vote(cpi_ctx, split_bps, voting_power)?;
+        self.override_delegate_vote()
+    }
+
+    pub fn cast_approval_vote(&mut self, selections: u16) -> Result<()> {
+        let voting_power = self.future_voting_power()?;
+
+        // zero votes should short circuit.
+        if voting_power == 0 {
+            return Ok(());
+        }
+
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        let cpi_ctx = CpiContext::new(
+            self.govern_program.to_account_info(),
+            govern::cpi::accounts::SetVote {
+                governor: self.governor.to_account_info(),
+                proposal: self.proposal.to_account_info(),
+                vote: self.vote.to_account_info(),
+                locker: self.locker.to_account_info(),
+            },
+        )
+        .with_signer(seeds);
+
+        govern::cpi::set_approval_vote(cpi_ctx, selections, voting_power)?;
+        self.override_delegate_vote()
+    }
+
+    /// Whether the owner of the escrow is voting instead of their delegate.
+    fn is_owner_override(&self) -> bool {
+        self.vote_delegate.key() == self.escrow.owner
+            && self.escrow.vote_delegate != self.escrow.owner
+    }
+
+    /// Marks the vote as overridden if the owner cast it, so their delegate cannot overwrite it.
+    fn override_delegate_vote(&self) -> Result<()> {
+        if !self.is_owner_override() || self.vote.owner_override {
+            return Ok(());
+        }
+
+        let seeds: &[&[&[u8]]] = locker_seeds!(self.locker);
+        let cpi_ctx = CpiContext::new(
+            self.govern_program.to_account_info(),
+            govern::cpi::accounts::SetVote {
+                governor: self.governor.to_account_info(),
+                proposal: self.proposal.to_account_info(),
+                vote: self.vote.to_account_info(),
+                locker: self.locker.to_account_info(),
+            },
+        )
+        .with_signer(seeds);
+
+        govern::cpi::set_vote_override(cpi_ctx)
     }
 
     /// The voting power of the escrow at the time the proposal's voting ends.
@@ -65,7 +141,13 @@ impl<'info> CastVote<'info> {
 impl<'info> Validate<'info> for CastVote<'info> {
     fn validate(&self) -> Result<()> {
         assert_keys_eq!(self.escrow.locker, self.locker);
-        assert_keys_eq!(self.escrow.vote_delegate, self.vote_delegate);
+        if !self.is_owner_override() {
+            assert_keys_eq!(self.escrow.vote_delegate, self.vote_delegate);
+            invariant!(
+                !self.vote.owner_override,
+                "the owner has overridden the delegate's vote"
+            );
+        }
         assert_keys_eq!(self.locker.governor, self.governor);
         assert_keys_eq!(self.proposal.governor, self.governor);
         assert_keys_eq!(self.vote.proposal, self.proposal);
@@ -4,6 +4,8 @@ pub mod activate_proposal;
 pub mod activate_proposal_initial_phase;
 pub mod cast_vote;
 pub mod change_locker_expiration;
+pub mod claim_reward;
+pub mod commit_vote;
 pub mod extend_lock_duration;
 pub mod increase_locked_amount;
 pub mod new_escrow;
@@ -17,6 +19,8 @@ pub use activate_proposal::*;
 pub use activate_proposal_initial_phase::*;
 pub use cast_vote::*;
 pub use change_locker_expiration::*;
+pub use claim_reward::*;
+pub use commit_vote::*;
 pub use extend_lock_duration::*;
 pub use increase_locked_amount::*;
 pub use new_escrow::*;
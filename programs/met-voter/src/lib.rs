@@ -108,6 +108,33 @@ pub mod met_voter {
         ctx.accounts.cast_vote(side)
     }
 
+    /// Commits a hidden vote on a commit-reveal proposal.
+    #[access_control(ctx.accounts.validate())]
+    pub fn commit_vote(ctx: Context<CommitVote>, commitment: [u8; 32]) -> Result<()> {
+        ctx.accounts.commit_vote(commitment)
+    }
+
+    /// Casts a vote split across options, in basis points of the escrow's voting power.
+    #[access_control(ctx.accounts.validate())]
+    pub fn cast_split_vote(ctx: Context<CastVote>, split_bps: Vec<u16>) -> Result<()> {
+        ctx.accounts.cast_split_vote(split_bps)
+    }
+
+    /// Casts a vote on an approval proposal, selecting the options in the `selections` bitmask.
+    #[access_control(ctx.accounts.validate())]
+    pub fn cast_approval_vote(ctx: Context<CastVote>, selections: u16) -> Result<()> {
+        ctx.accounts.cast_approval_vote(selections)
+    }
+
+    /// Claims the voting rewards of an escrow owner on their behalf. Only callable by the vote delegate,
+    /// and the rewards are always paid to the owner's associated token accounts.
+    #[access_control(ctx.accounts.validate())]
+    pub fn claim_reward<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ClaimReward<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.claim_reward(ctx.remaining_accounts)
+    }
+
     /// Delegate escrow vote.
     #[access_control(ctx.accounts.validate())]
     pub fn set_vote_delegate(ctx: Context<SetVoteDelegate>, new_delegate: Pubkey) -> Result<()> {
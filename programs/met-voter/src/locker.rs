@@ -20,6 +20,15 @@ fn get_unix_timestamp() -> Result<i64> {
 }
 
 impl Locker {
+    /// Calculates the voting power of the [Locker::locked_supply], as if it were max locked.
+    ///
+    /// This is an upper bound of the voting power of all [Escrow]s: it also counts tokens of
+    /// escrows that are not max locked or whose lockup has expired.
+    pub fn locked_voting_power(&self) -> Option<u64> {
+        self.locked_supply
+            .checked_mul(self.params.max_stake_vote_multiplier.into())
+    }
+
     pub fn get_current_phase(&self) -> Result<Phase> {
         let now = get_unix_timestamp()?;
         if self.expiration > now {
@@ -0,0 +1,62 @@
+use crate::*;
+
+/// Accounts for [smart_wallet::cancel_transaction].
+#[derive(Accounts)]
+pub struct CancelTransaction<'info> {
+    /// The [SmartWallet].
+    pub smart_wallet: Account<'info, SmartWallet>,
+    /// The [Transaction].
+    #[account(
+        mut,
+        has_one = smart_wallet,
+        has_one = proposer,
+        close = receiver,
+    )]
+    pub transaction: Account<'info, Transaction>,
+    /// Must be proposer of the transaction, and a program address signing through CPI, e.g. a governor.
+    pub proposer: Signer<'info>,
+    /// Receiver of the rent of the [Transaction].
+    /// CHECK: Arbitrary account
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+}
+
+impl<'info> CancelTransaction<'info> {
+    pub fn cancel_transaction(&mut self) -> Result<()> {
+        emit!(TransactionCancelEvent {
+            smart_wallet: self.smart_wallet.key(),
+            transaction: self.transaction.key(),
+            proposer: self.proposer.key(),
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for CancelTransaction<'info> {
+    fn validate(&self) -> Result<()> {
+        // transactions proposed by an owner keypair can only be removed before any approval,
+        // with [smart_wallet::remove_transaction].
+        invariant!(
+            !self.proposer.key().is_on_curve(),
+            ProposerNotProgramAddress
+        );
+        invariant!(self.transaction.executed_at == -1, AlreadyExecuted);
+        Ok(())
+    }
+}
+
+/// Emitted when a [Transaction] is canceled by its proposer.
+#[event]
+pub struct TransactionCancelEvent {
+    /// The [SmartWallet].
+    #[index]
+    pub smart_wallet: Pubkey,
+    /// The [Transaction].
+    #[index]
+    pub transaction: Pubkey,
+    /// The proposer of the transaction.
+    pub proposer: Pubkey,
+    /// The Unix timestamp when the event was emitted.
+    pub timestamp: i64,
+}
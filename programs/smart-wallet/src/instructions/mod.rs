@@ -1,5 +1,6 @@
 pub mod approve;
 pub mod auth;
+pub mod cancel_transaction;
 pub mod create_smart_wallet;
 pub mod create_subaccount_info;
 pub mod create_transaction;
@@ -9,6 +10,7 @@ pub mod remove_transaction;
 
 pub use approve::*;
 pub use auth::*;
+pub use cancel_transaction::*;
 pub use create_smart_wallet::*;
 pub use create_subaccount_info::*;
 pub use create_transaction::*;
@@ -103,6 +103,13 @@ pub mod smart_wallet {
         ctx.accounts.remove_transaction()
     }
 
+    /// Cancels a [Transaction] that has not been executed, regardless of its approvals.
+    /// Only callable by the proposer of the [Transaction], if it is a program address such as a governor.
+    #[access_control(ctx.accounts.validate())]
+    pub fn cancel_transaction(ctx: Context<CancelTransaction>) -> Result<()> {
+        ctx.accounts.cancel_transaction()
+    }
+
     /// Creates a new [Transaction] account with time delay.
     #[access_control(ctx.accounts.validate())]
     pub fn create_transaction_with_timelock(
@@ -240,4 +247,6 @@ pub enum ErrorCode {
     SubaccountOwnerMismatch,
     #[msg("Number of signers is not zero.")]
     NumSignerIsNotZero,
+    #[msg("Only transactions proposed by a program address can be canceled.")]
+    ProposerNotProgramAddress,
 }
@@ -0,0 +1,272 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Wallet, web3 } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, createMint, mintTo } from "@solana/spl-token";
+import {
+    GOVERN_PROGRAM_ID,
+    LOCKED_VOTER_PROGRAM_ID,
+    VoteSide,
+    castVoteWithEscrow,
+    createGovernProgram,
+    createLockedVoterGovernance,
+    createLockedVoterProgram,
+    createMaxLockedEscrow,
+    createProposal,
+    createSetLockerParamsInstruction,
+    deriveVote,
+    getOrCreateATA,
+    invokeAndAssertError,
+    setVotingReward,
+    waitUntilOnChainTime,
+} from "../utils";
+import { expect } from "chai";
+
+const provider = anchor.AnchorProvider.env();
+
+const proposalCount = 2;
+const lockAmount = new BN(100);
+
+describe("Claim rewards batch", () => {
+    let locker: web3.PublicKey;
+    let govern: web3.PublicKey;
+    let smartWallet: web3.PublicKey;
+    let proposals: web3.PublicKey[] = [];
+
+    let wallet: Wallet;
+    let keypair: web3.Keypair;
+
+    let voterWallet: Wallet;
+    let voterEscrow: web3.PublicKey;
+
+    let votingRewardMint: web3.PublicKey;
+    let rewardVault: web3.PublicKey;
+
+    // Govern config
+    const votingPeriod: BN = new BN(5); // 5 seconds duration of voting on proposal
+    const quorumVotes: BN = new BN(2); // 2 vote to pass
+
+    // Voter config
+    const lockerParams = {
+        maxStakeDuration: new BN(20), // 20 seconds
+        maxStakeVoteMultiplier: 1,
+        minStakeDuration: new BN(10), // 10 seconds
+        proposalActivationMinVotes: new BN(2), // min 2 vote to activate proposal
+    };
+
+    const rewardPerProposal = new BN(100000);
+
+    function claimRewardsBatch(
+        voteAccounts: web3.PublicKey[],
+        vaultAccounts: web3.PublicKey[]
+    ) {
+        const governProgram = createGovernProgram(voterWallet, GOVERN_PROGRAM_ID);
+        return governProgram.methods
+            .claimRewardsBatch(voteAccounts.length / 2)
+            .accounts({
+                governor: govern,
+                voter: voterWallet.publicKey,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .remainingAccounts(
+                [...voteAccounts, ...vaultAccounts].map((pubkey) => {
+                    return {
+                        isSigner: false,
+                        isWritable: true,
+                        pubkey,
+                    };
+                })
+            )
+            .rpc();
+    }
+
+    function getVoteAccounts() {
+        return proposals.flatMap((proposal) => {
+            const [vote, _bump] = deriveVote(voterWallet.publicKey, proposal);
+            return [proposal, vote];
+        });
+    }
+
+    before(async () => {
+        const result = await createLockedVoterGovernance(
+            votingPeriod,
+            quorumVotes,
+            new BN(0),
+            lockerParams,
+            provider.connection
+        );
+        keypair = result.keypair;
+        wallet = result.wallet;
+        locker = result.locker;
+        govern = result.governor;
+        smartWallet = result.smartWallet;
+
+        const voter = await createMaxLockedEscrow(
+            locker,
+            result.tokenMint,
+            lockAmount,
+            keypair,
+            provider.connection
+        );
+        voterWallet = voter.wallet;
+        voterEscrow = voter.escrow;
+
+        votingRewardMint = await createMint(
+            provider.connection,
+            keypair,
+            keypair.publicKey,
+            null,
+            9
+        );
+        rewardVault = await setVotingReward(
+            govern,
+            smartWallet,
+            votingRewardMint,
+            rewardPerProposal,
+            keypair,
+            wallet
+        );
+        // fund the reward of the remaining proposals
+        await mintTo(
+            provider.connection,
+            keypair,
+            votingRewardMint,
+            rewardVault,
+            keypair.publicKey,
+            rewardPerProposal.muln(proposalCount - 1).toNumber()
+        );
+    });
+
+    it("user votes for many proposals", async () => {
+        const governProgram = createGovernProgram(voterWallet, GOVERN_PROGRAM_ID);
+        const voterProgram = createLockedVoterProgram(voterWallet, LOCKED_VOTER_PROGRAM_ID);
+
+        for (let i = 0; i < proposalCount; i++) {
+            const proposal = await createProposal(
+                govern,
+                [
+                    createSetLockerParamsInstruction(
+                        locker,
+                        govern,
+                        smartWallet,
+                        lockerParams,
+                        voterProgram
+                    ),
+                ],
+                governProgram
+            );
+
+            await voterProgram.methods
+                .activateProposalWithEscrow()
+                .accounts({
+                    locker,
+                    governor: govern,
+                    proposal,
+                    escrow: voterEscrow,
+                    authority: voterWallet.publicKey,
+                    governProgram: GOVERN_PROGRAM_ID,
+                })
+                .rpc();
+
+            await castVoteWithEscrow(
+                locker,
+                govern,
+                proposal,
+                voterEscrow,
+                VoteSide.For,
+                voterProgram,
+                governProgram
+            );
+            proposals.push(proposal);
+        }
+    });
+
+    it("cannot batch claim without the reward vault of the proposals", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const proposalState = await governProgram.account.proposal.fetch(
+            proposals[proposalCount - 1]
+        );
+        await waitUntilOnChainTime(
+            proposalState.votingEndsAt.toNumber() + 1,
+            provider.connection
+        );
+
+        const otherMint = await createMint(
+            provider.connection,
+            keypair,
+            keypair.publicKey,
+            null,
+            9
+        );
+        const otherVault = await getOrCreateATA(
+            otherMint,
+            govern,
+            keypair,
+            provider.connection
+        );
+        const voterTokenAccount = await getOrCreateATA(
+            otherMint,
+            voterWallet.publicKey,
+            keypair,
+            provider.connection
+        );
+
+        await invokeAndAssertError(
+            () => {
+                return claimRewardsBatch(getVoteAccounts(), [
+                    otherVault,
+                    voterTokenAccount,
+                ]);
+            },
+            "Invariant failed: missing reward vault",
+            false
+        );
+    });
+
+    it("user claims rewards of all proposals in one instruction", async () => {
+        const voterTokenAccount = await getOrCreateATA(
+            votingRewardMint,
+            voterWallet.publicKey,
+            keypair,
+            provider.connection
+        );
+
+        await claimRewardsBatch(getVoteAccounts(), [rewardVault, voterTokenAccount]);
+
+        // the user is the only voter of every proposal
+        const balance = await provider.connection
+            .getTokenAccountBalance(voterTokenAccount)
+            .then((b) => b.value.amount);
+        expect(balance).to.be.equal(rewardPerProposal.muln(proposalCount).toString());
+
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        for (const proposal of proposals) {
+            const [vote, _bump] = deriveVote(voterWallet.publicKey, proposal);
+            const voteState = await governProgram.account.vote.fetch(vote);
+            expect(voteState.claimed).to.be.true;
+
+            const proposalState = await governProgram.account.proposal.fetch(proposal);
+            expect(proposalState.totalClaimedReward.toString()).to.be.equal(
+                rewardPerProposal.toString()
+            );
+        }
+    });
+
+    it("cannot batch claim rewards twice", async () => {
+        const voterTokenAccount = await getOrCreateATA(
+            votingRewardMint,
+            voterWallet.publicKey,
+            keypair,
+            provider.connection
+        );
+
+        await invokeAndAssertError(
+            () => {
+                return claimRewardsBatch(getVoteAccounts(), [
+                    rewardVault,
+                    voterTokenAccount,
+                ]);
+            },
+            "Invariant failed: Voter has claimed reward",
+            false
+        );
+    });
+});
@@ -0,0 +1,256 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Wallet, web3 } from "@coral-xyz/anchor";
+import {
+    GOVERN_PROGRAM_ID,
+    LOCKED_VOTER_PROGRAM_ID,
+    SMART_WALLET_PROGRAM_ID,
+    VoteSide,
+    castVoteWithEscrow,
+    createGovernProgram,
+    createLockedVoterGovernance,
+    createLockedVoterProgram,
+    createMaxLockedEscrow,
+    createProposal,
+    createProposalMeta,
+    createSetLockerParamsInstruction,
+    createSmartWalletProgram,
+    deriveProposalBond,
+    deriveProposalRewards,
+    deriveVote,
+    invokeAndAssertError,
+    queueProposal,
+    waitUntilOnChainTime,
+} from "../utils";
+import { expect } from "chai";
+
+const provider = anchor.AnchorProvider.env();
+
+const voterCount = 2;
+const lockAmount = new BN(100);
+
+describe("Close vote and proposal", () => {
+    let locker: web3.PublicKey;
+    let govern: web3.PublicKey;
+    let smartWallet: web3.PublicKey;
+    let proposal: web3.PublicKey;
+
+    let wallet: Wallet;
+    let keypair: web3.Keypair;
+
+    let voterWallets: Wallet[] = [];
+    let voterEscrows: web3.PublicKey[] = [];
+
+    // Govern config
+    const votingPeriod: BN = new BN(5); // 5 seconds duration of voting on proposal
+    const quorumVotes: BN = new BN(2); // 2 vote to pass
+
+    // Voter config
+    const lockerParams = {
+        maxStakeDuration: new BN(20), // 20 seconds
+        maxStakeVoteMultiplier: 1,
+        minStakeDuration: new BN(10), // 10 seconds
+        proposalActivationMinVotes: new BN(2), // min 2 vote to activate proposal
+    };
+
+    async function createSetLockerParamsProposal(proposer: Wallet) {
+        const governProgram = createGovernProgram(proposer, GOVERN_PROGRAM_ID);
+        const voterProgram = createLockedVoterProgram(proposer, LOCKED_VOTER_PROGRAM_ID);
+        return createProposal(
+            govern,
+            [
+                createSetLockerParamsInstruction(
+                    locker,
+                    govern,
+                    smartWallet,
+                    lockerParams,
+                    voterProgram
+                ),
+            ],
+            governProgram
+        );
+    }
+
+    function closeVote(
+        voterWallet: Wallet,
+        queuedTransaction: web3.PublicKey | null
+    ) {
+        const governProgram = createGovernProgram(voterWallet, GOVERN_PROGRAM_ID);
+        const [vote, _vBump] = deriveVote(voterWallet.publicKey, proposal);
+        const [proposalRewards, _rBump] = deriveProposalRewards(proposal);
+        return governProgram.methods
+            .closeVote()
+            .accounts({
+                governor: govern,
+                proposal,
+                vote,
+                voteCommitment: null,
+                proposalRewards,
+                queuedTransaction,
+                voter: voterWallet.publicKey,
+            })
+            .rpc();
+    }
+
+    before(async () => {
+        const result = await createLockedVoterGovernance(
+            votingPeriod,
+            quorumVotes,
+            new BN(0),
+            lockerParams,
+            provider.connection
+        );
+        keypair = result.keypair;
+        wallet = result.wallet;
+        locker = result.locker;
+        govern = result.governor;
+        smartWallet = result.smartWallet;
+
+        for (let i = 0; i < voterCount; i++) {
+            const voter = await createMaxLockedEscrow(
+                locker,
+                result.tokenMint,
+                lockAmount,
+                keypair,
+                provider.connection
+            );
+            voterWallets.push(voter.wallet);
+            voterEscrows.push(voter.escrow);
+        }
+
+        proposal = await createSetLockerParamsProposal(wallet);
+    });
+
+    it("users vote for proposal", async () => {
+        const voterProgram = createLockedVoterProgram(
+            voterWallets[0],
+            LOCKED_VOTER_PROGRAM_ID
+        );
+        await voterProgram.methods
+            .activateProposalWithEscrow()
+            .accounts({
+                locker,
+                governor: govern,
+                proposal,
+                escrow: voterEscrows[0],
+                authority: voterWallets[0].publicKey,
+                governProgram: GOVERN_PROGRAM_ID,
+            })
+            .rpc();
+
+        for (let i = 0; i < voterCount; i++) {
+            await castVoteWithEscrow(
+                locker,
+                govern,
+                proposal,
+                voterEscrows[i],
+                VoteSide.For,
+                createLockedVoterProgram(voterWallets[i], LOCKED_VOTER_PROGRAM_ID),
+                createGovernProgram(voterWallets[i], GOVERN_PROGRAM_ID)
+            );
+        }
+    });
+
+    it("cannot close vote while voting is active", async () => {
+        await invokeAndAssertError(
+            () => {
+                return closeVote(voterWallets[0], null);
+            },
+            "The proposal must be final.",
+            true
+        );
+    });
+
+    it("user closes vote of a succeeded proposal", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const proposalState = await governProgram.account.proposal.fetch(proposal);
+        await waitUntilOnChainTime(
+            proposalState.votingEndsAt.toNumber() + 1,
+            provider.connection
+        );
+
+        await closeVote(voterWallets[0], null);
+
+        const [vote, _bump] = deriveVote(voterWallets[0].publicKey, proposal);
+        const voteAccount = await provider.connection.getAccountInfo(vote);
+        expect(voteAccount).to.be.null;
+    });
+
+    it("user closes vote of a proposal queued without ETA", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+
+        const transaction = await queueProposal(
+            govern,
+            proposal,
+            governProgram,
+            createSmartWalletProgram(wallet, SMART_WALLET_PROGRAM_ID)
+        );
+
+        // the queued transaction must be given to prove it cannot expire
+        await invokeAndAssertError(
+            () => {
+                return closeVote(voterWallets[1], null);
+            },
+            "The proposal must be final.",
+            true
+        );
+
+        await closeVote(voterWallets[1], transaction);
+
+        const [vote, _bump] = deriveVote(voterWallets[1].publicKey, proposal);
+        const voteAccount = await provider.connection.getAccountInfo(vote);
+        expect(voteAccount).to.be.null;
+    });
+
+    it("proposer closes canceled proposal and its meta", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const canceledProposal = await createSetLockerParamsProposal(wallet);
+        const proposalMeta = await createProposalMeta(
+            canceledProposal,
+            "Canceled proposal",
+            "Canceled proposal test",
+            governProgram
+        );
+        const [proposalBond, _bump] = deriveProposalBond(canceledProposal);
+        const [proposalRewards] = deriveProposalRewards(canceledProposal);
+
+        const closeProposal = () => {
+            return governProgram.methods
+                .closeProposal()
+                .accounts({
+                    proposal: canceledProposal,
+                    proposalMeta,
+                    optionProposalMeta: null,
+                    proposalBond,
+                    proposalRewards,
+                    proposer: wallet.publicKey,
+                })
+                .rpc();
+        };
+
+        await invokeAndAssertError(
+            closeProposal,
+            "Invariant failed: proposal must be canceled",
+            false
+        );
+
+        await governProgram.methods
+            .cancelProposal()
+            .accounts({
+                governor: govern,
+                proposal: canceledProposal,
+                proposer: wallet.publicKey,
+            })
+            .rpc();
+
+        await closeProposal();
+
+        const proposalAccount = await provider.connection.getAccountInfo(
+            canceledProposal
+        );
+        const proposalMetaAccount = await provider.connection.getAccountInfo(
+            proposalMeta
+        );
+        expect(proposalAccount).to.be.null;
+        expect(proposalMetaAccount).to.be.null;
+    });
+});
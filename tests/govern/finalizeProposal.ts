@@ -0,0 +1,223 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Wallet, web3 } from "@coral-xyz/anchor";
+import {
+    GOVERN_PROGRAM_ID,
+    LOCKED_VOTER_PROGRAM_ID,
+    SMART_WALLET_PROGRAM_ID,
+    VoteSide,
+    castVoteWithEscrow,
+    createGovernProgram,
+    createLockedVoterGovernance,
+    createLockedVoterProgram,
+    createMaxLockedEscrow,
+    createProposal,
+    createSetLockerParamsInstruction,
+    createSmartWalletProgram,
+    invokeAndAssertError,
+    queueProposal,
+    waitUntilOnChainTime,
+} from "../utils";
+import { expect } from "chai";
+
+const provider = anchor.AnchorProvider.env();
+
+const lockAmount = new BN(100);
+
+describe("Finalize proposal", () => {
+    let locker: web3.PublicKey;
+    let govern: web3.PublicKey;
+    let smartWallet: web3.PublicKey;
+    let proposal: web3.PublicKey;
+    let transaction: web3.PublicKey;
+
+    let wallet: Wallet;
+    let keypair: web3.Keypair;
+
+    let voterWallet: Wallet;
+    let voterEscrow: web3.PublicKey;
+
+    // Govern config
+    const votingPeriod: BN = new BN(5); // 5 seconds duration of voting on proposal
+    const quorumVotes: BN = new BN(2); // 2 vote to pass
+
+    // Voter config
+    const lockerParams = {
+        maxStakeDuration: new BN(20), // 20 seconds
+        maxStakeVoteMultiplier: 1,
+        minStakeDuration: new BN(10), // 10 seconds
+        proposalActivationMinVotes: new BN(2), // min 2 vote to activate proposal
+    };
+
+    function finalizeProposal() {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        return governProgram.methods
+            .finalizeProposal()
+            .accounts({
+                governor: govern,
+                proposal,
+                transaction,
+                smartWallet,
+            })
+            .rpc();
+    }
+
+    before(async () => {
+        const result = await createLockedVoterGovernance(
+            votingPeriod,
+            quorumVotes,
+            new BN(0),
+            lockerParams,
+            provider.connection
+        );
+        keypair = result.keypair;
+        wallet = result.wallet;
+        locker = result.locker;
+        govern = result.governor;
+        smartWallet = result.smartWallet;
+
+        const voter = await createMaxLockedEscrow(
+            locker,
+            result.tokenMint,
+            lockAmount,
+            keypair,
+            provider.connection
+        );
+        voterWallet = voter.wallet;
+        voterEscrow = voter.escrow;
+
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const voterProgram = createLockedVoterProgram(wallet, LOCKED_VOTER_PROGRAM_ID);
+        proposal = await createProposal(
+            govern,
+            [
+                createSetLockerParamsInstruction(
+                    locker,
+                    govern,
+                    smartWallet,
+                    lockerParams,
+                    voterProgram
+                ),
+            ],
+            governProgram
+        );
+    });
+
+    it("voter passes and queues proposal", async () => {
+        const voterProgram = createLockedVoterProgram(voterWallet, LOCKED_VOTER_PROGRAM_ID);
+        const governProgram = createGovernProgram(voterWallet, GOVERN_PROGRAM_ID);
+
+        await voterProgram.methods
+            .activateProposalWithEscrow()
+            .accounts({
+                locker,
+                governor: govern,
+                proposal,
+                escrow: voterEscrow,
+                authority: voterWallet.publicKey,
+                governProgram: GOVERN_PROGRAM_ID,
+            })
+            .rpc();
+
+        await castVoteWithEscrow(
+            locker,
+            govern,
+            proposal,
+            voterEscrow,
+            VoteSide.For,
+            voterProgram,
+            governProgram
+        );
+
+        let proposalState = await governProgram.account.proposal.fetch(proposal);
+        await waitUntilOnChainTime(
+            proposalState.votingEndsAt.toNumber() + 1,
+            provider.connection
+        );
+
+        transaction = await queueProposal(
+            govern,
+            proposal,
+            governProgram,
+            createSmartWalletProgram(voterWallet, SMART_WALLET_PROGRAM_ID)
+        );
+
+        proposalState = await governProgram.account.proposal.fetch(proposal);
+        expect(proposalState.queuedTransaction.toBase58()).to.be.equal(
+            transaction.toBase58()
+        );
+        expect(proposalState.executedAt.toString()).to.be.equal("0");
+        expect(proposalState.expiredAt.toString()).to.be.equal("0");
+    });
+
+    it("cannot finalize proposal before the queued transaction is executed", async () => {
+        await invokeAndAssertError(
+            () => {
+                return finalizeProposal();
+            },
+            "The queued transaction has neither been executed nor expired.",
+            true
+        );
+    });
+
+    it("finalizes proposal once the queued transaction is executed", async () => {
+        const smartWalletProgram = createSmartWalletProgram(
+            wallet,
+            SMART_WALLET_PROGRAM_ID
+        );
+        const voterProgram = createLockedVoterProgram(wallet, LOCKED_VOTER_PROGRAM_ID);
+        const setLockerParamsIx = createSetLockerParamsInstruction(
+            locker,
+            govern,
+            smartWallet,
+            lockerParams,
+            voterProgram
+        );
+
+        await smartWalletProgram.methods
+            .executeTransaction()
+            .accounts({
+                owner: smartWalletProgram.provider.publicKey,
+                smartWallet,
+                transaction,
+            })
+            .remainingAccounts([
+                {
+                    isSigner: false,
+                    isWritable: false,
+                    pubkey: setLockerParamsIx.programId,
+                },
+                ...setLockerParamsIx.keys.map((x) => {
+                    return {
+                        ...x,
+                        isSigner: false, // Need to override to false as these are signed by the smart wallet
+                    };
+                }),
+            ])
+            .rpc();
+
+        await finalizeProposal();
+
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const transactionState = await smartWalletProgram.account.transaction.fetch(
+            transaction
+        );
+        const proposalState = await governProgram.account.proposal.fetch(proposal);
+        expect(proposalState.executedAt.toString()).to.be.equal(
+            transactionState.executedAt.toString()
+        );
+        expect(proposalState.expiredAt.toString()).to.be.equal("0");
+    });
+
+    it("cannot finalize an executed proposal again", async () => {
+        await invokeAndAssertError(
+            () => {
+                return finalizeProposal();
+            },
+            "The proposal must be queued.",
+            true
+        );
+    });
+
+    // Expiring a queued transaction requires waiting for the smart wallet grace period,
+    // which cannot be shortened in a test; it is covered by `test_executed_and_expired_states`.
+});
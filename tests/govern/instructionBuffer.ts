@@ -0,0 +1,246 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Wallet, web3 } from "@coral-xyz/anchor";
+import {
+    GOVERN_PROGRAM_ID,
+    LOCKED_VOTER_PROGRAM_ID,
+    SMART_WALLET_PROGRAM_ID,
+    VoteSide,
+    castVoteWithEscrow,
+    createAndFundWallet,
+    createGovernProgram,
+    createLockedVoterGovernance,
+    createLockedVoterProgram,
+    createMaxLockedEscrow,
+    createSetLockerParamsInstruction,
+    createSmartWalletProgram,
+    deriveInstructionBuffer,
+    deriveProposal,
+    invokeAndAssertError,
+    queueProposal,
+    waitUntilOnChainTime,
+} from "../utils";
+import { expect } from "chai";
+
+const provider = anchor.AnchorProvider.env();
+
+const lockAmount = new BN(100);
+
+describe("Instruction buffer", () => {
+    let locker: web3.PublicKey;
+    let govern: web3.PublicKey;
+    let smartWallet: web3.PublicKey;
+    let proposal: web3.PublicKey;
+    let instructionBuffer: web3.PublicKey;
+
+    let wallet: Wallet;
+    let keypair: web3.Keypair;
+
+    let voterWallet: Wallet;
+    let voterEscrow: web3.PublicKey;
+
+    // Govern config
+    const votingPeriod: BN = new BN(5); // 5 seconds duration of voting on proposal
+    const quorumVotes: BN = new BN(2); // 2 vote to pass
+
+    // Voter config
+    const lockerParams = {
+        maxStakeDuration: new BN(20), // 20 seconds
+        maxStakeVoteMultiplier: 1,
+        minStakeDuration: new BN(10), // 10 seconds
+        proposalActivationMinVotes: new BN(2), // min 2 vote to activate proposal
+    };
+
+    // instructions appended to the buffer in separate transactions
+    const appendCount = 2;
+
+    function getSetLockerParamsInstruction() {
+        const voterProgram = createLockedVoterProgram(wallet, LOCKED_VOTER_PROGRAM_ID);
+        return createSetLockerParamsInstruction(
+            locker,
+            govern,
+            smartWallet,
+            lockerParams,
+            voterProgram
+        );
+    }
+
+    function createInstructionBuffer() {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        return governProgram.methods
+            .createInstructionBuffer()
+            .accounts({
+                governor: govern,
+                instructionBuffer,
+                proposer: wallet.publicKey,
+                payer: wallet.publicKey,
+                systemProgram: web3.SystemProgram.programId,
+            })
+            .rpc();
+    }
+
+    function appendInstructionBuffer(proposer: Wallet) {
+        const governProgram = createGovernProgram(proposer, GOVERN_PROGRAM_ID);
+        return governProgram.methods
+            .appendInstructionBuffer([getSetLockerParamsInstruction()])
+            .accounts({
+                instructionBuffer,
+                proposer: proposer.publicKey,
+                payer: proposer.publicKey,
+                systemProgram: web3.SystemProgram.programId,
+            })
+            .rpc();
+    }
+
+    before(async () => {
+        const result = await createLockedVoterGovernance(
+            votingPeriod,
+            quorumVotes,
+            new BN(0),
+            lockerParams,
+            provider.connection
+        );
+        keypair = result.keypair;
+        wallet = result.wallet;
+        locker = result.locker;
+        govern = result.governor;
+        smartWallet = result.smartWallet;
+
+        const voter = await createMaxLockedEscrow(
+            locker,
+            result.tokenMint,
+            lockAmount,
+            keypair,
+            provider.connection
+        );
+        voterWallet = voter.wallet;
+        voterEscrow = voter.escrow;
+
+        [instructionBuffer] = deriveInstructionBuffer(govern, wallet.publicKey);
+    });
+
+    it("proposer appends instructions to the buffer across transactions", async () => {
+        await createInstructionBuffer();
+        for (let i = 0; i < appendCount; i++) {
+            await appendInstructionBuffer(wallet);
+        }
+
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const instructionBufferState =
+            await governProgram.account.instructionBuffer.fetch(instructionBuffer);
+        expect(instructionBufferState.proposer.toBase58()).to.be.equal(
+            wallet.publicKey.toBase58()
+        );
+        expect(instructionBufferState.instructions.length).to.be.equal(appendCount);
+    });
+
+    it("only proposer can append to the buffer", async () => {
+        const { wallet: otherWallet } = await createAndFundWallet(provider.connection);
+
+        await invokeAndAssertError(
+            () => {
+                return appendInstructionBuffer(otherWallet);
+            },
+            "A has one constraint was violated",
+            true
+        );
+    });
+
+    it("proposer creates proposal consuming the buffer", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const governState = await governProgram.account.governor.fetch(govern);
+        [proposal] = deriveProposal(govern, governState.proposalCount);
+
+        await governProgram.methods
+            .createProposal(0, 2, [getSetLockerParamsInstruction()])
+            .accounts({
+                governor: govern,
+                instructionBuffer,
+                proposal,
+                smartWallet,
+                proposer: wallet.publicKey,
+                payer: wallet.publicKey,
+                systemProgram: web3.SystemProgram.programId,
+                proposalBond: null,
+                bondVault: null,
+                proposerBondAccount: null,
+                tokenProgram: null,
+            })
+            .rpc();
+
+        // the buffered instructions follow the ones passed to create_proposal
+        const proposalState = await governProgram.account.proposal.fetch(proposal);
+        expect(proposalState.instructions.length).to.be.equal(appendCount + 1);
+
+        const instructionBufferAccount = await provider.connection.getAccountInfo(
+            instructionBuffer
+        );
+        expect(instructionBufferAccount).to.be.null;
+    });
+
+    it("proposal with buffered instructions is queued as one transaction", async () => {
+        const voterProgram = createLockedVoterProgram(voterWallet, LOCKED_VOTER_PROGRAM_ID);
+        const governProgram = createGovernProgram(voterWallet, GOVERN_PROGRAM_ID);
+        await voterProgram.methods
+            .activateProposalWithEscrow()
+            .accounts({
+                locker,
+                governor: govern,
+                proposal,
+                escrow: voterEscrow,
+                authority: voterWallet.publicKey,
+                governProgram: GOVERN_PROGRAM_ID,
+            })
+            .rpc();
+
+        await castVoteWithEscrow(
+            locker,
+            govern,
+            proposal,
+            voterEscrow,
+            VoteSide.For,
+            voterProgram,
+            governProgram
+        );
+
+        const proposalState = await governProgram.account.proposal.fetch(proposal);
+        await waitUntilOnChainTime(
+            proposalState.votingEndsAt.toNumber() + 1,
+            provider.connection
+        );
+
+        const smartWalletProgram = createSmartWalletProgram(
+            voterWallet,
+            SMART_WALLET_PROGRAM_ID
+        );
+        const transaction = await queueProposal(
+            govern,
+            proposal,
+            governProgram,
+            smartWalletProgram
+        );
+
+        const transactionState = await smartWalletProgram.account.transaction.fetch(
+            transaction
+        );
+        expect(transactionState.instructions.length).to.be.equal(appendCount + 1);
+    });
+
+    it("proposer closes an unused buffer", async () => {
+        await createInstructionBuffer();
+        await appendInstructionBuffer(wallet);
+
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        await governProgram.methods
+            .closeInstructionBuffer()
+            .accounts({
+                instructionBuffer,
+                proposer: wallet.publicKey,
+            })
+            .rpc();
+
+        const instructionBufferAccount = await provider.connection.getAccountInfo(
+            instructionBuffer
+        );
+        expect(instructionBufferAccount).to.be.null;
+    });
+});
@@ -0,0 +1,261 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Wallet, web3 } from "@coral-xyz/anchor";
+import {
+    GOVERN_PROGRAM_ID,
+    LOCKED_VOTER_PROGRAM_ID,
+    SMART_WALLET_PROGRAM_ID,
+    VoteSide,
+    castVoteWithEscrow,
+    createGovernProgram,
+    createGovernorInstruction,
+    createLockedVoterGovernance,
+    createLockedVoterProgram,
+    createMaxLockedEscrow,
+    createSetLockerParamsInstruction,
+    createSmartWalletProgram,
+    deriveProposal,
+    invokeAndAssertError,
+    invokeSmartWalletInstruction,
+    queueProposal,
+    waitUntilOnChainTime,
+} from "../utils";
+import { expect } from "chai";
+
+const provider = anchor.AnchorProvider.env();
+
+// ProposalType::Optimistic
+const OPTIMISTIC_PROPOSAL_TYPE = 3;
+
+describe("Optimistic proposal", () => {
+    let locker: web3.PublicKey;
+    let govern: web3.PublicKey;
+    let smartWallet: web3.PublicKey;
+
+    let wallet: Wallet;
+    let keypair: web3.Keypair;
+
+    let voterWallets: Wallet[] = [];
+    let voterEscrows: web3.PublicKey[] = [];
+
+    // proposals without votes, with Against votes at the veto threshold, and above it
+    let unopposedProposal: web3.PublicKey;
+    let opposedProposal: web3.PublicKey;
+    let vetoedProposal: web3.PublicKey;
+
+    // Govern config
+    const votingPeriod: BN = new BN(5); // 5 seconds duration of voting on proposal
+    const quorumVotes: BN = new BN(1000); // not reached by any proposal
+    const optimisticVetoVotes: BN = new BN(100); // Against votes must exceed 100 to veto
+
+    // Voter config
+    const lockerParams = {
+        maxStakeDuration: new BN(20), // 20 seconds
+        maxStakeVoteMultiplier: 1,
+        minStakeDuration: new BN(10), // 10 seconds
+        proposalActivationMinVotes: new BN(2), // min 2 vote to activate proposal
+    };
+
+    // Max locked escrows have a voting power of amount * maxStakeVoteMultiplier
+    const lockAmounts = [optimisticVetoVotes, new BN(50)];
+
+    async function createOptimisticProposal() {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const voterProgram = createLockedVoterProgram(wallet, LOCKED_VOTER_PROGRAM_ID);
+        const governState = await governProgram.account.governor.fetch(govern);
+        const [proposal, _bump] = deriveProposal(govern, governState.proposalCount);
+
+        await governProgram.methods
+            .createProposal(OPTIMISTIC_PROPOSAL_TYPE, 2, [
+                createSetLockerParamsInstruction(
+                    locker,
+                    govern,
+                    smartWallet,
+                    lockerParams,
+                    voterProgram
+                ),
+            ])
+            .accounts({
+                governor: govern,
+                instructionBuffer: null,
+                proposal,
+                smartWallet,
+                proposer: wallet.publicKey,
+                payer: wallet.publicKey,
+                systemProgram: web3.SystemProgram.programId,
+                proposalBond: null,
+                bondVault: null,
+                proposerBondAccount: null,
+                tokenProgram: null,
+            })
+            .rpc();
+
+        return proposal;
+    }
+
+    async function activateProposal(proposal: web3.PublicKey) {
+        await createLockedVoterProgram(voterWallets[0], LOCKED_VOTER_PROGRAM_ID)
+            .methods.activateProposalWithEscrow()
+            .accounts({
+                locker,
+                governor: govern,
+                proposal,
+                escrow: voterEscrows[0],
+                authority: voterWallets[0].publicKey,
+                governProgram: GOVERN_PROGRAM_ID,
+            })
+            .rpc();
+    }
+
+    function voteAgainst(proposal: web3.PublicKey, voterIndex: number) {
+        return castVoteWithEscrow(
+            locker,
+            govern,
+            proposal,
+            voterEscrows[voterIndex],
+            VoteSide.Against,
+            createLockedVoterProgram(voterWallets[voterIndex], LOCKED_VOTER_PROGRAM_ID),
+            createGovernProgram(voterWallets[voterIndex], GOVERN_PROGRAM_ID)
+        );
+    }
+
+    function queue(proposal: web3.PublicKey) {
+        return queueProposal(
+            govern,
+            proposal,
+            createGovernProgram(wallet, GOVERN_PROGRAM_ID),
+            createSmartWalletProgram(wallet, SMART_WALLET_PROGRAM_ID)
+        );
+    }
+
+    before(async () => {
+        const result = await createLockedVoterGovernance(
+            votingPeriod,
+            quorumVotes,
+            new BN(0),
+            lockerParams,
+            provider.connection
+        );
+        keypair = result.keypair;
+        wallet = result.wallet;
+        locker = result.locker;
+        govern = result.governor;
+        smartWallet = result.smartWallet;
+
+        for (const lockAmount of lockAmounts) {
+            const voter = await createMaxLockedEscrow(
+                locker,
+                result.tokenMint,
+                lockAmount,
+                keypair,
+                provider.connection
+            );
+            voterWallets.push(voter.wallet);
+            voterEscrows.push(voter.escrow);
+        }
+    });
+
+    it("cannot create optimistic proposal without a veto threshold", async () => {
+        await invokeAndAssertError(
+            () => {
+                return createOptimisticProposal().then((proposal) => proposal.toBase58());
+            },
+            "Optimistic veto votes must be greater than 0.",
+            true
+        );
+    });
+
+    it("protocol team sets the veto threshold", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+
+        await invokeSmartWalletInstruction(
+            smartWallet,
+            createGovernorInstruction(
+                "set_voting_params",
+                {
+                    params: {
+                        quorumMode: 0,
+                        quorumBps: 0,
+                        approvalThresholdBps: 0,
+                        revealPeriod: new BN(0),
+                        lateQuorumExtension: new BN(0),
+                        optimisticVetoMode: 0,
+                        optimisticVetoVotes,
+                        optimisticVetoBps: 0,
+                    },
+                },
+                govern,
+                smartWallet,
+                governProgram
+            ),
+            createSmartWalletProgram(wallet, SMART_WALLET_PROGRAM_ID)
+        );
+
+        const governorState = await governProgram.account.governor.fetch(govern);
+        expect(governorState.votingParams.optimisticVetoVotes.toString()).to.be.equal(
+            optimisticVetoVotes.toString()
+        );
+    });
+
+    it("users vote against optimistic proposals", async () => {
+        unopposedProposal = await createOptimisticProposal();
+        opposedProposal = await createOptimisticProposal();
+        vetoedProposal = await createOptimisticProposal();
+
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const proposalState = await governProgram.account.proposal.fetch(
+            vetoedProposal
+        );
+        expect(proposalState.proposalType).to.be.equal(OPTIMISTIC_PROPOSAL_TYPE);
+
+        for (const proposal of [unopposedProposal, opposedProposal, vetoedProposal]) {
+            await activateProposal(proposal);
+        }
+
+        // exactly the veto threshold
+        await voteAgainst(opposedProposal, 0);
+
+        // above the veto threshold
+        await voteAgainst(vetoedProposal, 0);
+        await voteAgainst(vetoedProposal, 1);
+    });
+
+    it("optimistic proposal without votes succeeds when voting ends", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const proposalState = await governProgram.account.proposal.fetch(
+            vetoedProposal
+        );
+        await waitUntilOnChainTime(
+            proposalState.votingEndsAt.toNumber() + 1,
+            provider.connection
+        );
+
+        const transaction = await queue(unopposedProposal);
+
+        const unopposedProposalState = await governProgram.account.proposal.fetch(
+            unopposedProposal
+        );
+        expect(unopposedProposalState.queuedTransaction.toBase58()).to.be.equal(
+            transaction.toBase58()
+        );
+    });
+
+    it("optimistic proposal with Against votes at the veto threshold succeeds", async () => {
+        await queue(opposedProposal);
+
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const proposalState = await governProgram.account.proposal.fetch(
+            opposedProposal
+        );
+        expect(proposalState.queuedAt.toString()).not.equal("0");
+    });
+
+    it("optimistic proposal with Against votes above the veto threshold is defeated", async () => {
+        await invokeAndAssertError(
+            () => {
+                return queue(vetoedProposal).then((transaction) => transaction.toBase58());
+            },
+            "Invariant failed: proposal must be succeeded to be queued",
+            false
+        );
+    });
+});
@@ -0,0 +1,402 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Wallet, web3 } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, createMint, mintTo } from "@solana/spl-token";
+import {
+    GOVERN_PROGRAM_ID,
+    LOCKED_VOTER_PROGRAM_ID,
+    VoteSide,
+    castVoteWithEscrow,
+    createAndFundWallet,
+    createGovernProgram,
+    createLockedVoterGovernance,
+    createLockedVoterProgram,
+    createMaxLockedEscrow,
+    createProposal,
+    createSetLockerParamsInstruction,
+    deriveIncentiveClaim,
+    deriveIncentiveDeposit,
+    deriveOptionIncentive,
+    deriveVote,
+    getOrCreateATA,
+    invokeAndAssertError,
+    waitUntilOnChainTime,
+} from "../utils";
+import { expect } from "chai";
+
+const provider = anchor.AnchorProvider.env();
+
+const voterCount = 2;
+const lockAmount = new BN(100);
+
+describe("Option incentives", () => {
+    let locker: web3.PublicKey;
+    let govern: web3.PublicKey;
+    let smartWallet: web3.PublicKey;
+    let proposal: web3.PublicKey;
+
+    let wallet: Wallet;
+    let keypair: web3.Keypair;
+
+    let voterWallets: Wallet[] = [];
+    let voterEscrows: web3.PublicKey[] = [];
+
+    let depositorKeypair: web3.Keypair;
+    let depositorWallet: Wallet;
+    let depositorTokenAccount: web3.PublicKey;
+
+    let incentiveMint: web3.PublicKey;
+
+    // Govern config
+    const votingPeriod: BN = new BN(5); // 5 seconds duration of voting on proposal
+    const quorumVotes: BN = new BN(2); // 2 vote to pass
+
+    // Voter config
+    const lockerParams = {
+        maxStakeDuration: new BN(20), // 20 seconds
+        maxStakeVoteMultiplier: 1,
+        minStakeDuration: new BN(10), // 10 seconds
+        proposalActivationMinVotes: new BN(2), // min 2 vote to activate proposal
+    };
+
+    const incentiveAmount = new BN(10000);
+
+    function getOptionIncentiveAccounts(option: VoteSide) {
+        const [optionIncentive, _oBump] = deriveOptionIncentive(
+            proposal,
+            option,
+            incentiveMint
+        );
+        const [incentiveDeposit, _dBump] = deriveIncentiveDeposit(
+            optionIncentive,
+            depositorWallet.publicKey
+        );
+        return { optionIncentive, incentiveDeposit };
+    }
+
+    async function depositOptionIncentive(option: VoteSide) {
+        const governProgram = createGovernProgram(depositorWallet, GOVERN_PROGRAM_ID);
+        const { optionIncentive, incentiveDeposit } = getOptionIncentiveAccounts(option);
+        const vault = await getOrCreateATA(
+            incentiveMint,
+            optionIncentive,
+            depositorKeypair,
+            provider.connection
+        );
+
+        await governProgram.methods
+            .createOptionIncentive(option)
+            .accounts({
+                proposal,
+                optionIncentive,
+                mint: incentiveMint,
+                vault,
+                payer: depositorWallet.publicKey,
+                systemProgram: web3.SystemProgram.programId,
+            })
+            .rpc();
+
+        await governProgram.methods
+            .createIncentiveDeposit(depositorWallet.publicKey)
+            .accounts({
+                optionIncentive,
+                incentiveDeposit,
+                payer: depositorWallet.publicKey,
+                systemProgram: web3.SystemProgram.programId,
+            })
+            .rpc();
+
+        await governProgram.methods
+            .depositIncentive(incentiveAmount)
+            .accounts({
+                proposal,
+                optionIncentive,
+                vault,
+                incentiveDeposit,
+                depositor: depositorWallet.publicKey,
+                depositorTokenAccount,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .rpc();
+
+        return { optionIncentive, incentiveDeposit, vault };
+    }
+
+    async function claimIncentive(voterWallet: Wallet, option: VoteSide) {
+        const governProgram = createGovernProgram(voterWallet, GOVERN_PROGRAM_ID);
+        const { optionIncentive } = getOptionIncentiveAccounts(option);
+        const [vote, _vBump] = deriveVote(voterWallet.publicKey, proposal);
+        const [incentiveClaim, _cBump] = deriveIncentiveClaim(optionIncentive, vote);
+        const vault = await getOrCreateATA(
+            incentiveMint,
+            optionIncentive,
+            keypair,
+            provider.connection
+        );
+        const voterTokenAccount = await getOrCreateATA(
+            incentiveMint,
+            voterWallet.publicKey,
+            keypair,
+            provider.connection
+        );
+
+        return governProgram.methods
+            .claimIncentive()
+            .accounts({
+                governor: govern,
+                proposal,
+                vote,
+                optionIncentive,
+                vault,
+                incentiveClaim,
+                voter: voterWallet.publicKey,
+                voterTokenAccount,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: web3.SystemProgram.programId,
+            })
+            .rpc();
+    }
+
+    async function refundIncentive(option: VoteSide) {
+        const governProgram = createGovernProgram(depositorWallet, GOVERN_PROGRAM_ID);
+        const { optionIncentive, incentiveDeposit } = getOptionIncentiveAccounts(option);
+        const vault = await getOrCreateATA(
+            incentiveMint,
+            optionIncentive,
+            depositorKeypair,
+            provider.connection
+        );
+
+        return governProgram.methods
+            .refundIncentive()
+            .accounts({
+                governor: govern,
+                proposal,
+                optionIncentive,
+                vault,
+                incentiveDeposit,
+                depositor: depositorWallet.publicKey,
+                depositorTokenAccount,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .rpc();
+    }
+
+    before(async () => {
+        const result = await createLockedVoterGovernance(
+            votingPeriod,
+            quorumVotes,
+            new BN(0),
+            lockerParams,
+            provider.connection
+        );
+        keypair = result.keypair;
+        wallet = result.wallet;
+        locker = result.locker;
+        govern = result.governor;
+        smartWallet = result.smartWallet;
+
+        for (let i = 0; i < voterCount; i++) {
+            const voter = await createMaxLockedEscrow(
+                locker,
+                result.tokenMint,
+                lockAmount,
+                keypair,
+                provider.connection
+            );
+            voterWallets.push(voter.wallet);
+            voterEscrows.push(voter.escrow);
+        }
+
+        const depositor = await createAndFundWallet(provider.connection);
+        depositorKeypair = depositor.keypair;
+        depositorWallet = depositor.wallet;
+
+        incentiveMint = await createMint(
+            provider.connection,
+            keypair,
+            keypair.publicKey,
+            null,
+            9
+        );
+        depositorTokenAccount = await getOrCreateATA(
+            incentiveMint,
+            depositorWallet.publicKey,
+            depositorKeypair,
+            provider.connection
+        );
+        await mintTo(
+            provider.connection,
+            keypair,
+            incentiveMint,
+            depositorTokenAccount,
+            keypair.publicKey,
+            incentiveAmount.muln(2).toNumber()
+        );
+
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const voterProgram = createLockedVoterProgram(wallet, LOCKED_VOTER_PROGRAM_ID);
+        proposal = await createProposal(
+            govern,
+            [
+                createSetLockerParamsInstruction(
+                    locker,
+                    govern,
+                    smartWallet,
+                    lockerParams,
+                    voterProgram
+                ),
+            ],
+            governProgram
+        );
+    });
+
+    it("depositor deposits incentives for both options of a draft proposal", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+
+        for (const option of [VoteSide.For, VoteSide.Against]) {
+            const { optionIncentive, incentiveDeposit, vault } =
+                await depositOptionIncentive(option);
+
+            const optionIncentiveState = await governProgram.account.optionIncentive.fetch(
+                optionIncentive
+            );
+            expect(optionIncentiveState.option).to.be.equal(option);
+            expect(optionIncentiveState.vault.toBase58()).to.be.equal(vault.toBase58());
+            expect(optionIncentiveState.totalDeposited.toString()).to.be.equal(
+                incentiveAmount.toString()
+            );
+
+            const incentiveDepositState =
+                await governProgram.account.incentiveDeposit.fetch(incentiveDeposit);
+            expect(incentiveDepositState.amount.toString()).to.be.equal(
+                incentiveAmount.toString()
+            );
+        }
+
+        const proposalState = await governProgram.account.proposal.fetch(proposal);
+        expect(proposalState.hasOptionIncentives).to.be.true;
+        expect(proposalState.fundedOptionIncentives.toNumber()).to.be.equal(2);
+    });
+
+    it("cannot claim incentive while voting", async () => {
+        await createLockedVoterProgram(voterWallets[0], LOCKED_VOTER_PROGRAM_ID)
+            .methods.activateProposalWithEscrow()
+            .accounts({
+                locker,
+                governor: govern,
+                proposal,
+                escrow: voterEscrows[0],
+                authority: voterWallets[0].publicKey,
+                governProgram: GOVERN_PROGRAM_ID,
+            })
+            .rpc();
+
+        for (let i = 0; i < voterCount; i++) {
+            await castVoteWithEscrow(
+                locker,
+                govern,
+                proposal,
+                voterEscrows[i],
+                VoteSide.For,
+                createLockedVoterProgram(voterWallets[i], LOCKED_VOTER_PROGRAM_ID),
+                createGovernProgram(voterWallets[i], GOVERN_PROGRAM_ID)
+            );
+        }
+
+        await invokeAndAssertError(
+            () => {
+                return claimIncentive(voterWallets[0], VoteSide.For);
+            },
+            "Invariant failed: voting must be over",
+            false
+        );
+    });
+
+    it("voters claim their share of the incentive of the voted option", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const proposalState = await governProgram.account.proposal.fetch(proposal);
+        await waitUntilOnChainTime(
+            proposalState.votingEndsAt.toNumber() + 1,
+            provider.connection
+        );
+
+        const { optionIncentive } = getOptionIncentiveAccounts(VoteSide.For);
+        for (const voterWallet of voterWallets) {
+            await claimIncentive(voterWallet, VoteSide.For);
+
+            // both voters have the same voting power
+            const voterTokenAccount = await getOrCreateATA(
+                incentiveMint,
+                voterWallet.publicKey,
+                keypair,
+                provider.connection
+            );
+            const balance = await provider.connection
+                .getTokenAccountBalance(voterTokenAccount)
+                .then((b) => b.value.amount);
+            expect(+balance).to.be.equal(incentiveAmount.divn(voterCount).toNumber());
+
+            const [vote, _vBump] = deriveVote(voterWallet.publicKey, proposal);
+            const [incentiveClaim, _cBump] = deriveIncentiveClaim(optionIncentive, vote);
+            const incentiveClaimState = await governProgram.account.incentiveClaim.fetch(
+                incentiveClaim
+            );
+            expect(incentiveClaimState.amount.toNumber()).to.be.equal(
+                incentiveAmount.divn(voterCount).toNumber()
+            );
+        }
+
+        const optionIncentiveState = await governProgram.account.optionIncentive.fetch(
+            optionIncentive
+        );
+        expect(optionIncentiveState.totalClaimed.toString()).to.be.equal(
+            incentiveAmount.toString()
+        );
+    });
+
+    it("voter cannot claim incentive of an option without their votes", async () => {
+        await invokeAndAssertError(
+            () => {
+                return claimIncentive(voterWallets[0], VoteSide.Against);
+            },
+            "Invariant failed: vote has no incentive to claim",
+            false
+        );
+    });
+
+    it("depositor cannot refund incentive of the voted option before the claim deadline", async () => {
+        await invokeAndAssertError(
+            () => {
+                return refundIncentive(VoteSide.For);
+            },
+            "Invariant failed: proposal must be canceled, the option must have no votes, or the claim deadline must have passed",
+            false
+        );
+    });
+
+    it("depositor refunds incentive of the option without votes in full", async () => {
+        const depositorBeforeBalance = await provider.connection
+            .getTokenAccountBalance(depositorTokenAccount)
+            .then((b) => b.value.amount);
+
+        await refundIncentive(VoteSide.Against);
+
+        const depositorAfterBalance = await provider.connection
+            .getTokenAccountBalance(depositorTokenAccount)
+            .then((b) => b.value.amount);
+        expect(+depositorAfterBalance - +depositorBeforeBalance).to.be.equal(
+            incentiveAmount.toNumber()
+        );
+
+        const { incentiveDeposit } = getOptionIncentiveAccounts(VoteSide.Against);
+        const incentiveDepositAccount = await provider.connection.getAccountInfo(
+            incentiveDeposit
+        );
+        expect(incentiveDepositAccount).to.be.null;
+
+        // the option incentive no longer holds deposits
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const proposalState = await governProgram.account.proposal.fetch(proposal);
+        expect(proposalState.fundedOptionIncentives.toNumber()).to.be.equal(1);
+    });
+});
@@ -0,0 +1,265 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Wallet, web3 } from "@coral-xyz/anchor";
+import {
+    GOVERN_PROGRAM_ID,
+    LOCKED_VOTER_PROGRAM_ID,
+    SMART_WALLET_PROGRAM_ID,
+    VoteSide,
+    castVoteWithEscrow,
+    createAndFundWallet,
+    createGovernProgram,
+    createGovernorInstruction,
+    createLockedVoterGovernance,
+    createLockedVoterProgram,
+    createMaxLockedEscrow,
+    createProposal,
+    createSetLockerParamsInstruction,
+    createSmartWalletProgram,
+    invokeAndAssertError,
+    invokeSmartWalletInstruction,
+    waitUntilOnChainTime,
+} from "../utils";
+import { expect } from "chai";
+
+const provider = anchor.AnchorProvider.env();
+
+const lockAmount = new BN(100);
+
+// Instruction classes of Governor::paused_instructions
+const PAUSE_CREATE_PROPOSAL = 1 << 0;
+const PAUSE_SET_VOTE = 1 << 2;
+
+describe("Pause governor", () => {
+    let locker: web3.PublicKey;
+    let govern: web3.PublicKey;
+    let smartWallet: web3.PublicKey;
+    let proposal: web3.PublicKey;
+
+    let wallet: Wallet;
+    let keypair: web3.Keypair;
+
+    let voterWallet: Wallet;
+    let voterEscrow: web3.PublicKey;
+    let pauseAuthority: Wallet;
+
+    // Govern config
+    const votingPeriod: BN = new BN(30); // 30 seconds duration of voting on proposal
+    const quorumVotes: BN = new BN(2); // 2 vote to pass
+    const pauseDuration: BN = new BN(100); // paused for at most 100 seconds
+
+    // Voter config
+    const lockerParams = {
+        maxStakeDuration: new BN(20), // 20 seconds
+        maxStakeVoteMultiplier: 1,
+        minStakeDuration: new BN(10), // 10 seconds
+        proposalActivationMinVotes: new BN(2), // min 2 vote to activate proposal
+    };
+
+    function createSetLockerParamsProposal() {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const voterProgram = createLockedVoterProgram(wallet, LOCKED_VOTER_PROGRAM_ID);
+        return createProposal(
+            govern,
+            [
+                createSetLockerParamsInstruction(
+                    locker,
+                    govern,
+                    smartWallet,
+                    lockerParams,
+                    voterProgram
+                ),
+            ],
+            governProgram
+        );
+    }
+
+    function pauseGovernor(authority: Wallet) {
+        const governProgram = createGovernProgram(authority, GOVERN_PROGRAM_ID);
+        return governProgram.methods
+            .pauseGovernor(PAUSE_CREATE_PROPOSAL | PAUSE_SET_VOTE, pauseDuration)
+            .accounts({
+                governor: govern,
+                authority: authority.publicKey,
+            })
+            .rpc();
+    }
+
+    function castVote() {
+        return castVoteWithEscrow(
+            locker,
+            govern,
+            proposal,
+            voterEscrow,
+            VoteSide.For,
+            createLockedVoterProgram(voterWallet, LOCKED_VOTER_PROGRAM_ID),
+            createGovernProgram(voterWallet, GOVERN_PROGRAM_ID)
+        ).then((vote) => vote.toBase58());
+    }
+
+    function applyVotingPause() {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        return governProgram.methods
+            .applyVotingPause()
+            .accounts({
+                governor: govern,
+                proposal,
+            })
+            .rpc();
+    }
+
+    before(async () => {
+        const result = await createLockedVoterGovernance(
+            votingPeriod,
+            quorumVotes,
+            new BN(0),
+            lockerParams,
+            provider.connection
+        );
+        keypair = result.keypair;
+        wallet = result.wallet;
+        locker = result.locker;
+        govern = result.governor;
+        smartWallet = result.smartWallet;
+
+        const voter = await createMaxLockedEscrow(
+            locker,
+            result.tokenMint,
+            lockAmount,
+            keypair,
+            provider.connection
+        );
+        voterWallet = voter.wallet;
+        voterEscrow = voter.escrow;
+
+        pauseAuthority = (await createAndFundWallet(provider.connection)).wallet;
+
+        proposal = await createSetLockerParamsProposal();
+        await createLockedVoterProgram(voterWallet, LOCKED_VOTER_PROGRAM_ID)
+            .methods.activateProposalWithEscrow()
+            .accounts({
+                locker,
+                governor: govern,
+                proposal,
+                escrow: voterEscrow,
+                authority: voterWallet.publicKey,
+                governProgram: GOVERN_PROGRAM_ID,
+            })
+            .rpc();
+    });
+
+    it("protocol team sets pause authority", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+
+        await invokeSmartWalletInstruction(
+            smartWallet,
+            createGovernorInstruction(
+                "set_pause_authority",
+                { pauseAuthority: pauseAuthority.publicKey },
+                govern,
+                smartWallet,
+                governProgram
+            ),
+            createSmartWalletProgram(wallet, SMART_WALLET_PROGRAM_ID)
+        );
+
+        const governorState = await governProgram.account.governor.fetch(govern);
+        expect(governorState.pauseAuthority.toBase58()).to.be.equal(
+            pauseAuthority.publicKey.toBase58()
+        );
+    });
+
+    it("only pause authority or smart wallet can pause governor", async () => {
+        await invokeAndAssertError(
+            () => {
+                return pauseGovernor(voterWallet);
+            },
+            "Keys do not match",
+            false
+        );
+    });
+
+    it("pause authority pauses proposal creation and voting", async () => {
+        await pauseGovernor(pauseAuthority);
+
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const governorState = await governProgram.account.governor.fetch(govern);
+        expect(governorState.pausedInstructions).to.be.equal(
+            PAUSE_CREATE_PROPOSAL | PAUSE_SET_VOTE
+        );
+        expect(governorState.pausedUntil.toNumber()).to.be.equal(
+            governorState.pausedAt.add(pauseDuration).toNumber()
+        );
+
+        await invokeAndAssertError(
+            () => {
+                return createSetLockerParamsProposal().then((proposal) =>
+                    proposal.toBase58()
+                );
+            },
+            "The instruction is paused.",
+            true
+        );
+        await invokeAndAssertError(castVote, "The instruction is paused.", false);
+    });
+
+    it("pause authority cannot pause again during the cooldown", async () => {
+        await invokeAndAssertError(
+            () => {
+                return pauseGovernor(pauseAuthority);
+            },
+            "The pause authority must wait for the pause cooldown.",
+            true
+        );
+    });
+
+    it("voting is extended by the paused duration after unpausing", async () => {
+        const governProgram = createGovernProgram(pauseAuthority, GOVERN_PROGRAM_ID);
+        let governorState = await governProgram.account.governor.fetch(govern);
+        await waitUntilOnChainTime(
+            governorState.pausedAt.toNumber() + 2,
+            provider.connection
+        );
+
+        await governProgram.methods
+            .unpauseGovernor()
+            .accounts({
+                governor: govern,
+                authority: pauseAuthority.publicKey,
+            })
+            .rpc();
+
+        governorState = await governProgram.account.governor.fetch(govern);
+        expect(governorState.pausedInstructions).to.be.equal(0);
+        expect(governorState.votingPausedDuration.toNumber()).to.be.greaterThan(0);
+
+        // votes cannot be counted before voting was extended for the pause
+        await invokeAndAssertError(
+            castVote,
+            "Voting was paused: extend it with apply_voting_pause first.",
+            false
+        );
+
+        const proposalBeforeState = await governProgram.account.proposal.fetch(
+            proposal
+        );
+        await applyVotingPause();
+        const proposalAfterState = await governProgram.account.proposal.fetch(
+            proposal
+        );
+        expect(
+            proposalAfterState.votingEndsAt.sub(proposalBeforeState.votingEndsAt).toNumber()
+        ).to.be.equal(governorState.votingPausedDuration.toNumber());
+
+        await invokeAndAssertError(
+            applyVotingPause,
+            "Invariant failed: voting of the proposal was not paused",
+            false
+        );
+
+        await castVote();
+        const proposalState = await governProgram.account.proposal.fetch(proposal);
+        expect(proposalState.optionVotes[VoteSide.For].toString()).to.be.equal(
+            lockAmount.toString()
+        );
+    });
+});
@@ -0,0 +1,351 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Wallet, web3 } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, createMint, mintTo } from "@solana/spl-token";
+import {
+    GOVERN_PROGRAM_ID,
+    IProposalInstruction,
+    LOCKED_VOTER_PROGRAM_ID,
+    SMART_WALLET_PROGRAM_ID,
+    VoteSide,
+    castVoteWithEscrow,
+    createGovernProgram,
+    createLockedVoterGovernance,
+    createLockedVoterProgram,
+    createMaxLockedEscrow,
+    createProposal,
+    createSetLockerParamsInstruction,
+    createSmartWalletProgram,
+    deriveProposal,
+    deriveProposalBond,
+    getOrCreateATA,
+    invokeAndAssertError,
+    invokeSmartWalletInstruction,
+    waitUntilOnChainTime,
+} from "../utils";
+import { expect } from "chai";
+
+const provider = anchor.AnchorProvider.env();
+
+const lockAmount = new BN(100);
+
+describe("Proposal bond", () => {
+    let locker: web3.PublicKey;
+    let govern: web3.PublicKey;
+    let smartWallet: web3.PublicKey;
+    let proposal: web3.PublicKey;
+
+    let wallet: Wallet;
+    let keypair: web3.Keypair;
+
+    let voterWallet: Wallet;
+    let voterEscrow: web3.PublicKey;
+
+    let bondMint: web3.PublicKey;
+    let proposerBondAccount: web3.PublicKey;
+    let treasury: web3.PublicKey;
+
+    // Govern config
+    const votingPeriod: BN = new BN(5); // 5 seconds duration of voting on proposal
+    const quorumVotes: BN = new BN(2); // 2 vote to pass
+
+    // Voter config
+    const lockerParams = {
+        maxStakeDuration: new BN(20), // 20 seconds
+        maxStakeVoteMultiplier: 1,
+        minStakeDuration: new BN(10), // 10 seconds
+        proposalActivationMinVotes: new BN(2), // min 2 vote to activate proposal
+    };
+
+    const bondAmount = new BN(5000);
+
+    function getSetLockerParamsInstructions() {
+        const voterProgram = createLockedVoterProgram(wallet, LOCKED_VOTER_PROGRAM_ID);
+        return [
+            createSetLockerParamsInstruction(
+                locker,
+                govern,
+                smartWallet,
+                lockerParams,
+                voterProgram
+            ),
+        ];
+    }
+
+    async function createBondedProposal() {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const governState = await governProgram.account.governor.fetch(govern);
+        const [proposal, _pBump] = deriveProposal(govern, governState.proposalCount);
+        const [proposalBond, _bBump] = deriveProposalBond(proposal);
+        const bondVault = await getOrCreateATA(
+            bondMint,
+            proposalBond,
+            keypair,
+            provider.connection
+        );
+
+        await governProgram.methods
+            .createProposal(0, 2, getSetLockerParamsInstructions())
+            .accounts({
+                governor: govern,
+                instructionBuffer: null,
+                proposal,
+                smartWallet,
+                proposer: wallet.publicKey,
+                payer: wallet.publicKey,
+                systemProgram: web3.SystemProgram.programId,
+                proposalBond,
+                bondVault,
+                proposerBondAccount,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .rpc();
+
+        return proposal;
+    }
+
+    async function settleProposalBond(proposal: web3.PublicKey) {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const [proposalBond, _bump] = deriveProposalBond(proposal);
+        const vault = await getOrCreateATA(
+            bondMint,
+            proposalBond,
+            keypair,
+            provider.connection
+        );
+
+        return governProgram.methods
+            .settleProposalBond()
+            .accounts({
+                governor: govern,
+                proposal,
+                proposalBond,
+                vault,
+                proposer: wallet.publicKey,
+                proposerTokenAccount: proposerBondAccount,
+                treasury,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .rpc();
+    }
+
+    function getBalance(tokenAccount: web3.PublicKey) {
+        return provider.connection
+            .getTokenAccountBalance(tokenAccount)
+            .then((b) => +b.value.amount);
+    }
+
+    before(async () => {
+        const result = await createLockedVoterGovernance(
+            votingPeriod,
+            quorumVotes,
+            new BN(0),
+            lockerParams,
+            provider.connection
+        );
+        keypair = result.keypair;
+        wallet = result.wallet;
+        locker = result.locker;
+        govern = result.governor;
+        smartWallet = result.smartWallet;
+
+        const voter = await createMaxLockedEscrow(
+            locker,
+            result.tokenMint,
+            lockAmount,
+            keypair,
+            provider.connection
+        );
+        voterWallet = voter.wallet;
+        voterEscrow = voter.escrow;
+
+        bondMint = await createMint(
+            provider.connection,
+            keypair,
+            keypair.publicKey,
+            null,
+            9
+        );
+        proposerBondAccount = await getOrCreateATA(
+            bondMint,
+            wallet.publicKey,
+            keypair,
+            provider.connection
+        );
+        await mintTo(
+            provider.connection,
+            keypair,
+            bondMint,
+            proposerBondAccount,
+            keypair.publicKey,
+            bondAmount.muln(2).toNumber()
+        );
+        treasury = await getOrCreateATA(
+            bondMint,
+            smartWallet,
+            keypair,
+            provider.connection
+        );
+    });
+
+    it("protocol team sets proposal bond", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+
+        const setProposalBondIx: IProposalInstruction = {
+            programId: GOVERN_PROGRAM_ID,
+            data: governProgram.coder.instruction.encode("set_proposal_bond", {
+                amount: bondAmount,
+            }),
+            keys: [
+                {
+                    isSigner: false,
+                    isWritable: true,
+                    pubkey: govern,
+                },
+                {
+                    isSigner: false,
+                    isWritable: false,
+                    pubkey: bondMint,
+                },
+                {
+                    isSigner: true,
+                    isWritable: false,
+                    pubkey: smartWallet,
+                },
+            ],
+        };
+
+        await invokeSmartWalletInstruction(
+            smartWallet,
+            setProposalBondIx,
+            createSmartWalletProgram(wallet, SMART_WALLET_PROGRAM_ID)
+        );
+
+        const governorState = await governProgram.account.governor.fetch(govern);
+        expect(governorState.proposalBond.mint.toBase58()).to.be.equal(
+            bondMint.toBase58()
+        );
+        expect(governorState.proposalBond.amount.toString()).to.be.equal(
+            bondAmount.toString()
+        );
+    });
+
+    it("cannot create proposal without the bond", async () => {
+        await invokeAndAssertError(
+            () => {
+                return createProposal(
+                    govern,
+                    getSetLockerParamsInstructions(),
+                    createGovernProgram(wallet, GOVERN_PROGRAM_ID)
+                ).then((proposal) => proposal.toBase58());
+            },
+            "The governor requires a proposal bond.",
+            true
+        );
+    });
+
+    it("proposer escrows the bond when creating proposal", async () => {
+        const proposerBeforeBalance = await getBalance(proposerBondAccount);
+
+        proposal = await createBondedProposal();
+
+        const proposerAfterBalance = await getBalance(proposerBondAccount);
+        expect(proposerBeforeBalance - proposerAfterBalance).to.be.equal(
+            bondAmount.toNumber()
+        );
+
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const [proposalBond, _bump] = deriveProposalBond(proposal);
+        const proposalBondState = await governProgram.account.proposalBond.fetch(
+            proposalBond
+        );
+        expect(proposalBondState.proposer.toBase58()).to.be.equal(
+            wallet.publicKey.toBase58()
+        );
+        expect(proposalBondState.amount.toString()).to.be.equal(bondAmount.toString());
+        expect(await getBalance(proposalBondState.vault)).to.be.equal(
+            bondAmount.toNumber()
+        );
+    });
+
+    it("cannot settle bond while voting", async () => {
+        const voterProgram = createLockedVoterProgram(voterWallet, LOCKED_VOTER_PROGRAM_ID);
+        await voterProgram.methods
+            .activateProposalWithEscrow()
+            .accounts({
+                locker,
+                governor: govern,
+                proposal,
+                escrow: voterEscrow,
+                authority: voterWallet.publicKey,
+                governProgram: GOVERN_PROGRAM_ID,
+            })
+            .rpc();
+
+        await castVoteWithEscrow(
+            locker,
+            govern,
+            proposal,
+            voterEscrow,
+            VoteSide.For,
+            voterProgram,
+            createGovernProgram(voterWallet, GOVERN_PROGRAM_ID)
+        );
+
+        await invokeAndAssertError(
+            () => {
+                return settleProposalBond(proposal);
+            },
+            "The proposal bond cannot be settled yet.",
+            true
+        );
+    });
+
+    it("bond is refunded to the proposer once the proposal reaches quorum", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const proposalState = await governProgram.account.proposal.fetch(proposal);
+        await waitUntilOnChainTime(
+            proposalState.votingEndsAt.toNumber() + 1,
+            provider.connection
+        );
+
+        const proposerBeforeBalance = await getBalance(proposerBondAccount);
+        const treasuryBeforeBalance = await getBalance(treasury);
+
+        await settleProposalBond(proposal);
+
+        expect((await getBalance(proposerBondAccount)) - proposerBeforeBalance).to.be.equal(
+            bondAmount.toNumber()
+        );
+        expect(await getBalance(treasury)).to.be.equal(treasuryBeforeBalance);
+
+        const [proposalBond, _bump] = deriveProposalBond(proposal);
+        const proposalBondAccount = await provider.connection.getAccountInfo(
+            proposalBond
+        );
+        expect(proposalBondAccount).to.be.null;
+    });
+
+    it("bond of a canceled proposal is forfeited to the treasury", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const canceledProposal = await createBondedProposal();
+
+        await governProgram.methods
+            .cancelProposal()
+            .accounts({
+                governor: govern,
+                proposal: canceledProposal,
+                proposer: wallet.publicKey,
+            })
+            .rpc();
+
+        const proposerBeforeBalance = await getBalance(proposerBondAccount);
+        const treasuryBeforeBalance = await getBalance(treasury);
+
+        await settleProposalBond(canceledProposal);
+
+        expect(await getBalance(proposerBondAccount)).to.be.equal(proposerBeforeBalance);
+        expect((await getBalance(treasury)) - treasuryBeforeBalance).to.be.equal(
+            bondAmount.toNumber()
+        );
+    });
+});
@@ -0,0 +1,482 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Wallet, web3 } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, createMint, mintTo } from "@solana/spl-token";
+import {
+    GOVERN_PROGRAM_ID,
+    LOCKED_VOTER_PROGRAM_ID,
+    SMART_WALLET_PROGRAM_ID,
+    VoteSide,
+    castVoteWithEscrow,
+    createAndFundWallet,
+    createGovernProgram,
+    createGovernorInstruction,
+    createLockedVoterGovernance,
+    createLockedVoterProgram,
+    createMaxLockedEscrow,
+    createProposal,
+    createSetLockerParamsInstruction,
+    createSmartWalletProgram,
+    deriveProposalRewards,
+    deriveVote,
+    getOrCreateATA,
+    invokeAndAssertError,
+    invokeSmartWalletInstruction,
+    setVotingReward,
+    waitUntilOnChainTime,
+} from "../utils";
+import { expect } from "chai";
+
+const provider = anchor.AnchorProvider.env();
+
+const voterCount = 2;
+const lockAmount = new BN(100);
+
+describe("Reward streams", () => {
+    let locker: web3.PublicKey;
+    let govern: web3.PublicKey;
+    let smartWallet: web3.PublicKey;
+    let proposal: web3.PublicKey;
+    let proposalRewards: web3.PublicKey;
+
+    let wallet: Wallet;
+    let keypair: web3.Keypair;
+
+    let voterWallets: Wallet[] = [];
+    let voterEscrows: web3.PublicKey[] = [];
+
+    let funderKeypair: web3.Keypair;
+    let funderWallet: Wallet;
+
+    let rewardVault: web3.PublicKey;
+    let streamMints: web3.PublicKey[] = [];
+
+    // Govern config
+    const votingPeriod: BN = new BN(5); // 5 seconds duration of voting on proposal
+    const quorumVotes: BN = new BN(2); // 2 vote to pass
+    const rewardClaimPeriod: BN = new BN(10); // 10 seconds to claim rewards after voting
+
+    // Voter config
+    const lockerParams = {
+        maxStakeDuration: new BN(20), // 20 seconds
+        maxStakeVoteMultiplier: 1,
+        minStakeDuration: new BN(10), // 10 seconds
+        proposalActivationMinVotes: new BN(2), // min 2 vote to activate proposal
+    };
+
+    const rewardPerProposal = new BN(100000);
+    const streamAmount = new BN(50000);
+
+    async function createSetLockerParamsProposal() {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const voterProgram = createLockedVoterProgram(wallet, LOCKED_VOTER_PROGRAM_ID);
+        return createProposal(
+            govern,
+            [
+                createSetLockerParamsInstruction(
+                    locker,
+                    govern,
+                    smartWallet,
+                    lockerParams,
+                    voterProgram
+                ),
+            ],
+            governProgram
+        );
+    }
+
+    async function fundRewardStreams(proposal: web3.PublicKey) {
+        const governProgram = createGovernProgram(funderWallet, GOVERN_PROGRAM_ID);
+        const [proposalRewards, _bump] = deriveProposalRewards(proposal);
+
+        await governProgram.methods
+            .createProposalRewards()
+            .accounts({
+                proposal,
+                proposalRewards,
+                payer: funderWallet.publicKey,
+                systemProgram: web3.SystemProgram.programId,
+            })
+            .rpc();
+
+        for (const streamMint of streamMints) {
+            const streamVault = await getOrCreateATA(
+                streamMint,
+                proposalRewards,
+                funderKeypair,
+                provider.connection
+            );
+            const funderTokenAccount = await getOrCreateATA(
+                streamMint,
+                funderWallet.publicKey,
+                funderKeypair,
+                provider.connection
+            );
+
+            await governProgram.methods
+                .addRewardStream(streamAmount)
+                .accounts({
+                    proposal,
+                    proposalRewards,
+                    rewardMint: streamMint,
+                    rewardVault: streamVault,
+                    funder: funderWallet.publicKey,
+                    funderTokenAccount,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                })
+                .rpc();
+        }
+
+        return proposalRewards;
+    }
+
+    async function refundRewardStream(
+        proposal: web3.PublicKey,
+        proposalRewards: web3.PublicKey,
+        streamMint: web3.PublicKey
+    ) {
+        const governProgram = createGovernProgram(funderWallet, GOVERN_PROGRAM_ID);
+        const rewardVault = await getOrCreateATA(
+            streamMint,
+            proposalRewards,
+            funderKeypair,
+            provider.connection
+        );
+        const funderTokenAccount = await getOrCreateATA(
+            streamMint,
+            funderWallet.publicKey,
+            funderKeypair,
+            provider.connection
+        );
+
+        return governProgram.methods
+            .refundRewardStream()
+            .accounts({
+                governor: govern,
+                proposal,
+                proposalRewards,
+                rewardVault,
+                funder: funderWallet.publicKey,
+                funderTokenAccount,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .rpc();
+    }
+
+    before(async () => {
+        const result = await createLockedVoterGovernance(
+            votingPeriod,
+            quorumVotes,
+            new BN(0),
+            lockerParams,
+            provider.connection
+        );
+        keypair = result.keypair;
+        wallet = result.wallet;
+        locker = result.locker;
+        govern = result.governor;
+        smartWallet = result.smartWallet;
+
+        for (let i = 0; i < voterCount; i++) {
+            const voter = await createMaxLockedEscrow(
+                locker,
+                result.tokenMint,
+                lockAmount,
+                keypair,
+                provider.connection
+            );
+            voterWallets.push(voter.wallet);
+            voterEscrows.push(voter.escrow);
+        }
+
+        const votingRewardMint = await createMint(
+            provider.connection,
+            keypair,
+            keypair.publicKey,
+            null,
+            9
+        );
+        rewardVault = await setVotingReward(
+            govern,
+            smartWallet,
+            votingRewardMint,
+            rewardPerProposal,
+            keypair,
+            wallet
+        );
+
+        await invokeSmartWalletInstruction(
+            smartWallet,
+            createGovernorInstruction(
+                "set_reward_claim_period",
+                { claimPeriod: rewardClaimPeriod },
+                govern,
+                smartWallet,
+                createGovernProgram(wallet, GOVERN_PROGRAM_ID)
+            ),
+            createSmartWalletProgram(wallet, SMART_WALLET_PROGRAM_ID)
+        );
+
+        const funder = await createAndFundWallet(provider.connection);
+        funderKeypair = funder.keypair;
+        funderWallet = funder.wallet;
+
+        // two streams of different mints, funded twice: for the voted and the canceled proposal
+        for (let i = 0; i < 2; i++) {
+            const streamMint = await createMint(
+                provider.connection,
+                keypair,
+                keypair.publicKey,
+                null,
+                9
+            );
+            const funderTokenAccount = await getOrCreateATA(
+                streamMint,
+                funderWallet.publicKey,
+                funderKeypair,
+                provider.connection
+            );
+            await mintTo(
+                provider.connection,
+                keypair,
+                streamMint,
+                funderTokenAccount,
+                keypair.publicKey,
+                streamAmount.muln(2).toNumber()
+            );
+            streamMints.push(streamMint);
+        }
+    });
+
+    it("funder adds reward streams to proposal", async () => {
+        proposal = await createSetLockerParamsProposal();
+        proposalRewards = await fundRewardStreams(proposal);
+
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const proposalRewardsState = await governProgram.account.proposalRewards.fetch(
+            proposalRewards
+        );
+        expect(proposalRewardsState.streams.length).to.be.equal(streamMints.length);
+        for (const [index, stream] of proposalRewardsState.streams.entries()) {
+            expect(stream.mint.toBase58()).to.be.equal(streamMints[index].toBase58());
+            expect(stream.funder.toBase58()).to.be.equal(
+                funderWallet.publicKey.toBase58()
+            );
+            expect(stream.amount.toString()).to.be.equal(streamAmount.toString());
+        }
+    });
+
+    it("users vote for proposal", async () => {
+        await createLockedVoterProgram(voterWallets[0], LOCKED_VOTER_PROGRAM_ID)
+            .methods.activateProposalWithEscrow()
+            .accounts({
+                locker,
+                governor: govern,
+                proposal,
+                escrow: voterEscrows[0],
+                authority: voterWallets[0].publicKey,
+                governProgram: GOVERN_PROGRAM_ID,
+            })
+            .rpc();
+
+        for (let i = 0; i < voterCount; i++) {
+            await castVoteWithEscrow(
+                locker,
+                govern,
+                proposal,
+                voterEscrows[i],
+                VoteSide.For,
+                createLockedVoterProgram(voterWallets[i], LOCKED_VOTER_PROGRAM_ID),
+                createGovernProgram(voterWallets[i], GOVERN_PROGRAM_ID)
+            );
+        }
+    });
+
+    it("user claims voting reward and reward streams", async () => {
+        const voterWallet = voterWallets[0];
+        const governProgram = createGovernProgram(voterWallet, GOVERN_PROGRAM_ID);
+        const proposalState = await governProgram.account.proposal.fetch(proposal);
+        await waitUntilOnChainTime(
+            proposalState.votingEndsAt.toNumber() + 1,
+            provider.connection
+        );
+
+        const governorState = await governProgram.account.governor.fetch(govern);
+        const voterTokenAccount = await getOrCreateATA(
+            governorState.votingReward.rewardMint,
+            voterWallet.publicKey,
+            keypair,
+            provider.connection
+        );
+
+        let streamAccounts: web3.AccountMeta[] = [];
+        let streamTokenAccounts: web3.PublicKey[] = [];
+        for (const streamMint of streamMints) {
+            const streamVault = await getOrCreateATA(
+                streamMint,
+                proposalRewards,
+                keypair,
+                provider.connection
+            );
+            const streamTokenAccount = await getOrCreateATA(
+                streamMint,
+                voterWallet.publicKey,
+                keypair,
+                provider.connection
+            );
+            streamTokenAccounts.push(streamTokenAccount);
+            streamAccounts.push(
+                {
+                    isSigner: false,
+                    isWritable: true,
+                    pubkey: streamVault,
+                },
+                {
+                    isSigner: false,
+                    isWritable: true,
+                    pubkey: streamTokenAccount,
+                }
+            );
+        }
+
+        const [vote, _bump] = deriveVote(voterWallet.publicKey, proposal);
+        await governProgram.methods
+            .claimReward()
+            .accounts({
+                governor: govern,
+                rewardVault,
+                proposal,
+                vote,
+                voter: voterWallet.publicKey,
+                authority: null,
+                voterTokenAccount,
+                proposalRewards,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .remainingAccounts(streamAccounts)
+            .rpc();
+
+        const voterBalance = await provider.connection
+            .getTokenAccountBalance(voterTokenAccount)
+            .then((b) => b.value.amount);
+        expect(+voterBalance).to.be.greaterThan(0);
+
+        // both voters have the same voting power
+        for (const streamTokenAccount of streamTokenAccounts) {
+            const streamBalance = await provider.connection
+                .getTokenAccountBalance(streamTokenAccount)
+                .then((b) => b.value.amount);
+            expect(+streamBalance).to.be.equal(streamAmount.divn(voterCount).toNumber());
+        }
+
+        const voteState = await governProgram.account.vote.fetch(vote);
+        expect(voteState.claimedStreams).to.be.equal(0b11);
+    });
+
+    it("funder cannot refund reward stream before the claim deadline", async () => {
+        await invokeAndAssertError(
+            () => {
+                return refundRewardStream(proposal, proposalRewards, streamMints[0]);
+            },
+            "Invariant failed: claim deadline has not passed",
+            false
+        );
+    });
+
+    it("funder refunds unclaimed reward stream after the claim deadline", async () => {
+        const governProgram = createGovernProgram(funderWallet, GOVERN_PROGRAM_ID);
+        const proposalState = await governProgram.account.proposal.fetch(proposal);
+        await waitUntilOnChainTime(
+            proposalState.votingEndsAt.add(rewardClaimPeriod).toNumber() + 1,
+            provider.connection
+        );
+
+        const funderTokenAccount = await getOrCreateATA(
+            streamMints[0],
+            funderWallet.publicKey,
+            funderKeypair,
+            provider.connection
+        );
+        const funderBeforeBalance = await provider.connection
+            .getTokenAccountBalance(funderTokenAccount)
+            .then((b) => b.value.amount);
+
+        await refundRewardStream(proposal, proposalRewards, streamMints[0]);
+
+        const funderAfterBalance = await provider.connection
+            .getTokenAccountBalance(funderTokenAccount)
+            .then((b) => b.value.amount);
+        const proposalRewardsState = await governProgram.account.proposalRewards.fetch(
+            proposalRewards
+        );
+        const stream = proposalRewardsState.streams[0];
+
+        expect(stream.refundedAt.toString()).not.equal("0");
+        expect(+funderAfterBalance - +funderBeforeBalance).to.be.equal(
+            stream.amount.sub(stream.totalClaimed).toNumber()
+        );
+
+        await invokeAndAssertError(
+            () => {
+                return refundRewardStream(proposal, proposalRewards, streamMints[0]);
+            },
+            "Invariant failed: reward stream has been refunded",
+            false
+        );
+    });
+
+    it("funder refunds reward streams of a canceled proposal", async () => {
+        const canceledProposal = await createSetLockerParamsProposal();
+        const canceledProposalRewards = await fundRewardStreams(canceledProposal);
+
+        await createGovernProgram(wallet, GOVERN_PROGRAM_ID)
+            .methods.cancelProposal()
+            .accounts({
+                governor: govern,
+                proposal: canceledProposal,
+                proposer: wallet.publicKey,
+            })
+            .rpc();
+
+        for (const streamMint of streamMints) {
+            await refundRewardStream(
+                canceledProposal,
+                canceledProposalRewards,
+                streamMint
+            );
+        }
+
+        const governProgram = createGovernProgram(funderWallet, GOVERN_PROGRAM_ID);
+        const proposalRewardsState = await governProgram.account.proposalRewards.fetch(
+            canceledProposalRewards
+        );
+        for (const stream of proposalRewardsState.streams) {
+            expect(stream.refundedAt.toString()).not.equal("0");
+            expect(stream.totalClaimed.toString()).to.be.equal("0");
+        }
+    });
+
+    it("funder cannot add reward streams to a proposal without a claim period", async () => {
+        await invokeSmartWalletInstruction(
+            smartWallet,
+            createGovernorInstruction(
+                "set_reward_claim_period",
+                { claimPeriod: new BN(0) },
+                govern,
+                smartWallet,
+                createGovernProgram(wallet, GOVERN_PROGRAM_ID)
+            ),
+            createSmartWalletProgram(wallet, SMART_WALLET_PROGRAM_ID)
+        );
+        const proposal = await createSetLockerParamsProposal();
+
+        await invokeAndAssertError(
+            () => {
+                return fundRewardStreams(proposal).then((proposalRewards) =>
+                    proposalRewards.toBase58()
+                );
+            },
+            "Reward streams require a reward claim period.",
+            true
+        );
+    });
+});
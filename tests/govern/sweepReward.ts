@@ -0,0 +1,298 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Wallet, web3 } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, createMint } from "@solana/spl-token";
+import {
+    GOVERN_PROGRAM_ID,
+    LOCKED_VOTER_PROGRAM_ID,
+    SMART_WALLET_PROGRAM_ID,
+    VoteSide,
+    castVoteWithEscrow,
+    createGovernProgram,
+    createGovernorInstruction,
+    createLockedVoterGovernance,
+    createLockedVoterProgram,
+    createMaxLockedEscrow,
+    createProposal,
+    createSetLockerParamsInstruction,
+    createSmartWalletProgram,
+    deriveVote,
+    getOrCreateATA,
+    invokeAndAssertError,
+    invokeSmartWalletInstruction,
+    setVotingReward,
+    waitUntilOnChainTime,
+} from "../utils";
+import { expect } from "chai";
+
+const provider = anchor.AnchorProvider.env();
+
+const voterCount = 2;
+const lockAmount = new BN(100);
+
+describe("Sweep reward", () => {
+    let locker: web3.PublicKey;
+    let govern: web3.PublicKey;
+    let smartWallet: web3.PublicKey;
+    let proposal: web3.PublicKey;
+
+    let wallet: Wallet;
+    let keypair: web3.Keypair;
+
+    let voterWallets: Wallet[] = [];
+    let voterEscrows: web3.PublicKey[] = [];
+
+    let votingRewardMint: web3.PublicKey;
+    let rewardVault: web3.PublicKey;
+    let treasury: web3.PublicKey;
+
+    // Govern config
+    const votingPeriod: BN = new BN(5); // 5 seconds duration of voting on proposal
+    const quorumVotes: BN = new BN(2); // 2 vote to pass
+    const rewardClaimPeriod: BN = new BN(10); // 10 seconds to claim rewards after voting
+
+    // Voter config
+    const lockerParams = {
+        maxStakeDuration: new BN(20), // 20 seconds
+        maxStakeVoteMultiplier: 1,
+        minStakeDuration: new BN(10), // 10 seconds
+        proposalActivationMinVotes: new BN(2), // min 2 vote to activate proposal
+    };
+
+    const rewardPerProposal = new BN(100000);
+
+    function claimReward(voterWallet: Wallet, voterTokenAccount: web3.PublicKey) {
+        const governProgram = createGovernProgram(voterWallet, GOVERN_PROGRAM_ID);
+        const [vote, _bump] = deriveVote(voterWallet.publicKey, proposal);
+        return governProgram.methods
+            .claimReward()
+            .accounts({
+                governor: govern,
+                rewardVault,
+                proposal,
+                vote,
+                voter: voterWallet.publicKey,
+                authority: null,
+                voterTokenAccount,
+                proposalRewards: null,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .rpc();
+    }
+
+    function sweepRewardInstruction() {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        return governProgram.methods
+            .sweepReward()
+            .accounts({
+                governor: govern,
+                proposal,
+                rewardVault,
+                treasury,
+                smartWallet,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .instruction();
+    }
+
+    before(async () => {
+        const result = await createLockedVoterGovernance(
+            votingPeriod,
+            quorumVotes,
+            new BN(0),
+            lockerParams,
+            provider.connection
+        );
+        keypair = result.keypair;
+        wallet = result.wallet;
+        locker = result.locker;
+        govern = result.governor;
+        smartWallet = result.smartWallet;
+
+        for (let i = 0; i < voterCount; i++) {
+            const voter = await createMaxLockedEscrow(
+                locker,
+                result.tokenMint,
+                lockAmount,
+                keypair,
+                provider.connection
+            );
+            voterWallets.push(voter.wallet);
+            voterEscrows.push(voter.escrow);
+        }
+
+        votingRewardMint = await createMint(
+            provider.connection,
+            keypair,
+            keypair.publicKey,
+            null,
+            9
+        );
+        rewardVault = await setVotingReward(
+            govern,
+            smartWallet,
+            votingRewardMint,
+            rewardPerProposal,
+            keypair,
+            wallet
+        );
+        treasury = await getOrCreateATA(
+            votingRewardMint,
+            smartWallet,
+            keypair,
+            provider.connection
+        );
+    });
+
+    it("protocol team sets reward claim period", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+
+        await invokeSmartWalletInstruction(
+            smartWallet,
+            createGovernorInstruction(
+                "set_reward_claim_period",
+                { claimPeriod: rewardClaimPeriod },
+                govern,
+                smartWallet,
+                governProgram
+            ),
+            createSmartWalletProgram(wallet, SMART_WALLET_PROGRAM_ID)
+        );
+
+        const governorState = await governProgram.account.governor.fetch(govern);
+        expect(governorState.rewardClaimPeriod.toString()).to.be.equal(
+            rewardClaimPeriod.toString()
+        );
+    });
+
+    it("users vote for proposal snapshotting the claim period", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const voterProgram = createLockedVoterProgram(wallet, LOCKED_VOTER_PROGRAM_ID);
+        proposal = await createProposal(
+            govern,
+            [
+                createSetLockerParamsInstruction(
+                    locker,
+                    govern,
+                    smartWallet,
+                    lockerParams,
+                    voterProgram
+                ),
+            ],
+            governProgram
+        );
+
+        const proposalState = await governProgram.account.proposal.fetch(proposal);
+        expect(proposalState.rewardClaimPeriod.toString()).to.be.equal(
+            rewardClaimPeriod.toString()
+        );
+
+        await createLockedVoterProgram(voterWallets[0], LOCKED_VOTER_PROGRAM_ID)
+            .methods.activateProposalWithEscrow()
+            .accounts({
+                locker,
+                governor: govern,
+                proposal,
+                escrow: voterEscrows[0],
+                authority: voterWallets[0].publicKey,
+                governProgram: GOVERN_PROGRAM_ID,
+            })
+            .rpc();
+
+        for (let i = 0; i < voterCount; i++) {
+            await castVoteWithEscrow(
+                locker,
+                govern,
+                proposal,
+                voterEscrows[i],
+                VoteSide.For,
+                createLockedVoterProgram(voterWallets[i], LOCKED_VOTER_PROGRAM_ID),
+                createGovernProgram(voterWallets[i], GOVERN_PROGRAM_ID)
+            );
+        }
+    });
+
+    it("user claims reward before the claim deadline", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const proposalState = await governProgram.account.proposal.fetch(proposal);
+        await waitUntilOnChainTime(
+            proposalState.votingEndsAt.toNumber() + 1,
+            provider.connection
+        );
+
+        const voterTokenAccount = await getOrCreateATA(
+            votingRewardMint,
+            voterWallets[0].publicKey,
+            keypair,
+            provider.connection
+        );
+        await claimReward(voterWallets[0], voterTokenAccount);
+
+        const balance = await provider.connection
+            .getTokenAccountBalance(voterTokenAccount)
+            .then((b) => b.value.amount);
+        expect(+balance).to.be.greaterThan(0);
+    });
+
+    it("cannot sweep reward before the claim deadline", async () => {
+        const sweepRewardIx = await sweepRewardInstruction();
+
+        await invokeAndAssertError(
+            () => {
+                return invokeSmartWalletInstruction(
+                    smartWallet,
+                    sweepRewardIx,
+                    createSmartWalletProgram(wallet, SMART_WALLET_PROGRAM_ID)
+                ).then((transaction) => transaction.toBase58());
+            },
+            "Invariant failed: claim deadline has not passed",
+            false
+        );
+    });
+
+    it("cannot claim reward after the claim deadline", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const proposalState = await governProgram.account.proposal.fetch(proposal);
+        await waitUntilOnChainTime(
+            proposalState.votingEndsAt.add(rewardClaimPeriod).toNumber() + 1,
+            provider.connection
+        );
+
+        const voterTokenAccount = await getOrCreateATA(
+            votingRewardMint,
+            voterWallets[1].publicKey,
+            keypair,
+            provider.connection
+        );
+
+        await invokeAndAssertError(
+            () => {
+                return claimReward(voterWallets[1], voterTokenAccount);
+            },
+            "The claim deadline of the voting reward has passed.",
+            true
+        );
+    });
+
+    it("protocol team sweeps unclaimed reward to the treasury", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const treasuryBeforeBalance = await provider.connection
+            .getTokenAccountBalance(treasury)
+            .then((b) => b.value.amount);
+
+        await invokeSmartWalletInstruction(
+            smartWallet,
+            await sweepRewardInstruction(),
+            createSmartWalletProgram(wallet, SMART_WALLET_PROGRAM_ID)
+        );
+
+        const treasuryAfterBalance = await provider.connection
+            .getTokenAccountBalance(treasury)
+            .then((b) => b.value.amount);
+        const proposalState = await governProgram.account.proposal.fetch(proposal);
+
+        expect(proposalState.rewardSweptAt.toString()).not.equal("0");
+        expect(+treasuryAfterBalance - +treasuryBeforeBalance).to.be.equal(
+            rewardPerProposal.sub(proposalState.totalClaimedReward).toNumber()
+        );
+    });
+});
@@ -0,0 +1,251 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Wallet, web3 } from "@coral-xyz/anchor";
+import {
+    GOVERN_PROGRAM_ID,
+    LOCKED_VOTER_PROGRAM_ID,
+    createAndFundWallet,
+    createGovernProgram,
+    createLockedVoterGovernance,
+    createLockedVoterProgram,
+    createMaxLockedEscrow,
+    createOptionProposal,
+    createOptionProposalMeta,
+    createProposal,
+    createProposalMeta,
+    createSetLockerParamsInstruction,
+    invokeAndAssertError,
+} from "../utils";
+import { expect } from "chai";
+
+const provider = anchor.AnchorProvider.env();
+
+const lockAmount = new BN(100);
+
+describe("Update draft proposal", () => {
+    let locker: web3.PublicKey;
+    let govern: web3.PublicKey;
+    let smartWallet: web3.PublicKey;
+    let proposal: web3.PublicKey;
+    let proposalMeta: web3.PublicKey;
+
+    let wallet: Wallet;
+    let keypair: web3.Keypair;
+
+    let voterWallet: Wallet;
+    let voterEscrow: web3.PublicKey;
+
+    // Govern config
+    const votingPeriod: BN = new BN(5); // 5 seconds duration of voting on proposal
+    const quorumVotes: BN = new BN(2); // 2 vote to pass
+
+    // Voter config
+    const lockerParams = {
+        maxStakeDuration: new BN(20), // 20 seconds
+        maxStakeVoteMultiplier: 1,
+        minStakeDuration: new BN(10), // 10 seconds
+        proposalActivationMinVotes: new BN(2), // min 2 vote to activate proposal
+    };
+
+    function getSetLockerParamsInstruction() {
+        const voterProgram = createLockedVoterProgram(wallet, LOCKED_VOTER_PROGRAM_ID);
+        return createSetLockerParamsInstruction(
+            locker,
+            govern,
+            smartWallet,
+            lockerParams,
+            voterProgram
+        );
+    }
+
+    function updateProposalInstructions(proposer: Wallet, instructionCount: number) {
+        const governProgram = createGovernProgram(proposer, GOVERN_PROGRAM_ID);
+        return governProgram.methods
+            .updateProposalInstructions(
+                Array(instructionCount).fill(getSetLockerParamsInstruction())
+            )
+            .accounts({
+                governor: govern,
+                proposal,
+                proposer: proposer.publicKey,
+                payer: proposer.publicKey,
+                systemProgram: web3.SystemProgram.programId,
+            })
+            .rpc();
+    }
+
+    function updateProposalMeta(proposer: Wallet, title: string) {
+        const governProgram = createGovernProgram(proposer, GOVERN_PROGRAM_ID);
+        return governProgram.methods
+            .updateProposalMeta(title, "https://example.com/proposal")
+            .accounts({
+                proposal,
+                proposer: proposer.publicKey,
+                proposalMeta,
+                payer: proposer.publicKey,
+                systemProgram: web3.SystemProgram.programId,
+            })
+            .rpc();
+    }
+
+    before(async () => {
+        const result = await createLockedVoterGovernance(
+            votingPeriod,
+            quorumVotes,
+            new BN(0),
+            lockerParams,
+            provider.connection
+        );
+        keypair = result.keypair;
+        wallet = result.wallet;
+        locker = result.locker;
+        govern = result.governor;
+        smartWallet = result.smartWallet;
+
+        const voter = await createMaxLockedEscrow(
+            locker,
+            result.tokenMint,
+            lockAmount,
+            keypair,
+            provider.connection
+        );
+        voterWallet = voter.wallet;
+        voterEscrow = voter.escrow;
+
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        proposal = await createProposal(
+            govern,
+            [getSetLockerParamsInstruction()],
+            governProgram
+        );
+        proposalMeta = await createProposalMeta(
+            proposal,
+            "Draft",
+            "https://example.com",
+            governProgram
+        );
+    });
+
+    it("proposer updates instructions of draft proposal", async () => {
+        await updateProposalInstructions(wallet, 2);
+
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const proposalState = await governProgram.account.proposal.fetch(proposal);
+        expect(proposalState.instructions.length).to.be.equal(2);
+        expect(proposalState.revision).to.be.equal(1);
+    });
+
+    it("proposer updates meta of draft proposal", async () => {
+        const title = "Set locker params of the voter program";
+        await updateProposalMeta(wallet, title);
+
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const proposalMetaState = await governProgram.account.proposalMeta.fetch(
+            proposalMeta
+        );
+        expect(proposalMetaState.title).to.be.equal(title);
+        expect(proposalMetaState.descriptionLink).to.be.equal(
+            "https://example.com/proposal"
+        );
+
+        const proposalState = await governProgram.account.proposal.fetch(proposal);
+        expect(proposalState.revision).to.be.equal(2);
+    });
+
+    it("only proposer can update draft proposal", async () => {
+        const { wallet: otherWallet } = await createAndFundWallet(provider.connection);
+
+        await invokeAndAssertError(
+            () => {
+                return updateProposalInstructions(otherWallet, 1);
+            },
+            "A has one constraint was violated",
+            true
+        );
+        await invokeAndAssertError(
+            () => {
+                return updateProposalMeta(otherWallet, "Spam");
+            },
+            "A has one constraint was violated",
+            true
+        );
+    });
+
+    it("cannot update proposal once activated", async () => {
+        await createLockedVoterProgram(voterWallet, LOCKED_VOTER_PROGRAM_ID)
+            .methods.activateProposalWithEscrow()
+            .accounts({
+                locker,
+                governor: govern,
+                proposal,
+                escrow: voterEscrow,
+                authority: voterWallet.publicKey,
+                governProgram: GOVERN_PROGRAM_ID,
+            })
+            .rpc();
+
+        await invokeAndAssertError(
+            () => {
+                return updateProposalInstructions(wallet, 1);
+            },
+            "Only drafts can be canceled.",
+            true
+        );
+        await invokeAndAssertError(
+            () => {
+                return updateProposalMeta(wallet, "Too late");
+            },
+            "Only drafts can be canceled.",
+            true
+        );
+    });
+
+    it("proposer updates option descriptions of draft option proposal", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const optionProposal = await createOptionProposal(
+            govern,
+            [getSetLockerParamsInstruction()],
+            governProgram,
+            3
+        );
+        const optionProposalMeta = await createOptionProposalMeta(
+            optionProposal,
+            ["A", "B", "C"],
+            governProgram
+        );
+
+        const updateOptionProposalMeta = (optionDescriptions: string[]) => {
+            return governProgram.methods
+                .updateOptionProposalMeta(optionDescriptions)
+                .accounts({
+                    proposal: optionProposal,
+                    proposer: wallet.publicKey,
+                    optionProposalMeta,
+                    payer: wallet.publicKey,
+                    systemProgram: web3.SystemProgram.programId,
+                })
+                .rpc();
+        };
+
+        await invokeAndAssertError(
+            () => {
+                return updateOptionProposalMeta(["Option A", "Option B"]);
+            },
+            "Invalid option descriptions.",
+            true
+        );
+
+        const optionDescriptions = ["Option A", "Option B", "Option C"];
+        await updateOptionProposalMeta(optionDescriptions);
+
+        const optionProposalMetaState =
+            await governProgram.account.optionProposalMeta.fetch(optionProposalMeta);
+        expect(optionProposalMetaState.optionDescriptions).to.be.deep.equal(
+            optionDescriptions
+        );
+
+        const proposalState = await governProgram.account.proposal.fetch(
+            optionProposal
+        );
+        expect(proposalState.revision).to.be.equal(1);
+    });
+});
@@ -0,0 +1,320 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Wallet, web3 } from "@coral-xyz/anchor";
+import {
+    GOVERN_PROGRAM_ID,
+    IProposalInstruction,
+    LOCKED_VOTER_PROGRAM_ID,
+    SMART_WALLET_PROGRAM_ID,
+    VoteSide,
+    castVoteWithEscrow,
+    createAndFundWallet,
+    createGovernProgram,
+    createLockedVoterGovernance,
+    createLockedVoterProgram,
+    createMaxLockedEscrow,
+    createProposal,
+    createSetLockerParamsInstruction,
+    createSmartWalletProgram,
+    deriveTransaction,
+    invokeAndAssertError,
+    invokeSmartWalletInstruction,
+    queueProposal,
+    waitUntilOnChainTime,
+} from "../utils";
+import { expect } from "chai";
+
+const provider = anchor.AnchorProvider.env();
+
+const lockAmount = new BN(100);
+
+describe("Veto proposal", () => {
+    let locker: web3.PublicKey;
+    let govern: web3.PublicKey;
+    let smartWallet: web3.PublicKey;
+
+    let wallet: Wallet;
+    let keypair: web3.Keypair;
+
+    let voterWallet: Wallet;
+    let voterEscrow: web3.PublicKey;
+    let vetoAuthority: Wallet;
+
+    // proposals queued together, vetoed before and after their timelock elapsed
+    let proposal: web3.PublicKey;
+    let transaction: web3.PublicKey;
+    let lateProposal: web3.PublicKey;
+    let lateTransaction: web3.PublicKey;
+
+    // Govern config
+    const votingPeriod: BN = new BN(5); // 5 seconds duration of voting on proposal
+    const quorumVotes: BN = new BN(2); // 2 vote to pass
+    const timelockDelaySeconds: BN = new BN(10); // 10 seconds to veto a queued proposal
+
+    // Voter config
+    const lockerParams = {
+        maxStakeDuration: new BN(20), // 20 seconds
+        maxStakeVoteMultiplier: 1,
+        minStakeDuration: new BN(10), // 10 seconds
+        proposalActivationMinVotes: new BN(2), // min 2 vote to activate proposal
+    };
+
+    async function createActiveProposal() {
+        const governProgram = createGovernProgram(voterWallet, GOVERN_PROGRAM_ID);
+        const voterProgram = createLockedVoterProgram(voterWallet, LOCKED_VOTER_PROGRAM_ID);
+        const proposal = await createProposal(
+            govern,
+            [
+                createSetLockerParamsInstruction(
+                    locker,
+                    govern,
+                    smartWallet,
+                    lockerParams,
+                    voterProgram
+                ),
+            ],
+            governProgram
+        );
+
+        await voterProgram.methods
+            .activateProposalWithEscrow()
+            .accounts({
+                locker,
+                governor: govern,
+                proposal,
+                escrow: voterEscrow,
+                authority: voterWallet.publicKey,
+                governProgram: GOVERN_PROGRAM_ID,
+            })
+            .rpc();
+
+        await castVoteWithEscrow(
+            locker,
+            govern,
+            proposal,
+            voterEscrow,
+            VoteSide.For,
+            voterProgram,
+            governProgram
+        );
+
+        return proposal;
+    }
+
+    function vetoProposal(
+        proposal: web3.PublicKey,
+        transaction: web3.PublicKey,
+        authority: Wallet
+    ) {
+        const governProgram = createGovernProgram(authority, GOVERN_PROGRAM_ID);
+        return governProgram.methods
+            .vetoProposal("malicious proposal")
+            .accounts({
+                governor: govern,
+                proposal,
+                transaction,
+                smartWallet,
+                vetoAuthority: authority.publicKey,
+                smartWalletProgram: SMART_WALLET_PROGRAM_ID,
+            })
+            .rpc();
+    }
+
+    before(async () => {
+        const result = await createLockedVoterGovernance(
+            votingPeriod,
+            quorumVotes,
+            timelockDelaySeconds,
+            lockerParams,
+            provider.connection
+        );
+        keypair = result.keypair;
+        wallet = result.wallet;
+        locker = result.locker;
+        govern = result.governor;
+        smartWallet = result.smartWallet;
+
+        const voter = await createMaxLockedEscrow(
+            locker,
+            result.tokenMint,
+            lockAmount,
+            keypair,
+            provider.connection
+        );
+        voterWallet = voter.wallet;
+        voterEscrow = voter.escrow;
+
+        vetoAuthority = (await createAndFundWallet(provider.connection)).wallet;
+    });
+
+    it("protocol team sets veto authority", async () => {
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+
+        const setVetoAuthorityIx: IProposalInstruction = {
+            programId: GOVERN_PROGRAM_ID,
+            data: governProgram.coder.instruction.encode("set_veto_authority", {
+                vetoAuthority: vetoAuthority.publicKey,
+            }),
+            keys: [
+                {
+                    isSigner: false,
+                    isWritable: true,
+                    pubkey: govern,
+                },
+                {
+                    isSigner: true,
+                    isWritable: false,
+                    pubkey: smartWallet,
+                },
+            ],
+        };
+
+        await invokeSmartWalletInstruction(
+            smartWallet,
+            setVetoAuthorityIx,
+            createSmartWalletProgram(wallet, SMART_WALLET_PROGRAM_ID)
+        );
+
+        const governorState = await governProgram.account.governor.fetch(govern);
+        expect(governorState.vetoAuthority.toBase58()).to.be.equal(
+            vetoAuthority.publicKey.toBase58()
+        );
+    });
+
+    it("voter passes and queues proposals", async () => {
+        proposal = await createActiveProposal();
+        lateProposal = await createActiveProposal();
+
+        const governProgram = createGovernProgram(voterWallet, GOVERN_PROGRAM_ID);
+        const smartWalletProgram = createSmartWalletProgram(
+            voterWallet,
+            SMART_WALLET_PROGRAM_ID
+        );
+
+        const proposalState = await governProgram.account.proposal.fetch(
+            lateProposal
+        );
+        await waitUntilOnChainTime(
+            proposalState.votingEndsAt.toNumber() + 1,
+            provider.connection
+        );
+
+        transaction = await queueProposal(
+            govern,
+            proposal,
+            governProgram,
+            smartWalletProgram
+        );
+        lateTransaction = await queueProposal(
+            govern,
+            lateProposal,
+            governProgram,
+            smartWalletProgram
+        );
+
+        const transactionState = await smartWalletProgram.account.transaction.fetch(
+            transaction
+        );
+        expect(transactionState.proposer.toBase58()).to.be.equal(govern.toBase58());
+        expect(transactionState.eta.toNumber()).to.be.greaterThan(0);
+    });
+
+    it("only veto authority can veto proposal", async () => {
+        await invokeAndAssertError(
+            () => {
+                return vetoProposal(proposal, transaction, voterWallet);
+            },
+            "A has one constraint was violated",
+            true
+        );
+    });
+
+    it("veto authority vetoes queued proposal during the timelock", async () => {
+        await vetoProposal(proposal, transaction, vetoAuthority);
+
+        const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+        const proposalState = await governProgram.account.proposal.fetch(proposal);
+        expect(proposalState.vetoedAt.toString()).not.equal("0");
+
+        const transactionAccount = await provider.connection.getAccountInfo(
+            transaction
+        );
+        expect(transactionAccount).to.be.null;
+    });
+
+    it("cannot veto proposal after the timelock elapsed", async () => {
+        const smartWalletProgram = createSmartWalletProgram(
+            wallet,
+            SMART_WALLET_PROGRAM_ID
+        );
+        const transactionState = await smartWalletProgram.account.transaction.fetch(
+            lateTransaction
+        );
+        await waitUntilOnChainTime(
+            transactionState.eta.toNumber() + 1,
+            provider.connection
+        );
+
+        await invokeAndAssertError(
+            () => {
+                return vetoProposal(lateProposal, lateTransaction, vetoAuthority);
+            },
+            "The timelock of the queued transaction has elapsed.",
+            true
+        );
+    });
+
+    it("owner cannot cancel a transaction proposed by a keypair", async () => {
+        const smartWalletProgram = createSmartWalletProgram(
+            wallet,
+            SMART_WALLET_PROGRAM_ID
+        );
+        const voterProgram = createLockedVoterProgram(wallet, LOCKED_VOTER_PROGRAM_ID);
+        const smartWalletState = await smartWalletProgram.account.smartWallet.fetch(
+            smartWallet
+        );
+        const [ownerTransaction, txBump] = deriveTransaction(
+            smartWallet,
+            smartWalletState.numTransactions
+        );
+
+        await smartWalletProgram.methods
+            .createTransaction(txBump, [
+                createSetLockerParamsInstruction(
+                    locker,
+                    govern,
+                    smartWallet,
+                    lockerParams,
+                    voterProgram
+                ),
+            ])
+            .accounts({
+                payer: smartWalletProgram.provider.publicKey,
+                proposer: smartWalletProgram.provider.publicKey,
+                smartWallet,
+                systemProgram: web3.SystemProgram.programId,
+                transaction: ownerTransaction,
+            })
+            .rpc();
+
+        await invokeAndAssertError(
+            () => {
+                return smartWalletProgram.methods
+                    .cancelTransaction()
+                    .accounts({
+                        smartWallet,
+                        transaction: ownerTransaction,
+                        proposer: smartWalletProgram.provider.publicKey,
+                        receiver: smartWalletProgram.provider.publicKey,
+                    })
+                    .rpc();
+            },
+            "Only transactions proposed by a program address can be canceled.",
+            true
+        );
+
+        const transactionAccount = await provider.connection.getAccountInfo(
+            ownerTransaction
+        );
+        expect(transactionAccount).to.be.not.null;
+    });
+});
@@ -0,0 +1,209 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Wallet, web3 } from "@coral-xyz/anchor";
+import {
+  GOVERN_PROGRAM_ID,
+  LOCKED_VOTER_PROGRAM_ID,
+  createAndFundWallet,
+  createGovernProgram,
+  createLockedVoterGovernance,
+  createLockedVoterProgram,
+  createMaxLockedEscrow,
+  createProposal,
+  createSetLockerParamsInstruction,
+  invokeAndAssertError,
+} from "../utils";
+import { expect } from "chai";
+
+const provider = anchor.AnchorProvider.env();
+
+describe("Activate proposal with escrow", () => {
+  let locker: web3.PublicKey;
+  let govern: web3.PublicKey;
+  let smartWallet: web3.PublicKey;
+
+  let wallet: Wallet;
+  let keypair: web3.Keypair;
+
+  let tokenMint: web3.PublicKey;
+
+  // Govern config
+  const votingPeriod: BN = new BN(10); // 10 seconds duration of voting on proposal
+  const quorumVotes: BN = new BN(2); // 2 vote to pass
+
+  // Voter config
+  const lockerParams = {
+    maxStakeDuration: new BN(20), // 20 seconds
+    maxStakeVoteMultiplier: 1,
+    minStakeDuration: new BN(10), // 10 seconds
+    proposalActivationMinVotes: new BN(100), // min 100 vote to activate proposal
+  };
+
+  // Max locked escrows have a voting power of amount * maxStakeVoteMultiplier
+  let thresholdWallet: Wallet;
+  let thresholdEscrow: web3.PublicKey;
+  let belowThresholdWallet: Wallet;
+  let belowThresholdEscrow: web3.PublicKey;
+
+  async function createDraftProposal() {
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    const voterProgram = createLockedVoterProgram(wallet, LOCKED_VOTER_PROGRAM_ID);
+    const ix = createSetLockerParamsInstruction(
+      locker,
+      govern,
+      smartWallet,
+      lockerParams,
+      voterProgram
+    );
+
+    return createProposal(govern, [ix], governProgram);
+  }
+
+  function activateProposalWithEscrow(
+    proposal: web3.PublicKey,
+    escrow: web3.PublicKey,
+    authority: Wallet
+  ) {
+    const voterProgram = createLockedVoterProgram(authority, LOCKED_VOTER_PROGRAM_ID);
+    return voterProgram.methods
+      .activateProposalWithEscrow()
+      .accounts({
+        locker,
+        governor: govern,
+        proposal,
+        escrow,
+        authority: authority.publicKey,
+        governProgram: GOVERN_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    const result = await createLockedVoterGovernance(
+      votingPeriod,
+      quorumVotes,
+      new BN(0),
+      lockerParams,
+      provider.connection
+    );
+    keypair = result.keypair;
+    wallet = result.wallet;
+    locker = result.locker;
+    govern = result.governor;
+    smartWallet = result.smartWallet;
+    tokenMint = result.tokenMint;
+
+    const threshold = await createMaxLockedEscrow(
+      locker,
+      tokenMint,
+      lockerParams.proposalActivationMinVotes,
+      keypair,
+      provider.connection
+    );
+    thresholdWallet = threshold.wallet;
+    thresholdEscrow = threshold.escrow;
+
+    const belowThreshold = await createMaxLockedEscrow(
+      locker,
+      tokenMint,
+      lockerParams.proposalActivationMinVotes.sub(new BN(1)),
+      keypair,
+      provider.connection
+    );
+    belowThresholdWallet = belowThreshold.wallet;
+    belowThresholdEscrow = belowThreshold.escrow;
+  });
+
+  it("cannot activate proposal with voting power below the activation threshold", async () => {
+    const proposal = await createDraftProposal();
+
+    await invokeAndAssertError(
+      () => {
+        return activateProposalWithEscrow(
+          proposal,
+          belowThresholdEscrow,
+          belowThresholdWallet
+        );
+      },
+      "Insufficient voting power to activate a proposal",
+      true
+    );
+
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    const proposalState = await governProgram.account.proposal.fetch(proposal);
+    expect(proposalState.activatedAt.toString()).to.be.equal("0");
+  });
+
+  it("cannot activate proposal with an escrow of another owner", async () => {
+    const proposal = await createDraftProposal();
+    const { wallet: otherWallet } = await createAndFundWallet(
+      provider.connection
+    );
+
+    await invokeAndAssertError(
+      () => {
+        return activateProposalWithEscrow(proposal, thresholdEscrow, otherWallet);
+      },
+      "Authority must be the escrow owner or its vote delegate",
+      true
+    );
+  });
+
+  it("escrow owner at the activation threshold activates proposal", async () => {
+    const proposal = await createDraftProposal();
+
+    await activateProposalWithEscrow(proposal, thresholdEscrow, thresholdWallet);
+
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    const proposalState = await governProgram.account.proposal.fetch(proposal);
+    expect(proposalState.activatedAt.toString()).not.equal("0");
+    expect(proposalState.votingEndsAt.toNumber()).to.be.equal(
+      proposalState.activatedAt.add(votingPeriod).toNumber()
+    );
+
+    await invokeAndAssertError(
+      () => {
+        return activateProposalWithEscrow(proposal, thresholdEscrow, thresholdWallet);
+      },
+      "Only drafts can be canceled.",
+      false
+    );
+  });
+
+  it("vote delegate activates proposal with the delegated escrow", async () => {
+    const proposal = await createDraftProposal();
+    const { wallet: delegateWallet } = await createAndFundWallet(
+      provider.connection
+    );
+
+    const voterProgram = createLockedVoterProgram(
+      thresholdWallet,
+      LOCKED_VOTER_PROGRAM_ID
+    );
+    await voterProgram.methods
+      .setVoteDelegate(delegateWallet.publicKey)
+      .accounts({
+        locker,
+        governor: govern,
+        escrow: thresholdEscrow,
+        escrowOwner: thresholdWallet.publicKey,
+        oldDelegateRecord: null,
+        newDelegateRecord: null,
+      })
+      .rpc();
+
+    await activateProposalWithEscrow(proposal, thresholdEscrow, delegateWallet);
+
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    const proposalState = await governProgram.account.proposal.fetch(proposal);
+    expect(proposalState.activatedAt.toString()).not.equal("0");
+
+    // the owner keeps the right to activate proposals after delegating
+    const ownerProposal = await createDraftProposal();
+    await activateProposalWithEscrow(ownerProposal, thresholdEscrow, thresholdWallet);
+
+    const ownerProposalState = await governProgram.account.proposal.fetch(
+      ownerProposal
+    );
+    expect(ownerProposalState.activatedAt.toString()).not.equal("0");
+  });
+});
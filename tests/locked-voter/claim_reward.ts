@@ -0,0 +1,264 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Wallet, web3 } from "@coral-xyz/anchor";
+import {
+  TOKEN_PROGRAM_ID,
+  createAccount,
+  createMint,
+} from "@solana/spl-token";
+import {
+  GOVERN_PROGRAM_ID,
+  LOCKED_VOTER_PROGRAM_ID,
+  VoteSide,
+  castVoteWithEscrow,
+  createAndFundWallet,
+  createGovernProgram,
+  createLockedVoterGovernance,
+  createLockedVoterProgram,
+  createMaxLockedEscrow,
+  createProposal,
+  createSetLockerParamsInstruction,
+  deriveEventAuthority,
+  deriveVote,
+  getOrCreateATA,
+  invokeAndAssertError,
+  setVotingReward,
+  waitUntilOnChainTime,
+} from "../utils";
+import { expect } from "chai";
+
+const provider = anchor.AnchorProvider.env();
+
+describe("Claim reward with vote delegate", () => {
+  let locker: web3.PublicKey;
+  let govern: web3.PublicKey;
+  let smartWallet: web3.PublicKey;
+  let proposal: web3.PublicKey;
+
+  let wallet: Wallet;
+  let keypair: web3.Keypair;
+
+  let ownerWallet: Wallet;
+  let escrow: web3.PublicKey;
+  let delegateWallet: Wallet;
+
+  let votingRewardMint: web3.PublicKey;
+  let rewardVault: web3.PublicKey;
+
+  // Govern config
+  const votingPeriod: BN = new BN(5); // 5 seconds duration of voting on proposal
+  const quorumVotes: BN = new BN(2); // 2 vote to pass
+
+  // Voter config
+  const lockerParams = {
+    maxStakeDuration: new BN(20), // 20 seconds
+    maxStakeVoteMultiplier: 1,
+    minStakeDuration: new BN(10), // 10 seconds
+    proposalActivationMinVotes: new BN(2), // min 2 vote to activate proposal
+  };
+
+  const rewardPerProposal = new BN(100000);
+
+  function claimReward(
+    voteDelegate: Wallet,
+    ownerTokenAccount: web3.PublicKey
+  ) {
+    const voterProgram = createLockedVoterProgram(
+      voteDelegate,
+      LOCKED_VOTER_PROGRAM_ID
+    );
+    const [vote, _vBump] = deriveVote(ownerWallet.publicKey, proposal);
+    const [governEventAuthority, _eBump] =
+      deriveEventAuthority(GOVERN_PROGRAM_ID);
+    return voterProgram.methods
+      .claimReward()
+      .accounts({
+        locker,
+        escrow,
+        voteDelegate: voteDelegate.publicKey,
+        governor: govern,
+        rewardVault,
+        proposal,
+        vote,
+        owner: ownerWallet.publicKey,
+        ownerTokenAccount,
+        proposalRewards: null,
+        governEventAuthority,
+        governProgram: GOVERN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    const result = await createLockedVoterGovernance(
+      votingPeriod,
+      quorumVotes,
+      new BN(0),
+      lockerParams,
+      provider.connection
+    );
+    keypair = result.keypair;
+    wallet = result.wallet;
+    locker = result.locker;
+    govern = result.governor;
+    smartWallet = result.smartWallet;
+
+    const owner = await createMaxLockedEscrow(
+      locker,
+      result.tokenMint,
+      new BN(100),
+      keypair,
+      provider.connection
+    );
+    ownerWallet = owner.wallet;
+    escrow = owner.escrow;
+
+    delegateWallet = (await createAndFundWallet(provider.connection)).wallet;
+
+    votingRewardMint = await createMint(
+      provider.connection,
+      keypair,
+      keypair.publicKey,
+      null,
+      9
+    );
+    rewardVault = await setVotingReward(
+      govern,
+      smartWallet,
+      votingRewardMint,
+      rewardPerProposal,
+      keypair,
+      wallet
+    );
+
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    const voterProgram = createLockedVoterProgram(
+      wallet,
+      LOCKED_VOTER_PROGRAM_ID
+    );
+    proposal = await createProposal(
+      govern,
+      [
+        createSetLockerParamsInstruction(
+          locker,
+          govern,
+          smartWallet,
+          lockerParams,
+          voterProgram
+        ),
+      ],
+      governProgram
+    );
+  });
+
+  it("owner votes and delegates the escrow", async () => {
+    const voterProgram = createLockedVoterProgram(
+      ownerWallet,
+      LOCKED_VOTER_PROGRAM_ID
+    );
+    await voterProgram.methods
+      .activateProposalWithEscrow()
+      .accounts({
+        locker,
+        governor: govern,
+        proposal,
+        escrow,
+        authority: ownerWallet.publicKey,
+        governProgram: GOVERN_PROGRAM_ID,
+      })
+      .rpc();
+
+    await castVoteWithEscrow(
+      locker,
+      govern,
+      proposal,
+      escrow,
+      VoteSide.For,
+      voterProgram,
+      createGovernProgram(ownerWallet, GOVERN_PROGRAM_ID)
+    );
+
+    await voterProgram.methods
+      .setVoteDelegate(delegateWallet.publicKey)
+      .accounts({
+        locker,
+        governor: govern,
+        escrow,
+        escrowOwner: ownerWallet.publicKey,
+        oldDelegateRecord: null,
+        newDelegateRecord: null,
+      })
+      .rpc();
+
+    const escrowState = await voterProgram.account.escrow.fetch(escrow);
+    expect(escrowState.voteDelegate.toBase58()).to.be.equal(
+      delegateWallet.publicKey.toBase58()
+    );
+  });
+
+  it("only the vote delegate of the escrow can claim on behalf of the owner", async () => {
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    const proposalState = await governProgram.account.proposal.fetch(proposal);
+    await waitUntilOnChainTime(
+      proposalState.votingEndsAt.toNumber() + 1,
+      provider.connection
+    );
+
+    const { wallet: otherWallet } = await createAndFundWallet(
+      provider.connection
+    );
+    const ownerTokenAccount = await getOrCreateATA(
+      votingRewardMint,
+      ownerWallet.publicKey,
+      keypair,
+      provider.connection
+    );
+
+    await invokeAndAssertError(
+      () => {
+        return claimReward(otherWallet, ownerTokenAccount);
+      },
+      "Keys do not match",
+      false
+    );
+  });
+
+  it("vote delegate cannot claim to a token account other than the owner's associated token account", async () => {
+    const ownerTokenAccount = await createAccount(
+      provider.connection,
+      keypair,
+      votingRewardMint,
+      ownerWallet.publicKey,
+      web3.Keypair.generate()
+    );
+
+    await invokeAndAssertError(
+      () => {
+        return claimReward(delegateWallet, ownerTokenAccount);
+      },
+      "Must be withdraw to owner associated token account",
+      false
+    );
+  });
+
+  it("vote delegate claims reward to the owner's associated token account", async () => {
+    const ownerTokenAccount = await getOrCreateATA(
+      votingRewardMint,
+      ownerWallet.publicKey,
+      keypair,
+      provider.connection
+    );
+
+    await claimReward(delegateWallet, ownerTokenAccount);
+
+    const balance = await provider.connection
+      .getTokenAccountBalance(ownerTokenAccount)
+      .then((b) => b.value.amount);
+    expect(balance).to.be.equal(rewardPerProposal.toString());
+
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    const [vote, _bump] = deriveVote(ownerWallet.publicKey, proposal);
+    const voteState = await governProgram.account.vote.fetch(vote);
+    expect(voteState.claimed).to.be.true;
+  });
+});
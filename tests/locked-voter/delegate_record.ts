@@ -0,0 +1,395 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Wallet, web3 } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, createMint } from "@solana/spl-token";
+import {
+  GOVERN_PROGRAM_ID,
+  LOCKED_VOTER_PROGRAM_ID,
+  VoteSide,
+  castVoteWithEscrow,
+  createAndFundWallet,
+  createGovernProgram,
+  createLockedVoterGovernance,
+  createLockedVoterProgram,
+  createMaxLockedEscrow,
+  createProposal,
+  createSetLockerParamsInstruction,
+  deriveDelegateRecord,
+  deriveVote,
+  getOrCreateATA,
+  invokeAndAssertError,
+  setVotingReward,
+  waitUntilOnChainTime,
+} from "../utils";
+import { expect } from "chai";
+
+const provider = anchor.AnchorProvider.env();
+
+describe("Delegate record", () => {
+  let locker: web3.PublicKey;
+  let govern: web3.PublicKey;
+  let smartWallet: web3.PublicKey;
+  let proposal: web3.PublicKey;
+
+  let wallet: Wallet;
+  let keypair: web3.Keypair;
+
+  let delegateWallet: Wallet;
+  let delegateRecord: web3.PublicKey;
+
+  // escrows aggregated into the delegate record
+  let delegatorWallets: Wallet[] = [];
+  let delegatorEscrows: web3.PublicKey[] = [];
+
+  let voterWallet: Wallet;
+  let voterEscrow: web3.PublicKey;
+
+  let votingRewardMint: web3.PublicKey;
+  let rewardVault: web3.PublicKey;
+
+  // Govern config
+  const votingPeriod: BN = new BN(5); // 5 seconds duration of voting on proposal
+  const quorumVotes: BN = new BN(2); // 2 vote to pass
+
+  // Voter config
+  const lockerParams = {
+    maxStakeDuration: new BN(20), // 20 seconds
+    maxStakeVoteMultiplier: 1,
+    minStakeDuration: new BN(10), // 10 seconds
+    proposalActivationMinVotes: new BN(2), // min 2 vote to activate proposal
+  };
+
+  // Max locked escrows have a voting power of amount * maxStakeVoteMultiplier
+  const delegatorLockAmounts = [new BN(100), new BN(50)];
+  const voterLockAmount = new BN(100);
+
+  const rewardPerProposal = new BN(100000);
+
+  function setVoteDelegate(
+    index: number,
+    newDelegate: web3.PublicKey,
+    oldDelegateRecord: web3.PublicKey | null,
+    newDelegateRecord: web3.PublicKey | null
+  ) {
+    const voterProgram = createLockedVoterProgram(
+      delegatorWallets[index],
+      LOCKED_VOTER_PROGRAM_ID
+    );
+    return voterProgram.methods
+      .setVoteDelegate(newDelegate)
+      .accounts({
+        locker,
+        governor: govern,
+        escrow: delegatorEscrows[index],
+        escrowOwner: delegatorWallets[index].publicKey,
+        oldDelegateRecord,
+        newDelegateRecord,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    const result = await createLockedVoterGovernance(
+      votingPeriod,
+      quorumVotes,
+      new BN(0),
+      lockerParams,
+      provider.connection
+    );
+    keypair = result.keypair;
+    wallet = result.wallet;
+    locker = result.locker;
+    govern = result.governor;
+    smartWallet = result.smartWallet;
+
+    for (const lockAmount of delegatorLockAmounts) {
+      const delegator = await createMaxLockedEscrow(
+        locker,
+        result.tokenMint,
+        lockAmount,
+        keypair,
+        provider.connection
+      );
+      delegatorWallets.push(delegator.wallet);
+      delegatorEscrows.push(delegator.escrow);
+    }
+
+    const voter = await createMaxLockedEscrow(
+      locker,
+      result.tokenMint,
+      voterLockAmount,
+      keypair,
+      provider.connection
+    );
+    voterWallet = voter.wallet;
+    voterEscrow = voter.escrow;
+
+    delegateWallet = (await createAndFundWallet(provider.connection)).wallet;
+    [delegateRecord] = deriveDelegateRecord(locker, delegateWallet.publicKey);
+
+    votingRewardMint = await createMint(
+      provider.connection,
+      keypair,
+      keypair.publicKey,
+      null,
+      9
+    );
+    rewardVault = await setVotingReward(
+      govern,
+      smartWallet,
+      votingRewardMint,
+      rewardPerProposal,
+      keypair,
+      wallet
+    );
+  });
+
+  it("escrows are aggregated into the delegate record", async () => {
+    const voterProgram = createLockedVoterProgram(
+      delegateWallet,
+      LOCKED_VOTER_PROGRAM_ID
+    );
+    await voterProgram.methods
+      .newDelegateRecord()
+      .accounts({
+        locker,
+        delegateRecord,
+        delegate: delegateWallet.publicKey,
+        payer: delegateWallet.publicKey,
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    for (let i = 0; i < delegatorEscrows.length; i++) {
+      await setVoteDelegate(
+        i,
+        delegateWallet.publicKey,
+        null,
+        delegateRecord
+      );
+
+      const escrowState = await voterProgram.account.escrow.fetch(
+        delegatorEscrows[i]
+      );
+      expect(escrowState.aggregatedAmount.toString()).to.be.equal(
+        delegatorLockAmounts[i].toString()
+      );
+    }
+
+    const delegateRecordState = await voterProgram.account.delegateRecord.fetch(
+      delegateRecord
+    );
+    expect(delegateRecordState.delegatedAmount.toNumber()).to.be.equal(
+      delegatorLockAmounts[0].add(delegatorLockAmounts[1]).toNumber()
+    );
+    expect(delegateRecordState.escrowCount.toNumber()).to.be.equal(
+      delegatorEscrows.length
+    );
+  });
+
+  it("aggregated escrow cannot vote on its own", async () => {
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    const voterProgram = createLockedVoterProgram(
+      voterWallet,
+      LOCKED_VOTER_PROGRAM_ID
+    );
+    proposal = await createProposal(
+      govern,
+      [
+        createSetLockerParamsInstruction(
+          locker,
+          govern,
+          smartWallet,
+          lockerParams,
+          voterProgram
+        ),
+      ],
+      governProgram
+    );
+    await voterProgram.methods
+      .activateProposalWithEscrow()
+      .accounts({
+        locker,
+        governor: govern,
+        proposal,
+        escrow: voterEscrow,
+        authority: voterWallet.publicKey,
+        governProgram: GOVERN_PROGRAM_ID,
+      })
+      .rpc();
+
+    await invokeAndAssertError(
+      () => {
+        return castVoteWithEscrow(
+          locker,
+          govern,
+          proposal,
+          delegatorEscrows[0],
+          VoteSide.For,
+          createLockedVoterProgram(delegateWallet, LOCKED_VOTER_PROGRAM_ID),
+          createGovernProgram(delegateWallet, GOVERN_PROGRAM_ID)
+        ).then((vote) => vote.toBase58());
+      },
+      "Escrow is aggregated into a delegate record",
+      true
+    );
+  });
+
+  it("delegate casts the aggregated voting power in a single vote", async () => {
+    const governProgram = createGovernProgram(delegateWallet, GOVERN_PROGRAM_ID);
+    const [vote] = deriveVote(delegateRecord, proposal);
+    await governProgram.methods
+      .newVote(delegateRecord)
+      .accounts({
+        payer: delegateWallet.publicKey,
+        proposal,
+        systemProgram: web3.SystemProgram.programId,
+        vote,
+      })
+      .rpc();
+
+    await createLockedVoterProgram(delegateWallet, LOCKED_VOTER_PROGRAM_ID)
+      .methods.castDelegatedVote(VoteSide.For)
+      .accounts({
+        locker,
+        delegateRecord,
+        delegate: delegateWallet.publicKey,
+        proposal,
+        vote,
+        governor: govern,
+        governProgram: GOVERN_PROGRAM_ID,
+      })
+      .rpc();
+
+    await castVoteWithEscrow(
+      locker,
+      govern,
+      proposal,
+      voterEscrow,
+      VoteSide.For,
+      createLockedVoterProgram(voterWallet, LOCKED_VOTER_PROGRAM_ID),
+      createGovernProgram(voterWallet, GOVERN_PROGRAM_ID)
+    );
+
+    const delegatedVotes = delegatorLockAmounts[0].add(delegatorLockAmounts[1]);
+    const voteState = await governProgram.account.vote.fetch(vote);
+    expect(voteState.votingPower.toString()).to.be.equal(
+      delegatedVotes.toString()
+    );
+    expect(voteState.unrewarded).to.be.true;
+
+    const proposalState = await governProgram.account.proposal.fetch(proposal);
+    expect(proposalState.optionVotes[VoteSide.For].toString()).to.be.equal(
+      delegatedVotes.add(voterLockAmount).toString()
+    );
+    expect(proposalState.unrewardedVotingPower.toString()).to.be.equal(
+      delegatedVotes.toString()
+    );
+  });
+
+  it("escrow cannot leave the delegate record while its votes may be counted", async () => {
+    await invokeAndAssertError(
+      () => {
+        return setVoteDelegate(
+          1,
+          delegatorWallets[1].publicKey,
+          delegateRecord,
+          null
+        );
+      },
+      "Votes may still be counted",
+      true
+    );
+  });
+
+  it("vote of the delegate record does not earn rewards", async () => {
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    const proposalState = await governProgram.account.proposal.fetch(proposal);
+    await waitUntilOnChainTime(
+      proposalState.votingEndsAt.toNumber() + 1,
+      provider.connection
+    );
+
+    const [vote] = deriveVote(delegateRecord, proposal);
+    const delegateRecordTokenAccount = await getOrCreateATA(
+      votingRewardMint,
+      delegateRecord,
+      keypair,
+      provider.connection
+    );
+
+    await invokeAndAssertError(
+      () => {
+        return governProgram.methods
+          .claimReward()
+          .accounts({
+            governor: govern,
+            rewardVault,
+            proposal,
+            vote,
+            voter: delegateRecord,
+            authority: null,
+            voterTokenAccount: delegateRecordTokenAccount,
+            proposalRewards: null,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+      },
+      "Votes of delegate records and delegation shares do not earn rewards.",
+      true
+    );
+
+    // the only rewarded voter earns the whole reward
+    const voterTokenAccount = await getOrCreateATA(
+      votingRewardMint,
+      voterWallet.publicKey,
+      keypair,
+      provider.connection
+    );
+    const [voterVote] = deriveVote(voterWallet.publicKey, proposal);
+    await createGovernProgram(voterWallet, GOVERN_PROGRAM_ID)
+      .methods.claimReward()
+      .accounts({
+        governor: govern,
+        rewardVault,
+        proposal,
+        vote: voterVote,
+        voter: voterWallet.publicKey,
+        authority: null,
+        voterTokenAccount,
+        proposalRewards: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    const balance = await provider.connection
+      .getTokenAccountBalance(voterTokenAccount)
+      .then((b) => b.value.amount);
+    expect(balance).to.be.equal(rewardPerProposal.toString());
+  });
+
+  it("escrow leaves the delegate record once voting is over", async () => {
+    await setVoteDelegate(
+      1,
+      delegatorWallets[1].publicKey,
+      delegateRecord,
+      null
+    );
+
+    const voterProgram = createLockedVoterProgram(
+      wallet,
+      LOCKED_VOTER_PROGRAM_ID
+    );
+    const delegateRecordState = await voterProgram.account.delegateRecord.fetch(
+      delegateRecord
+    );
+    expect(delegateRecordState.delegatedAmount.toString()).to.be.equal(
+      delegatorLockAmounts[0].toString()
+    );
+    expect(delegateRecordState.escrowCount.toNumber()).to.be.equal(1);
+
+    const escrowState = await voterProgram.account.escrow.fetch(
+      delegatorEscrows[1]
+    );
+    expect(escrowState.aggregatedAmount.toString()).to.be.equal("0");
+  });
+});
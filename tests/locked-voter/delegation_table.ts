@@ -0,0 +1,263 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Wallet, web3 } from "@coral-xyz/anchor";
+import {
+  GOVERN_PROGRAM_ID,
+  LOCKED_VOTER_PROGRAM_ID,
+  VoteSide,
+  createAndFundWallet,
+  createGovernProgram,
+  createLockedVoterGovernance,
+  createLockedVoterProgram,
+  createMaxLockedEscrow,
+  createProposal,
+  createSetLockerParamsInstruction,
+  deriveDelegationShareVoter,
+  deriveDelegationTable,
+  deriveVote,
+  invokeAndAssertError,
+} from "../utils";
+import { expect } from "chai";
+
+const provider = anchor.AnchorProvider.env();
+
+describe("Delegation table", () => {
+  let locker: web3.PublicKey;
+  let govern: web3.PublicKey;
+  let smartWallet: web3.PublicKey;
+  let proposal: web3.PublicKey;
+
+  let wallet: Wallet;
+  let keypair: web3.Keypair;
+
+  let ownerWallet: Wallet;
+  let escrow: web3.PublicKey;
+  let delegationTable: web3.PublicKey;
+
+  let delegateWallets: Wallet[] = [];
+
+  // Govern config
+  const votingPeriod: BN = new BN(30); // 30 seconds duration of voting on proposal
+  const quorumVotes: BN = new BN(2); // 2 vote to pass
+
+  // Voter config
+  const lockerParams = {
+    maxStakeDuration: new BN(20), // 20 seconds
+    maxStakeVoteMultiplier: 1,
+    minStakeDuration: new BN(10), // 10 seconds
+    proposalActivationMinVotes: new BN(2), // min 2 vote to activate proposal
+  };
+
+  // Max locked escrows have a voting power of amount * maxStakeVoteMultiplier
+  const lockAmount = new BN(100);
+  const delegateBps = [6000, 4000];
+
+  function setDelegationTable(
+    delegates: { delegate: web3.PublicKey; bps: number }[]
+  ) {
+    const voterProgram = createLockedVoterProgram(
+      ownerWallet,
+      LOCKED_VOTER_PROGRAM_ID
+    );
+    return voterProgram.methods
+      .setDelegationTable(delegates)
+      .accounts({
+        locker,
+        governor: govern,
+        escrow,
+        escrowOwner: ownerWallet.publicKey,
+        delegationTable,
+      })
+      .rpc();
+  }
+
+  async function castShareVote(delegate: Wallet, side: VoteSide) {
+    const [shareVoter] = deriveDelegationShareVoter(escrow, delegate.publicKey);
+    const [vote] = deriveVote(shareVoter, proposal);
+
+    const voteAccount = await provider.connection.getAccountInfo(vote);
+    if (!voteAccount) {
+      await createGovernProgram(delegate, GOVERN_PROGRAM_ID)
+        .methods.newVote(shareVoter)
+        .accounts({
+          payer: delegate.publicKey,
+          proposal,
+          systemProgram: web3.SystemProgram.programId,
+          vote,
+        })
+        .rpc();
+    }
+
+    await createLockedVoterProgram(delegate, LOCKED_VOTER_PROGRAM_ID)
+      .methods.castVote(side)
+      .accounts({
+        escrow,
+        governor: govern,
+        governProgram: GOVERN_PROGRAM_ID,
+        locker,
+        proposal,
+        vote,
+        voteDelegate: delegate.publicKey,
+        delegationTable,
+      })
+      .rpc();
+
+    return vote;
+  }
+
+  before(async () => {
+    const result = await createLockedVoterGovernance(
+      votingPeriod,
+      quorumVotes,
+      new BN(0),
+      lockerParams,
+      provider.connection
+    );
+    keypair = result.keypair;
+    wallet = result.wallet;
+    locker = result.locker;
+    govern = result.governor;
+    smartWallet = result.smartWallet;
+
+    const owner = await createMaxLockedEscrow(
+      locker,
+      result.tokenMint,
+      lockAmount,
+      keypair,
+      provider.connection
+    );
+    ownerWallet = owner.wallet;
+    escrow = owner.escrow;
+    [delegationTable] = deriveDelegationTable(escrow);
+
+    for (let i = 0; i < delegateBps.length; i++) {
+      delegateWallets.push(
+        (await createAndFundWallet(provider.connection)).wallet
+      );
+    }
+
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    proposal = await createProposal(
+      govern,
+      [
+        createSetLockerParamsInstruction(
+          locker,
+          govern,
+          smartWallet,
+          lockerParams,
+          createLockedVoterProgram(wallet, LOCKED_VOTER_PROGRAM_ID)
+        ),
+      ],
+      governProgram
+    );
+  });
+
+  it("owner splits the escrow voting power across delegates", async () => {
+    const voterProgram = createLockedVoterProgram(
+      ownerWallet,
+      LOCKED_VOTER_PROGRAM_ID
+    );
+    await voterProgram.methods
+      .newDelegationTable()
+      .accounts({
+        escrow,
+        delegationTable,
+        payer: ownerWallet.publicKey,
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    await invokeAndAssertError(
+      () => {
+        return setDelegationTable([
+          { delegate: delegateWallets[0].publicKey, bps: delegateBps[0] },
+          { delegate: delegateWallets[1].publicKey, bps: delegateBps[0] },
+        ]);
+      },
+      "Delegation shares must be distinct, non-zero and sum up to 10000 bps",
+      true
+    );
+
+    await setDelegationTable(
+      delegateWallets.map((delegateWallet, i) => {
+        return { delegate: delegateWallet.publicKey, bps: delegateBps[i] };
+      })
+    );
+
+    const escrowState = await voterProgram.account.escrow.fetch(escrow);
+    expect(escrowState.hasDelegationTable).to.be.true;
+
+    const delegationTableState =
+      await voterProgram.account.delegationTable.fetch(delegationTable);
+    expect(delegationTableState.delegates.length).to.be.equal(
+      delegateBps.length
+    );
+  });
+
+  it("each delegate votes with their share on a separate vote", async () => {
+    await createLockedVoterProgram(ownerWallet, LOCKED_VOTER_PROGRAM_ID)
+      .methods.activateProposalWithEscrow()
+      .accounts({
+        locker,
+        governor: govern,
+        proposal,
+        escrow,
+        authority: ownerWallet.publicKey,
+        governProgram: GOVERN_PROGRAM_ID,
+      })
+      .rpc();
+
+    const forVote = await castShareVote(delegateWallets[0], VoteSide.For);
+    const againstVote = await castShareVote(
+      delegateWallets[1],
+      VoteSide.Against
+    );
+
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    const forVoteState = await governProgram.account.vote.fetch(forVote);
+    expect(forVoteState.votingPower.toNumber()).to.be.equal(
+      (lockAmount.toNumber() * delegateBps[0]) / 10000
+    );
+    expect(forVoteState.unrewarded).to.be.true;
+
+    const againstVoteState = await governProgram.account.vote.fetch(
+      againstVote
+    );
+    expect(againstVoteState.votingPower.toNumber()).to.be.equal(
+      (lockAmount.toNumber() * delegateBps[1]) / 10000
+    );
+
+    const proposalState = await governProgram.account.proposal.fetch(proposal);
+    expect(proposalState.optionVotes[VoteSide.For].toNumber()).to.be.equal(
+      forVoteState.votingPower.toNumber()
+    );
+    expect(proposalState.optionVotes[VoteSide.Against].toNumber()).to.be.equal(
+      againstVoteState.votingPower.toNumber()
+    );
+  });
+
+  it("only delegates of the table can vote with the escrow", async () => {
+    const { wallet: otherWallet } = await createAndFundWallet(
+      provider.connection
+    );
+
+    await invokeAndAssertError(
+      () => {
+        return castShareVote(otherWallet, VoteSide.For).then((vote) =>
+          vote.toBase58()
+        );
+      },
+      "Invariant failed: signer must be a delegate of the escrow",
+      false
+    );
+  });
+
+  it("owner cannot change the delegation table while votes may be counted", async () => {
+    await invokeAndAssertError(
+      () => {
+        return setDelegationTable([]);
+      },
+      "Votes may still be counted",
+      true
+    );
+  });
+});
@@ -509,6 +509,8 @@ describe("Locked voter", () => {
     await voterProgram.methods
       .setVoteDelegate(delegateWallet.publicKey)
       .accounts({
+        locker,
+        governor: govern,
         escrow,
         escrowOwner: wallet.publicKey,
       })
@@ -0,0 +1,211 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Wallet, web3 } from "@coral-xyz/anchor";
+import {
+  GOVERN_PROGRAM_ID,
+  LOCKED_VOTER_PROGRAM_ID,
+  VoteSide,
+  createAndFundWallet,
+  createGovernProgram,
+  createLockedVoterGovernance,
+  createLockedVoterProgram,
+  createMaxLockedEscrow,
+  createProposal,
+  createSetLockerParamsInstruction,
+  deriveVote,
+  invokeAndAssertError,
+} from "../utils";
+import { expect } from "chai";
+
+const provider = anchor.AnchorProvider.env();
+
+describe("Owner override of delegate vote", () => {
+  let locker: web3.PublicKey;
+  let govern: web3.PublicKey;
+  let smartWallet: web3.PublicKey;
+  let proposal: web3.PublicKey;
+  let vote: web3.PublicKey;
+
+  let wallet: Wallet;
+  let keypair: web3.Keypair;
+
+  let ownerWallet: Wallet;
+  let escrow: web3.PublicKey;
+  let delegateWallet: Wallet;
+
+  // Govern config
+  const votingPeriod: BN = new BN(30); // 30 seconds duration of voting on proposal
+  const quorumVotes: BN = new BN(2); // 2 vote to pass
+
+  // Voter config
+  const lockerParams = {
+    maxStakeDuration: new BN(20), // 20 seconds
+    maxStakeVoteMultiplier: 1,
+    minStakeDuration: new BN(10), // 10 seconds
+    proposalActivationMinVotes: new BN(2), // min 2 vote to activate proposal
+  };
+
+  // Max locked escrows have a voting power of amount * maxStakeVoteMultiplier
+  const lockAmount = new BN(100);
+
+  function castVote(signer: Wallet, side: VoteSide) {
+    const voterProgram = createLockedVoterProgram(
+      signer,
+      LOCKED_VOTER_PROGRAM_ID
+    );
+    return voterProgram.methods
+      .castVote(side)
+      .accounts({
+        escrow,
+        governor: govern,
+        governProgram: GOVERN_PROGRAM_ID,
+        locker,
+        proposal,
+        vote,
+        voteDelegate: signer.publicKey,
+        delegationTable: null,
+      })
+      .rpc();
+  }
+
+  async function expectVotes(forVotes: BN, againstVotes: BN) {
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    const proposalState = await governProgram.account.proposal.fetch(proposal);
+    expect(proposalState.optionVotes[VoteSide.For].toString()).to.be.equal(
+      forVotes.toString()
+    );
+    expect(proposalState.optionVotes[VoteSide.Against].toString()).to.be.equal(
+      againstVotes.toString()
+    );
+  }
+
+  before(async () => {
+    const result = await createLockedVoterGovernance(
+      votingPeriod,
+      quorumVotes,
+      new BN(0),
+      lockerParams,
+      provider.connection
+    );
+    keypair = result.keypair;
+    wallet = result.wallet;
+    locker = result.locker;
+    govern = result.governor;
+    smartWallet = result.smartWallet;
+
+    const owner = await createMaxLockedEscrow(
+      locker,
+      result.tokenMint,
+      lockAmount,
+      keypair,
+      provider.connection
+    );
+    ownerWallet = owner.wallet;
+    escrow = owner.escrow;
+
+    delegateWallet = (await createAndFundWallet(provider.connection)).wallet;
+
+    const voterProgram = createLockedVoterProgram(
+      ownerWallet,
+      LOCKED_VOTER_PROGRAM_ID
+    );
+    await voterProgram.methods
+      .setVoteDelegate(delegateWallet.publicKey)
+      .accounts({
+        locker,
+        governor: govern,
+        escrow,
+        escrowOwner: ownerWallet.publicKey,
+        oldDelegateRecord: null,
+        newDelegateRecord: null,
+      })
+      .rpc();
+
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    proposal = await createProposal(
+      govern,
+      [
+        createSetLockerParamsInstruction(
+          locker,
+          govern,
+          smartWallet,
+          lockerParams,
+          createLockedVoterProgram(wallet, LOCKED_VOTER_PROGRAM_ID)
+        ),
+      ],
+      governProgram
+    );
+
+    await voterProgram.methods
+      .activateProposalWithEscrow()
+      .accounts({
+        locker,
+        governor: govern,
+        proposal,
+        escrow,
+        authority: ownerWallet.publicKey,
+        governProgram: GOVERN_PROGRAM_ID,
+      })
+      .rpc();
+
+    // the vote of the owner, created by the delegate
+    [vote] = deriveVote(ownerWallet.publicKey, proposal);
+    await createGovernProgram(delegateWallet, GOVERN_PROGRAM_ID)
+      .methods.newVote(ownerWallet.publicKey)
+      .accounts({
+        payer: delegateWallet.publicKey,
+        proposal,
+        systemProgram: web3.SystemProgram.programId,
+        vote,
+      })
+      .rpc();
+  });
+
+  it("vote delegate votes with the escrow", async () => {
+    await castVote(delegateWallet, VoteSide.For);
+
+    await expectVotes(lockAmount, new BN(0));
+  });
+
+  it("only the owner or the vote delegate can vote with the escrow", async () => {
+    const { wallet: otherWallet } = await createAndFundWallet(
+      provider.connection
+    );
+
+    await invokeAndAssertError(
+      () => {
+        return castVote(otherWallet, VoteSide.Against);
+      },
+      "Keys do not match",
+      false
+    );
+  });
+
+  it("owner overrides the vote of the delegate", async () => {
+    await castVote(ownerWallet, VoteSide.Against);
+
+    await expectVotes(new BN(0), lockAmount);
+
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    const voteState = await governProgram.account.vote.fetch(vote);
+    expect(voteState.side).to.be.equal(VoteSide.Against);
+    expect(voteState.ownerOverride).to.be.true;
+  });
+
+  it("vote delegate cannot overwrite the override of the owner", async () => {
+    await invokeAndAssertError(
+      () => {
+        return castVote(delegateWallet, VoteSide.For);
+      },
+      "Invariant failed: the owner has overridden the delegate's vote",
+      false
+    );
+
+    await expectVotes(new BN(0), lockAmount);
+  });
+
+  it("owner changes the overriding vote", async () => {
+    await castVote(ownerWallet, VoteSide.For);
+
+    await expectVotes(lockAmount, new BN(0));
+  });
+});
@@ -0,0 +1,247 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Wallet, web3 } from "@coral-xyz/anchor";
+import {
+  GOVERN_PROGRAM_ID,
+  LOCKED_VOTER_PROGRAM_ID,
+  VoteSide,
+  createAndFundWallet,
+  createGovernProgram,
+  createLockedVoterGovernance,
+  createLockedVoterProgram,
+  createMaxLockedEscrow,
+  createProposal,
+  createSetLockerParamsInstruction,
+  deriveVote,
+  invokeAndAssertError,
+} from "../utils";
+import { expect } from "chai";
+
+const provider = anchor.AnchorProvider.env();
+
+// Domain separator of relayed vote messages
+const RELAYED_VOTE_DOMAIN = "locked-voter:relayed-vote";
+
+describe("Relayed vote", () => {
+  let locker: web3.PublicKey;
+  let govern: web3.PublicKey;
+  let smartWallet: web3.PublicKey;
+  let proposal: web3.PublicKey;
+  let vote: web3.PublicKey;
+
+  let wallet: Wallet;
+  let keypair: web3.Keypair;
+
+  let ownerKeypair: web3.Keypair;
+  let ownerWallet: Wallet;
+  let escrow: web3.PublicKey;
+
+  let relayerWallet: Wallet;
+
+  // Govern config
+  const votingPeriod: BN = new BN(30); // 30 seconds duration of voting on proposal
+  const quorumVotes: BN = new BN(2); // 2 vote to pass
+
+  // Voter config
+  const lockerParams = {
+    maxStakeDuration: new BN(20), // 20 seconds
+    maxStakeVoteMultiplier: 1,
+    minStakeDuration: new BN(10), // 10 seconds
+    proposalActivationMinVotes: new BN(2), // min 2 vote to activate proposal
+  };
+
+  // Max locked escrows have a voting power of amount * maxStakeVoteMultiplier
+  const lockAmount = new BN(100);
+
+  function relayedVoteMessage(side: VoteSide, nonce: BN) {
+    return Buffer.concat([
+      Buffer.from(RELAYED_VOTE_DOMAIN),
+      escrow.toBuffer(),
+      proposal.toBuffer(),
+      Buffer.from([side]),
+      nonce.toArrayLike(Buffer, "le", 8),
+    ]);
+  }
+
+  function castRelayedVote(
+    signer: web3.Keypair,
+    signedSide: VoteSide,
+    side: VoteSide,
+    nonce: BN
+  ) {
+    const voterProgram = createLockedVoterProgram(
+      relayerWallet,
+      LOCKED_VOTER_PROGRAM_ID
+    );
+    const ed25519Ix = web3.Ed25519Program.createInstructionWithPrivateKey({
+      privateKey: signer.secretKey,
+      message: relayedVoteMessage(signedSide, nonce),
+    });
+
+    return voterProgram.methods
+      .castRelayedVote(side, nonce)
+      .accounts({
+        locker,
+        escrow,
+        proposal,
+        vote,
+        governor: govern,
+        governProgram: GOVERN_PROGRAM_ID,
+        relayer: relayerWallet.publicKey,
+        instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      })
+      .preInstructions([ed25519Ix])
+      .rpc();
+  }
+
+  before(async () => {
+    const result = await createLockedVoterGovernance(
+      votingPeriod,
+      quorumVotes,
+      new BN(0),
+      lockerParams,
+      provider.connection
+    );
+    keypair = result.keypair;
+    wallet = result.wallet;
+    locker = result.locker;
+    govern = result.governor;
+    smartWallet = result.smartWallet;
+
+    const owner = await createMaxLockedEscrow(
+      locker,
+      result.tokenMint,
+      lockAmount,
+      keypair,
+      provider.connection
+    );
+    ownerKeypair = owner.keypair;
+    ownerWallet = owner.wallet;
+    escrow = owner.escrow;
+
+    relayerWallet = (await createAndFundWallet(provider.connection)).wallet;
+
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    proposal = await createProposal(
+      govern,
+      [
+        createSetLockerParamsInstruction(
+          locker,
+          govern,
+          smartWallet,
+          lockerParams,
+          createLockedVoterProgram(wallet, LOCKED_VOTER_PROGRAM_ID)
+        ),
+      ],
+      governProgram
+    );
+
+    await createLockedVoterProgram(ownerWallet, LOCKED_VOTER_PROGRAM_ID)
+      .methods.activateProposalWithEscrow()
+      .accounts({
+        locker,
+        governor: govern,
+        proposal,
+        escrow,
+        authority: ownerWallet.publicKey,
+        governProgram: GOVERN_PROGRAM_ID,
+      })
+      .rpc();
+  });
+
+  it("relayer casts the vote signed by the escrow owner and pays for it", async () => {
+    const ownerBeforeBalance = await provider.connection.getBalance(
+      ownerWallet.publicKey
+    );
+
+    [vote] = deriveVote(ownerWallet.publicKey, proposal);
+    await createGovernProgram(relayerWallet, GOVERN_PROGRAM_ID)
+      .methods.newVote(ownerWallet.publicKey)
+      .accounts({
+        payer: relayerWallet.publicKey,
+        proposal,
+        systemProgram: web3.SystemProgram.programId,
+        vote,
+      })
+      .rpc();
+
+    await castRelayedVote(ownerKeypair, VoteSide.For, VoteSide.For, new BN(0));
+
+    const ownerAfterBalance = await provider.connection.getBalance(
+      ownerWallet.publicKey
+    );
+    expect(ownerAfterBalance).to.be.equal(ownerBeforeBalance);
+
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    const voteState = await governProgram.account.vote.fetch(vote);
+    expect(voteState.side).to.be.equal(VoteSide.For);
+    expect(voteState.votingPower.toString()).to.be.equal(lockAmount.toString());
+
+    const voterProgram = createLockedVoterProgram(
+      wallet,
+      LOCKED_VOTER_PROGRAM_ID
+    );
+    const escrowState = await voterProgram.account.escrow.fetch(escrow);
+    expect(escrowState.relayNonce.toNumber()).to.be.equal(1);
+  });
+
+  it("cannot replay a relayed vote", async () => {
+    await invokeAndAssertError(
+      () => {
+        return castRelayedVote(
+          ownerKeypair,
+          VoteSide.For,
+          VoteSide.For,
+          new BN(0)
+        );
+      },
+      "Relayed vote nonce does not match the escrow relay nonce",
+      true
+    );
+  });
+
+  it("cannot relay a vote for another side than the signed one", async () => {
+    await invokeAndAssertError(
+      () => {
+        return castRelayedVote(
+          ownerKeypair,
+          VoteSide.For,
+          VoteSide.Against,
+          new BN(1)
+        );
+      },
+      "Relayed vote is not signed by an ed25519 instruction for this vote",
+      true
+    );
+  });
+
+  it("cannot relay a vote signed by neither the owner nor the vote delegate", async () => {
+    await invokeAndAssertError(
+      () => {
+        return castRelayedVote(
+          web3.Keypair.generate(),
+          VoteSide.Against,
+          VoteSide.Against,
+          new BN(1)
+        );
+      },
+      "Keys do not match",
+      false
+    );
+  });
+
+  it("relayer changes the vote with the next nonce", async () => {
+    await castRelayedVote(
+      ownerKeypair,
+      VoteSide.Against,
+      VoteSide.Against,
+      new BN(1)
+    );
+
+    const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+    const proposalState = await governProgram.account.proposal.fetch(proposal);
+    expect(proposalState.optionVotes[VoteSide.For].toString()).to.be.equal("0");
+    expect(proposalState.optionVotes[VoteSide.Against].toString()).to.be.equal(
+      lockAmount.toString()
+    );
+  });
+});
@@ -5,12 +5,18 @@ import { MetVoter } from "../../target/types/met_voter";
 import { LockedVoter } from "../../target/types/locked_voter";
 import {
   GOVERN_PROGRAM_ID,
+  LOCKED_VOTER_PROGRAM_ID,
   MERKLE_DISTRIBUTOR_PROGRAM_ID,
   SMART_WALLET_PROGRAM_ID,
   MET_VOTER_PROGRAM_ID,
+  createGovernProgram,
+  createLockedVoterProgram,
+  createSmartWalletProgram,
 } from "./program";
 import {
   createAssociatedTokenAccountInstruction,
+  createMint,
+  mintTo,
   getAssociatedTokenAddressSync,
   ASSOCIATED_TOKEN_PROGRAM_ID,
   TOKEN_PROGRAM_ID,
@@ -99,6 +105,33 @@ export function deriveEscrow(
   );
 }
 
+export function deriveDelegateRecord(
+  locker: web3.PublicKey,
+  delegate: web3.PublicKey
+) {
+  return web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("DelegateRecord"), locker.toBytes(), delegate.toBytes()],
+    LOCKED_VOTER_PROGRAM_ID
+  );
+}
+
+export function deriveDelegationTable(escrow: web3.PublicKey) {
+  return web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("DelegationTable"), escrow.toBytes()],
+    LOCKED_VOTER_PROGRAM_ID
+  );
+}
+
+export function deriveDelegationShareVoter(
+  escrow: web3.PublicKey,
+  delegate: web3.PublicKey
+) {
+  return web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("DelegationShare"), escrow.toBytes(), delegate.toBytes()],
+    LOCKED_VOTER_PROGRAM_ID
+  );
+}
+
 export function deriveTransaction(smartWallet: web3.PublicKey, txNo: BN) {
   return web3.PublicKey.findProgramAddressSync(
     [
@@ -135,6 +168,73 @@ export function deriveOptionProposalMeta(proposal: web3.PublicKey) {
   );
 }
 
+export function deriveProposalRewards(proposal: web3.PublicKey) {
+  return web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("ProposalRewards"), proposal.toBytes()],
+    GOVERN_PROGRAM_ID
+  );
+}
+
+export function deriveProposalBond(proposal: web3.PublicKey) {
+  return web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("ProposalBond"), proposal.toBytes()],
+    GOVERN_PROGRAM_ID
+  );
+}
+
+export function deriveInstructionBuffer(
+  governor: web3.PublicKey,
+  proposer: web3.PublicKey
+) {
+  return web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("InstructionBuffer"), governor.toBytes(), proposer.toBytes()],
+    GOVERN_PROGRAM_ID
+  );
+}
+
+export function deriveEventAuthority(programId: web3.PublicKey) {
+  return web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("__event_authority")],
+    programId
+  );
+}
+
+export function deriveOptionIncentive(
+  proposal: web3.PublicKey,
+  option: number,
+  mint: web3.PublicKey
+) {
+  return web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("OptionIncentive"),
+      proposal.toBytes(),
+      Buffer.from([option]),
+      mint.toBytes(),
+    ],
+    GOVERN_PROGRAM_ID
+  );
+}
+
+export function deriveIncentiveDeposit(
+  optionIncentive: web3.PublicKey,
+  depositor: web3.PublicKey
+) {
+  return web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("IncentiveDeposit"), optionIncentive.toBytes(), depositor.toBytes()],
+    GOVERN_PROGRAM_ID
+  );
+}
+
+export function deriveIncentiveClaim(
+  optionIncentive: web3.PublicKey,
+  vote: web3.PublicKey
+) {
+  return web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("IncentiveClaim"), optionIncentive.toBytes(), vote.toBytes()],
+    GOVERN_PROGRAM_ID
+  );
+}
+
 export async function createProposal(
   governor: web3.PublicKey,
   instruction: IProposalInstruction[],
@@ -536,3 +636,401 @@ export async function invokeAndAssertError(
 
   expect(error).not.null;
 }
+
+export interface ILockerParams {
+  maxStakeDuration: BN;
+  maxStakeVoteMultiplier: number;
+  minStakeDuration: BN;
+  proposalActivationMinVotes: BN;
+}
+
+export async function waitUntilOnChainTime(
+  timestamp: number,
+  connection: web3.Connection
+) {
+  while (true) {
+    const onchainTimestamp = await getOnChainTime(connection);
+    if (timestamp > onchainTimestamp) {
+      console.log(`${timestamp - onchainTimestamp} seconds until ${timestamp}`);
+      await sleep(1000);
+    } else {
+      break;
+    }
+  }
+}
+
+export async function createLockedVoterGovernance(
+  votingPeriod: BN,
+  quorumVotes: BN,
+  timelockDelaySeconds: BN,
+  lockerParams: ILockerParams,
+  connection: web3.Connection
+) {
+  const { keypair, wallet } = await createAndFundWallet(connection);
+
+  const [smartWallet, _sBump] = deriveSmartWallet(keypair.publicKey);
+  const [governor, _gBump] = deriveGovern(keypair.publicKey);
+  const [locker, _lBump] = deriveLocker(
+    keypair.publicKey,
+    LOCKED_VOTER_PROGRAM_ID
+  );
+
+  const smartWalletOwners = [governor, wallet.publicKey];
+  await createSmartWallet(
+    smartWalletOwners,
+    smartWalletOwners.length,
+    new BN(0),
+    new BN(1),
+    keypair,
+    createSmartWalletProgram(wallet, SMART_WALLET_PROGRAM_ID)
+  );
+
+  await createGovernor(
+    new BN(0),
+    votingPeriod,
+    quorumVotes,
+    timelockDelaySeconds,
+    keypair,
+    smartWallet,
+    createGovernProgram(wallet, GOVERN_PROGRAM_ID),
+    LOCKED_VOTER_PROGRAM_ID
+  );
+
+  const tokenMint = await createMint(
+    connection,
+    keypair,
+    keypair.publicKey,
+    null,
+    9
+  );
+
+  const voterProgram = createLockedVoterProgram(wallet, LOCKED_VOTER_PROGRAM_ID);
+  await voterProgram.methods
+    .newLocker(lockerParams)
+    .accounts({
+      base: keypair.publicKey,
+      locker,
+      tokenMint,
+      governor,
+      payer: voterProgram.provider.publicKey,
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .rpc();
+
+  return {
+    keypair,
+    wallet,
+    smartWallet,
+    governor,
+    locker,
+    tokenMint,
+  };
+}
+
+export async function createMaxLockedEscrow(
+  locker: web3.PublicKey,
+  tokenMint: web3.PublicKey,
+  amount: BN,
+  mintAuthority: web3.Keypair,
+  connection: web3.Connection
+) {
+  const { keypair, wallet } = await createAndFundWallet(connection);
+  const voterProgram = createLockedVoterProgram(wallet, LOCKED_VOTER_PROGRAM_ID);
+  const [escrow, _bump] = deriveEscrow(
+    locker,
+    wallet.publicKey,
+    LOCKED_VOTER_PROGRAM_ID
+  );
+
+  const sourceTokens = await getOrCreateATA(
+    tokenMint,
+    wallet.publicKey,
+    keypair,
+    connection
+  );
+
+  await mintTo(
+    connection,
+    mintAuthority,
+    tokenMint,
+    sourceTokens,
+    mintAuthority.publicKey,
+    amount.toNumber()
+  );
+
+  await voterProgram.methods
+    .newEscrow()
+    .accounts({
+      escrow,
+      escrowOwner: wallet.publicKey,
+      locker,
+      payer: wallet.publicKey,
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .rpc();
+
+  await voterProgram.methods
+    .toggleMaxLock(true)
+    .accounts({
+      escrow,
+      escrowOwner: wallet.publicKey,
+      locker,
+    })
+    .rpc();
+
+  const escrowTokens = await getOrCreateATA(
+    tokenMint,
+    escrow,
+    keypair,
+    connection
+  );
+
+  await voterProgram.methods
+    .increaseLockedAmount(amount)
+    .accounts({
+      escrow,
+      escrowTokens,
+      locker,
+      payer: wallet.publicKey,
+      sourceTokens,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .rpc();
+
+  return {
+    keypair,
+    wallet,
+    escrow,
+  };
+}
+
+export function createSetLockerParamsInstruction(
+  locker: web3.PublicKey,
+  governor: web3.PublicKey,
+  smartWallet: web3.PublicKey,
+  params: ILockerParams,
+  voterProgram: Program<LockedVoter>
+): IProposalInstruction {
+  return {
+    data: voterProgram.coder.instruction.encode("set_locker_params", {
+      params,
+    }),
+    programId: LOCKED_VOTER_PROGRAM_ID,
+    keys: [
+      {
+        isSigner: false,
+        isWritable: true,
+        pubkey: locker,
+      },
+      {
+        isSigner: false,
+        isWritable: false,
+        pubkey: governor,
+      },
+      {
+        isSigner: true,
+        isWritable: false,
+        pubkey: smartWallet,
+      },
+    ],
+  };
+}
+
+export async function invokeSmartWalletInstruction(
+  smartWallet: web3.PublicKey,
+  instruction: IProposalInstruction,
+  smartWalletProgram: Program<SmartWallet>
+) {
+  const smartWalletState = await smartWalletProgram.account.smartWallet.fetch(
+    smartWallet
+  );
+
+  const [transaction, txBump] = deriveTransaction(
+    smartWallet,
+    smartWalletState.numTransactions
+  );
+
+  await smartWalletProgram.methods
+    .createTransaction(txBump, [instruction])
+    .accounts({
+      payer: smartWalletProgram.provider.publicKey,
+      proposer: smartWalletProgram.provider.publicKey,
+      smartWallet,
+      systemProgram: web3.SystemProgram.programId,
+      transaction,
+    })
+    .rpc();
+
+  const tx = await smartWalletProgram.methods
+    .executeTransaction()
+    .accounts({
+      owner: smartWalletProgram.provider.publicKey,
+      smartWallet,
+      transaction,
+    })
+    .remainingAccounts([
+      {
+        isSigner: false,
+        isWritable: false,
+        pubkey: instruction.programId,
+      },
+      ...instruction.keys.map((x) => {
+        return {
+          ...x,
+          isSigner: false, // Need to override to false as these are signed by the smart wallet
+        };
+      }),
+    ])
+    .rpc();
+
+  console.log("Invoke smart wallet instruction tx", tx);
+
+  return transaction;
+}
+
+export async function castVoteWithEscrow(
+  locker: web3.PublicKey,
+  governor: web3.PublicKey,
+  proposal: web3.PublicKey,
+  escrow: web3.PublicKey,
+  side: number,
+  voterProgram: Program<LockedVoter>,
+  governProgram: Program<Govern>
+) {
+  const vote = await getOrCreateVote(proposal, governProgram);
+
+  await voterProgram.methods
+    .castVote(side)
+    .accounts({
+      escrow,
+      governor,
+      governProgram: GOVERN_PROGRAM_ID,
+      locker,
+      proposal,
+      vote,
+      voteDelegate: voterProgram.provider.publicKey,
+      delegationTable: null,
+    })
+    .rpc();
+
+  return vote;
+}
+
+export async function queueProposal(
+  governor: web3.PublicKey,
+  proposal: web3.PublicKey,
+  governProgram: Program<Govern>,
+  smartWalletProgram: Program<SmartWallet>
+) {
+  const governState = await governProgram.account.governor.fetch(governor);
+  const smartWalletState = await smartWalletProgram.account.smartWallet.fetch(
+    governState.smartWallet
+  );
+
+  const [transaction, _bump] = deriveTransaction(
+    governState.smartWallet,
+    smartWalletState.numTransactions
+  );
+
+  const tx = await governProgram.methods
+    .queueProposal()
+    .accounts({
+      governor,
+      proposal,
+      transaction,
+      smartWallet: governState.smartWallet,
+      payer: governProgram.provider.publicKey,
+      optionProposalInstructions: null,
+      smartWalletProgram: SMART_WALLET_PROGRAM_ID,
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .rpc();
+
+  console.log("Queue proposal tx", tx);
+
+  return transaction;
+}
+
+export function createGovernorInstruction(
+  name: string,
+  args: any,
+  governor: web3.PublicKey,
+  smartWallet: web3.PublicKey,
+  governProgram: Program<Govern>
+): IProposalInstruction {
+  return {
+    data: governProgram.coder.instruction.encode(name, args),
+    programId: GOVERN_PROGRAM_ID,
+    keys: [
+      {
+        isSigner: false,
+        isWritable: true,
+        pubkey: governor,
+      },
+      {
+        isSigner: true,
+        isWritable: false,
+        pubkey: smartWallet,
+      },
+    ],
+  };
+}
+
+export async function setVotingReward(
+  governor: web3.PublicKey,
+  smartWallet: web3.PublicKey,
+  rewardMint: web3.PublicKey,
+  rewardPerProposal: BN,
+  mintAuthority: web3.Keypair,
+  wallet: Wallet
+) {
+  const governProgram = createGovernProgram(wallet, GOVERN_PROGRAM_ID);
+
+  const setVotingRewardIx: IProposalInstruction = {
+    data: governProgram.coder.instruction.encode("set_voting_reward", {
+      rewardPerProposal,
+    }),
+    programId: GOVERN_PROGRAM_ID,
+    keys: [
+      {
+        isSigner: false,
+        isWritable: true,
+        pubkey: governor,
+      },
+      {
+        isSigner: false,
+        isWritable: false,
+        pubkey: rewardMint,
+      },
+      {
+        isSigner: true,
+        isWritable: false,
+        pubkey: smartWallet,
+      },
+    ],
+  };
+
+  await invokeSmartWalletInstruction(
+    smartWallet,
+    setVotingRewardIx,
+    createSmartWalletProgram(wallet, SMART_WALLET_PROGRAM_ID)
+  );
+
+  const rewardVault = await getOrCreateATA(
+    rewardMint,
+    governor,
+    mintAuthority,
+    governProgram.provider.connection
+  );
+
+  await mintTo(
+    governProgram.provider.connection,
+    mintAuthority,
+    rewardMint,
+    rewardVault,
+    mintAuthority.publicKey,
+    rewardPerProposal.toNumber()
+  );
+
+  return rewardVault;
+}